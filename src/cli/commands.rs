@@ -1,3 +1,4 @@
+use crate::types::HookEvent;
 use clap::{Args, Parser, Subcommand};
 
 /// Pomodoro Timer CLI
@@ -16,6 +17,18 @@ pub struct Cli {
     /// 詳細ログを出力
     #[arg(short, long, global = true)]
     pub verbose: bool,
+
+    /// デーモンが起動していなくても自動起動しない
+    #[arg(long, global = true)]
+    pub no_spawn: bool,
+
+    /// 操作するタイマーの名前（省略時はデフォルトタイマー）
+    #[arg(long, global = true, value_name = "NAME")]
+    pub timer: Option<String>,
+
+    /// デーモンのソケットパス（省略時は `POMODORO_SOCKET`、なければデータディレクトリから決定）
+    #[arg(long, global = true, value_name = "PATH")]
+    pub socket: Option<std::path::PathBuf>,
 }
 
 /// Subcommand definitions
@@ -33,8 +46,18 @@ pub enum Commands {
     /// タイマーを停止
     Stop,
 
+    /// 現在のフェーズをスキップ（作業→休憩、休憩→作業）
+    Skip,
+
     /// 現在のステータスを確認
-    Status,
+    Status {
+        /// すべてのタイマーを一覧表示
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// タイマーイベントを購読して表示（NDJSON）
+    Watch,
 
     /// LaunchAgentをインストール（ログイン時自動起動）
     Install,
@@ -44,7 +67,25 @@ pub enum Commands {
 
     /// デーモンモードで起動（LaunchAgentから呼ばれる）
     #[command(hide = true)]
-    Daemon,
+    Daemon {
+        /// 設定ファイルの変更を監視して自動的に再読み込みする
+        #[arg(long)]
+        watch_config: bool,
+
+        /// チームタイマーのリーダーとして指定アドレスで待ち受ける（team.jsonより優先）
+        #[arg(long, value_name = "ADDR", conflicts_with = "follow")]
+        lead: Option<std::net::SocketAddr>,
+
+        /// 指定アドレスのリーダーのフェーズに追従する（team.jsonより優先）
+        #[arg(long, value_name = "ADDR")]
+        follow: Option<String>,
+    },
+
+    /// デーモンを終了（実行中のタイマーは停止される）
+    Shutdown,
+
+    /// デーモンにフック設定・サウンド設定を再読み込みさせる
+    Reload,
 
     /// シェル補完スクリプトを生成
     Completions {
@@ -58,6 +99,28 @@ pub enum Commands {
 
     /// システムサウンド一覧を表示
     Sounds,
+
+    /// 作業履歴を管理（手動登録・修正）
+    Log(LogArgs),
+
+    /// フックを管理（一覧・検証・テスト実行・実行記録）
+    Hooks(HooksArgs),
+}
+
+impl Commands {
+    /// デーモンとの通信が必要なコマンドかどうか（自動起動の対象）
+    pub fn requires_daemon(&self) -> bool {
+        matches!(
+            self,
+            Commands::Start(_)
+                | Commands::Pause
+                | Commands::Resume
+                | Commands::Stop
+                | Commands::Skip
+                | Commands::Status { .. }
+                | Commands::Watch
+        )
+    }
 }
 
 /// Config command arguments
@@ -70,6 +133,154 @@ pub struct ConfigArgs {
     /// 休憩完了時のサウンドを設定
     #[arg(long)]
     pub break_sound: Option<String>,
+
+    /// デーモンの自動起動を設定（true/false）
+    #[arg(long)]
+    pub auto_spawn: Option<bool>,
+}
+
+/// Log command arguments
+#[derive(Args, Debug, Clone)]
+pub struct LogArgs {
+    #[command(subcommand)]
+    pub command: LogCommand,
+}
+
+/// Log subcommand definitions
+#[derive(Subcommand, Debug, Clone)]
+pub enum LogCommand {
+    /// 作業履歴を一覧表示
+    List(LogListArgs),
+
+    /// 作業履歴を手動で追加
+    Add(LogAddArgs),
+
+    /// 作業履歴を編集
+    Edit(LogEditArgs),
+
+    /// 作業履歴を削除
+    Delete {
+        /// 履歴ID
+        id: u32,
+    },
+}
+
+/// log list arguments
+#[derive(Args, Debug, Clone)]
+pub struct LogListArgs {
+    /// 表示する日付（YYYY-MM-DD、省略時は全件）
+    #[arg(long, value_parser = parse_log_date)]
+    pub date: Option<chrono::NaiveDate>,
+
+    /// JSON形式で出力（エクスポート用）
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// log add arguments
+#[derive(Args, Debug, Clone)]
+pub struct LogAddArgs {
+    /// タスク名
+    #[arg(short, long, value_parser = validate_task_name)]
+    pub task: Option<String>,
+
+    /// 開始時刻（HH:MM または YYYY-MM-DD HH:MM）
+    #[arg(short, long)]
+    pub start: String,
+
+    /// 作業時間（例: 25m, 1h, 1h30m）
+    #[arg(short, long, default_value = "25m", value_parser = parse_log_duration)]
+    pub duration: u64,
+}
+
+/// log edit arguments
+#[derive(Args, Debug, Clone)]
+pub struct LogEditArgs {
+    /// 履歴ID
+    pub id: u32,
+
+    /// タスク名
+    #[arg(short, long, value_parser = validate_task_name)]
+    pub task: Option<String>,
+
+    /// 開始時刻（HH:MM または YYYY-MM-DD HH:MM）
+    #[arg(short, long)]
+    pub start: Option<String>,
+
+    /// 作業時間（例: 25m, 1h, 1h30m）
+    #[arg(short, long, value_parser = parse_log_duration)]
+    pub duration: Option<u64>,
+}
+
+/// Hooks command arguments
+#[derive(Args, Debug, Clone)]
+pub struct HooksArgs {
+    #[command(subcommand)]
+    pub command: HooksCommand,
+}
+
+/// Hooks subcommand definitions
+#[derive(Subcommand, Debug, Clone)]
+pub enum HooksCommand {
+    /// 登録されているフックをイベントごとに一覧表示
+    List {
+        /// フック設定ファイル（省略時は ~/.pomodoro/hooks.json）
+        #[arg(long)]
+        file: Option<std::path::PathBuf>,
+    },
+
+    /// フック設定ファイルを検証
+    Validate {
+        /// フック設定ファイル（省略時は ~/.pomodoro/hooks.json）
+        #[arg(long)]
+        file: Option<std::path::PathBuf>,
+    },
+
+    /// 指定したイベントのフックを疑似的なコンテキストで実行
+    Test(HooksTestArgs),
+
+    /// デーモンが実行したフックの記録を表示
+    Logs(HooksLogsArgs),
+}
+
+/// hooks test arguments
+#[derive(Args, Debug, Clone)]
+pub struct HooksTestArgs {
+    /// イベント名（例: work_end, before_work_start）
+    #[arg(value_parser = parse_hook_event)]
+    pub event: HookEvent,
+
+    /// コンテキストに設定するタスク名
+    #[arg(short, long, value_parser = validate_task_name)]
+    pub task: Option<String>,
+
+    /// 指定した名前のフックだけを実行
+    #[arg(long)]
+    pub hook: Option<String>,
+
+    /// フック設定ファイル（省略時は ~/.pomodoro/hooks.json）
+    #[arg(long)]
+    pub file: Option<std::path::PathBuf>,
+}
+
+/// hooks logs arguments
+#[derive(Args, Debug, Clone)]
+pub struct HooksLogsArgs {
+    /// 表示する件数（新しい順）
+    #[arg(short = 'n', long, default_value = "20")]
+    pub limit: usize,
+
+    /// 指定した名前のフックだけを表示
+    #[arg(long)]
+    pub hook: Option<String>,
+
+    /// 失敗した実行だけを表示
+    #[arg(long)]
+    pub failed: bool,
+
+    /// JSON形式で出力
+    #[arg(long)]
+    pub json: bool,
 }
 
 /// start command arguments
@@ -115,6 +326,61 @@ fn validate_task_name(s: &str) -> Result<String, String> {
     Ok(s.to_string())
 }
 
+/// Hook event parsing
+fn parse_hook_event(s: &str) -> Result<HookEvent, String> {
+    HookEvent::parse(s).ok_or_else(|| {
+        let names: Vec<&str> = HookEvent::ALL.iter().map(HookEvent::as_str).collect();
+        format!(
+            "無効なイベント名: '{}' (使用できるイベント: {})",
+            s,
+            names.join(", ")
+        )
+    })
+}
+
+/// Log date validation
+fn parse_log_date(s: &str) -> Result<chrono::NaiveDate, String> {
+    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|_| "日付は YYYY-MM-DD 形式で指定してください".to_string())
+}
+
+/// Log duration parsing (returns seconds)
+///
+/// `25m`, `1h`, `1h30m`, `90s` などの形式を受け付ける。単位なしの数値は分として扱う。
+pub fn parse_log_duration(s: &str) -> Result<u64, String> {
+    const INVALID: &str = "作業時間は 25m, 1h, 1h30m の形式で指定してください";
+
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(INVALID.to_string());
+    }
+    if let Ok(minutes) = s.parse::<u64>() {
+        return Ok(minutes * 60);
+    }
+
+    let mut total = 0u64;
+    let mut digits = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let value: u64 = digits.parse().map_err(|_| INVALID.to_string())?;
+        digits.clear();
+        total += match c {
+            'h' => value * 3600,
+            'm' => value * 60,
+            's' => value,
+            _ => return Err(INVALID.to_string()),
+        };
+    }
+    if !digits.is_empty() {
+        return Err(INVALID.to_string());
+    }
+
+    Ok(total)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -187,7 +453,59 @@ mod tests {
     fn test_parse_status_command() {
         let args = vec!["pomodoro", "status"];
         let cli = Cli::try_parse_from(args).unwrap();
-        assert!(matches!(cli.command, Commands::Status));
+        assert!(matches!(cli.command, Commands::Status { all: false }));
+    }
+
+    #[test]
+    fn test_parse_status_all() {
+        let cli = Cli::try_parse_from(["pomodoro", "status", "--all"]).unwrap();
+        assert!(matches!(cli.command, Commands::Status { all: true }));
+    }
+
+    #[test]
+    fn test_parse_timer_option() {
+        let cli =
+            Cli::try_parse_from(["pomodoro", "--timer", "prep", "start", "-w", "10"]).unwrap();
+        assert_eq!(cli.timer.as_deref(), Some("prep"));
+        if let Commands::Start(args) = cli.command {
+            assert_eq!(args.work, 10);
+        } else {
+            panic!("Expected Start command");
+        }
+
+        // サブコマンドの後にも指定できる
+        let cli = Cli::try_parse_from(["pomodoro", "pause", "--timer", "prep"]).unwrap();
+        assert_eq!(cli.timer.as_deref(), Some("prep"));
+
+        let cli = Cli::try_parse_from(["pomodoro", "pause"]).unwrap();
+        assert!(cli.timer.is_none());
+    }
+
+    #[test]
+    fn test_parse_socket_option() {
+        let cli = Cli::try_parse_from(["pomodoro", "--socket", "/tmp/p.sock", "status"]).unwrap();
+        assert_eq!(cli.socket, Some(std::path::PathBuf::from("/tmp/p.sock")));
+
+        // デーモン起動時にも指定できる
+        let cli = Cli::try_parse_from(["pomodoro", "daemon", "--socket", "/tmp/p.sock"]).unwrap();
+        assert_eq!(cli.socket, Some(std::path::PathBuf::from("/tmp/p.sock")));
+
+        let cli = Cli::try_parse_from(["pomodoro", "status"]).unwrap();
+        assert!(cli.socket.is_none());
+    }
+
+    #[test]
+    fn test_parse_skip_command() {
+        let args = vec!["pomodoro", "skip"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(matches!(cli.command, Commands::Skip));
+    }
+
+    #[test]
+    fn test_parse_watch_command() {
+        let args = vec!["pomodoro", "watch"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(matches!(cli.command, Commands::Watch));
     }
 
     #[test]
@@ -257,6 +575,115 @@ mod tests {
         assert!(cli.verbose);
     }
 
+    #[test]
+    fn test_no_spawn_flag() {
+        let cli = Cli::try_parse_from(["pomodoro", "status"]).unwrap();
+        assert!(!cli.no_spawn);
+
+        let cli = Cli::try_parse_from(["pomodoro", "status", "--no-spawn"]).unwrap();
+        assert!(cli.no_spawn);
+    }
+
+    #[test]
+    fn test_requires_daemon() {
+        let cli = Cli::try_parse_from(["pomodoro", "start"]).unwrap();
+        assert!(cli.command.requires_daemon());
+
+        let cli = Cli::try_parse_from(["pomodoro", "watch"]).unwrap();
+        assert!(cli.command.requires_daemon());
+
+        let cli = Cli::try_parse_from(["pomodoro", "daemon"]).unwrap();
+        assert!(!cli.command.requires_daemon());
+
+        let cli = Cli::try_parse_from(["pomodoro", "sounds"]).unwrap();
+        assert!(!cli.command.requires_daemon());
+
+        // 終了のためだけにデーモンを起動しない
+        let cli = Cli::try_parse_from(["pomodoro", "shutdown"]).unwrap();
+        assert!(!cli.command.requires_daemon());
+    }
+
+    #[test]
+    fn test_parse_reload_command() {
+        let cli = Cli::try_parse_from(["pomodoro", "reload"]).unwrap();
+        assert!(matches!(cli.command, Commands::Reload));
+        assert!(!cli.command.requires_daemon());
+    }
+
+    #[test]
+    fn test_parse_daemon_watch_config() {
+        let cli = Cli::try_parse_from(["pomodoro", "daemon"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Daemon {
+                watch_config: false,
+                ..
+            }
+        ));
+
+        let cli = Cli::try_parse_from(["pomodoro", "daemon", "--watch-config"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Daemon {
+                watch_config: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_daemon_team_flags() {
+        let cli = Cli::try_parse_from(["pomodoro", "daemon", "--lead", "127.0.0.1:7374"]).unwrap();
+        match cli.command {
+            Commands::Daemon { lead, follow, .. } => {
+                assert_eq!(lead, Some("127.0.0.1:7374".parse().unwrap()));
+                assert!(follow.is_none());
+            }
+            _ => panic!("Expected Daemon command"),
+        }
+
+        let cli =
+            Cli::try_parse_from(["pomodoro", "daemon", "--follow", "localhost:7374"]).unwrap();
+        match cli.command {
+            Commands::Daemon { follow, .. } => {
+                assert_eq!(follow.as_deref(), Some("localhost:7374"));
+            }
+            _ => panic!("Expected Daemon command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_daemon_lead_and_follow_conflict() {
+        let result = Cli::try_parse_from([
+            "pomodoro",
+            "daemon",
+            "--lead",
+            "127.0.0.1:7374",
+            "--follow",
+            "127.0.0.1:7375",
+        ]);
+        assert!(result.is_err());
+
+        let result = Cli::try_parse_from(["pomodoro", "daemon", "--lead", "not-an-address"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_shutdown_command() {
+        let cli = Cli::try_parse_from(["pomodoro", "shutdown"]).unwrap();
+        assert!(matches!(cli.command, Commands::Shutdown));
+    }
+
+    #[test]
+    fn test_parse_config_auto_spawn() {
+        let cli = Cli::try_parse_from(["pomodoro", "config", "--auto-spawn", "false"]).unwrap();
+        if let Commands::Config(args) = cli.command {
+            assert_eq!(args.auto_spawn, Some(false));
+        } else {
+            panic!("Expected Config command");
+        }
+    }
+
     #[test]
     fn test_parse_sounds_command() {
         let args = vec!["pomodoro", "sounds"];
@@ -274,4 +701,130 @@ mod tests {
             panic!("Expected Config command");
         }
     }
+
+    #[test]
+    fn test_parse_log_add_command() {
+        let args = vec![
+            "pomodoro",
+            "log",
+            "add",
+            "--task",
+            "会議",
+            "--start",
+            "14:00",
+            "--duration",
+            "50m",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            Commands::Log(LogArgs {
+                command: LogCommand::Add(add_args),
+            }) => {
+                assert_eq!(add_args.task, Some("会議".to_string()));
+                assert_eq!(add_args.start, "14:00");
+                assert_eq!(add_args.duration, 50 * 60);
+            }
+            _ => panic!("Expected Log Add command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_log_add_default_duration() {
+        let args = vec!["pomodoro", "log", "add", "--start", "14:00"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        match cli.command {
+            Commands::Log(LogArgs {
+                command: LogCommand::Add(add_args),
+            }) => assert_eq!(add_args.duration, 25 * 60),
+            _ => panic!("Expected Log Add command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_log_edit_and_delete_commands() {
+        let cli = Cli::try_parse_from(vec!["pomodoro", "log", "edit", "3", "-d", "1h"]).unwrap();
+        match cli.command {
+            Commands::Log(LogArgs {
+                command: LogCommand::Edit(edit_args),
+            }) => {
+                assert_eq!(edit_args.id, 3);
+                assert_eq!(edit_args.duration, Some(3600));
+                assert!(edit_args.start.is_none());
+            }
+            _ => panic!("Expected Log Edit command"),
+        }
+
+        let cli = Cli::try_parse_from(vec!["pomodoro", "log", "delete", "3"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Log(LogArgs {
+                command: LogCommand::Delete { id: 3 }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_hooks_commands() {
+        let cli = Cli::try_parse_from(vec![
+            "pomodoro",
+            "hooks",
+            "test",
+            "before_work_start",
+            "--task",
+            "設計",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Hooks(HooksArgs {
+                command: HooksCommand::Test(test_args),
+            }) => {
+                assert_eq!(test_args.event, HookEvent::BeforeWorkStart);
+                assert_eq!(test_args.task, Some("設計".to_string()));
+                assert!(test_args.hook.is_none());
+            }
+            _ => panic!("Expected Hooks Test command"),
+        }
+
+        let cli =
+            Cli::try_parse_from(vec!["pomodoro", "hooks", "logs", "-n", "5", "--failed"]).unwrap();
+        match cli.command {
+            Commands::Hooks(HooksArgs {
+                command: HooksCommand::Logs(logs_args),
+            }) => {
+                assert_eq!(logs_args.limit, 5);
+                assert!(logs_args.failed);
+                assert!(!logs_args.json);
+            }
+            _ => panic!("Expected Hooks Logs command"),
+        }
+
+        let cli = Cli::try_parse_from(vec!["pomodoro", "hooks", "validate"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Hooks(HooksArgs {
+                command: HooksCommand::Validate { file: None }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_hooks_test_rejects_unknown_event() {
+        let err = Cli::try_parse_from(vec!["pomodoro", "hooks", "test", "lunch"]).unwrap_err();
+        assert!(err.to_string().contains("無効なイベント名: 'lunch'"));
+    }
+
+    #[test]
+    fn test_parse_log_duration() {
+        assert_eq!(parse_log_duration("25m"), Ok(25 * 60));
+        assert_eq!(parse_log_duration("25"), Ok(25 * 60));
+        assert_eq!(parse_log_duration("1h"), Ok(3600));
+        assert_eq!(parse_log_duration("1h30m"), Ok(90 * 60));
+        assert_eq!(parse_log_duration("90s"), Ok(90));
+        assert!(parse_log_duration("").is_err());
+        assert!(parse_log_duration("abc").is_err());
+        assert!(parse_log_duration("25x").is_err());
+        assert!(parse_log_duration("1h30").is_err());
+    }
 }
@@ -8,7 +8,7 @@ use crate::cli::animation::{AnimationEngine, AnimationFrame};
 use crate::cli::layout::LayoutRenderer;
 use crate::cli::terminal::TerminalController;
 use crate::cli::time_format::TimeDisplay;
-use crate::types::{IpcResponse, TimerPhase};
+use crate::types::{ErrorCode, HookFailure, IpcResponse, ResponseData, TimerPhase};
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::str::FromStr;
@@ -183,6 +183,25 @@ impl Display {
         println!("{} {}", "■".red().bold(), response.message.red());
     }
 
+    /// Show skip success message
+    pub fn show_skip_success(&self, response: IpcResponse) {
+        println!("{} {}", "⏭".cyan().bold(), response.message.cyan());
+        if let Some(phase) = response
+            .data
+            .and_then(|data| data.state)
+            .and_then(|state| TimerPhase::from_str(&state).ok())
+        {
+            let label = match phase {
+                TimerPhase::Working => "作業中",
+                TimerPhase::Breaking => "休憩中",
+                TimerPhase::LongBreaking => "長い休憩中",
+                TimerPhase::Paused => "一時停止中",
+                TimerPhase::Stopped => "停止中",
+            };
+            println!("  現在: {}", label);
+        }
+    }
+
     /// Show status information (one-shot display using new layout)
     pub fn show_status(&self, response: IpcResponse) {
         if let Some(data) = response.data {
@@ -241,16 +260,63 @@ impl Display {
             if let Some(count) = data.pomodoro_count {
                 println!("完了ポモドーロ: {} 🍅", count);
             }
+
+            if let Some(failures) = &data.hook_failures {
+                self.show_hook_failures(failures);
+            }
         } else {
             println!("{}", response.message);
         }
     }
 
+    /// Show hooks whose final attempt failed
+    pub fn show_hook_failures(&self, failures: &[HookFailure]) {
+        if failures.is_empty() {
+            return;
+        }
+        println!("{}", "⚠ 失敗したフックがあります".yellow().bold());
+        for failure in failures {
+            println!("  {}", hook_failure_line(failure));
+        }
+        println!(
+            "  {}",
+            "`pomodoro hooks logs --failed` で詳細を確認できます".dimmed()
+        );
+    }
+
+    /// Show the status of every timer (one line per timer)
+    pub fn show_timer_list(&self, response: IpcResponse) {
+        let timers = response
+            .data
+            .and_then(|data| data.timers)
+            .unwrap_or_default();
+        if timers.is_empty() {
+            println!("{}", response.message);
+            return;
+        }
+
+        println!("{}", "=== タイマー一覧 ===".bold());
+        let width = timers
+            .iter()
+            .filter_map(|data| data.timer.as_deref())
+            .map(|name| name.chars().count())
+            .max()
+            .unwrap_or(0);
+        for data in &timers {
+            println!("{}", timer_list_line(data, width));
+        }
+    }
+
     /// Show error message
     pub fn show_error(&self, msg: &str) {
         eprintln!("{} {}", "✗".red().bold(), msg.red());
     }
 
+    /// Show error response from the daemon (message is chosen by error code)
+    pub fn show_response_error(&self, response: &IpcResponse) {
+        self.show_error(&error_message(response));
+    }
+
     /// Show install success message
     pub fn show_install_success(&self) {
         println!(
@@ -397,6 +463,71 @@ impl Display {
     }
 }
 
+/// Format one line of the timer list: name, phase, remaining time and task
+fn timer_list_line(data: &ResponseData, name_width: usize) -> String {
+    let name = data.timer.as_deref().unwrap_or("");
+    let phase = data
+        .state
+        .as_deref()
+        .and_then(|s| TimerPhase::from_str(s).ok())
+        .unwrap_or(TimerPhase::Stopped);
+    let label = match phase {
+        TimerPhase::Working => "作業中",
+        TimerPhase::Breaking => "休憩中",
+        TimerPhase::LongBreaking => "長い休憩中",
+        TimerPhase::Paused => "一時停止",
+        TimerPhase::Stopped => "停止中",
+    };
+
+    let mut line = format!("{:<width$}  {}", name, label, width = name_width);
+    if phase != TimerPhase::Stopped {
+        if let Some(remaining) = data.remaining_seconds {
+            line.push_str(&format!("  {}:{:02}", remaining / 60, remaining % 60));
+        }
+    }
+    if let Some(task) = &data.task_name {
+        line.push_str(&format!("  {}", task));
+    }
+    line
+}
+
+/// Format one failed hook: name, event, attempts, time and error
+fn hook_failure_line(failure: &HookFailure) -> String {
+    format!(
+        "{} ({})  {}回実行  {}  {}",
+        failure.hook,
+        failure.event.as_str(),
+        failure.attempts,
+        failure
+            .timestamp
+            .with_timezone(&chrono::Local)
+            .format("%H:%M"),
+        failure.error
+    )
+}
+
+/// Map an error response to the CLI message for its error code
+///
+/// Falls back to the daemon's message when the code is missing or has no CLI-specific text.
+pub fn error_message(response: &IpcResponse) -> String {
+    match response.code {
+        Some(ErrorCode::AlreadyRunning) => {
+            "タイマーは既に実行中です（`pomodoro stop` で停止できます）".to_string()
+        }
+        Some(ErrorCode::NotRunning) => {
+            "タイマーは実行されていません（`pomodoro start` で開始できます）".to_string()
+        }
+        Some(ErrorCode::NotPaused) => {
+            "タイマーは一時停止していません（`pomodoro pause` で一時停止できます）".to_string()
+        }
+        Some(ErrorCode::UnsupportedProtocolVersion) => format!(
+            "デーモンとCLIのバージョンが一致しません。デーモンを再起動してください（{}）",
+            response.message
+        ),
+        _ => response.message.clone(),
+    }
+}
+
 impl Default for Display {
     fn default() -> Self {
         Self::new()
@@ -410,7 +541,8 @@ impl Default for Display {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::ResponseData;
+    use crate::types::HookEvent;
+    use chrono::TimeZone;
 
     #[test]
     fn test_display_new() {
@@ -445,6 +577,7 @@ mod tests {
                 pomodoro_count: None,
                 task_name: Some("Test task".to_string()),
                 duration: None,
+                ..Default::default()
             }),
         );
         // This should not panic
@@ -475,6 +608,20 @@ mod tests {
         display.show_stop_success(response);
     }
 
+    #[test]
+    fn test_show_skip_success() {
+        let display = Display::new();
+        let response = IpcResponse::success(
+            "フェーズをスキップしました",
+            Some(ResponseData {
+                state: Some("breaking".to_string()),
+                ..Default::default()
+            }),
+        );
+        // This should not panic
+        display.show_skip_success(response);
+    }
+
     #[test]
     fn test_show_status_with_data() {
         let display = Display::new();
@@ -486,6 +633,7 @@ mod tests {
                 pomodoro_count: Some(2),
                 task_name: Some("Test task".to_string()),
                 duration: Some(1500),
+                ..Default::default()
             }),
         );
         // This should not panic
@@ -500,6 +648,72 @@ mod tests {
         display.show_status(response);
     }
 
+    #[test]
+    fn test_show_timer_list() {
+        let display = Display::new();
+        let response = IpcResponse::success(
+            "",
+            Some(ResponseData {
+                timers: Some(vec![
+                    ResponseData {
+                        timer: Some("default".to_string()),
+                        state: Some("stopped".to_string()),
+                        ..Default::default()
+                    },
+                    ResponseData {
+                        timer: Some("prep".to_string()),
+                        state: Some("working".to_string()),
+                        remaining_seconds: Some(540),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }),
+        );
+        // This should not panic
+        display.show_timer_list(response);
+        display.show_timer_list(IpcResponse::success("No timers", None));
+    }
+
+    #[test]
+    fn test_timer_list_line() {
+        let data = ResponseData {
+            timer: Some("prep".to_string()),
+            state: Some("working".to_string()),
+            remaining_seconds: Some(545),
+            task_name: Some("会議準備".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(timer_list_line(&data, 7), "prep     作業中  9:05  会議準備");
+
+        let data = ResponseData {
+            timer: Some("default".to_string()),
+            state: Some("stopped".to_string()),
+            remaining_seconds: Some(1500),
+            ..Default::default()
+        };
+        assert_eq!(timer_list_line(&data, 7), "default  停止中");
+    }
+
+    #[test]
+    fn test_hook_failure_line() {
+        let timestamp = chrono::Local
+            .with_ymd_and_hms(2026, 10, 19, 14, 5, 0)
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let failure = HookFailure {
+            hook: "slack".to_string(),
+            event: HookEvent::WorkEnd,
+            attempts: 3,
+            error: "HTTPステータスが成功ではありません: 503".to_string(),
+            timestamp,
+        };
+        assert_eq!(
+            hook_failure_line(&failure),
+            "slack (work_end)  3回実行  14:05  HTTPステータスが成功ではありません: 503"
+        );
+    }
+
     #[test]
     fn test_show_error() {
         let display = Display::new();
@@ -507,6 +721,33 @@ mod tests {
         display.show_error("Test error");
     }
 
+    #[test]
+    fn test_show_response_error() {
+        let display = Display::new();
+        let response = IpcResponse::error(ErrorCode::NotRunning, "タイマーは実行されていません");
+        // This should not panic
+        display.show_response_error(&response);
+    }
+
+    #[test]
+    fn test_error_message_uses_code() {
+        let response = IpcResponse::error(ErrorCode::AlreadyRunning, "daemon message");
+        assert!(error_message(&response).contains("pomodoro stop"));
+
+        let response = IpcResponse::error(ErrorCode::NotPaused, "daemon message");
+        assert!(error_message(&response).contains("pomodoro pause"));
+    }
+
+    #[test]
+    fn test_error_message_falls_back_to_daemon_message() {
+        let response = IpcResponse::error(ErrorCode::Internal, "詳細なエラー");
+        assert_eq!(error_message(&response), "詳細なエラー");
+
+        let mut response = IpcResponse::error(ErrorCode::Internal, "旧デーモンのエラー");
+        response.code = None;
+        assert_eq!(error_message(&response), "旧デーモンのエラー");
+    }
+
     #[test]
     fn test_show_install_success() {
         let display = Display::new();
@@ -564,6 +805,7 @@ mod tests {
                 pomodoro_count: None,
                 task_name: None,
                 duration: Some(1500),
+                ..Default::default()
             }),
         );
 
@@ -594,6 +836,7 @@ mod tests {
                 pomodoro_count: Some(1),
                 task_name: Some("コーディング".to_string()),
                 duration: Some(1500),
+                ..Default::default()
             }),
         );
         // Should not panic and should use new layout
@@ -612,6 +855,7 @@ mod tests {
                 pomodoro_count: Some(1),
                 task_name: Some("タスク".to_string()),
                 duration: None, // No duration - should use legacy display
+                ..Default::default()
             }),
         );
         display.show_status(response);
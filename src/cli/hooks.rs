@@ -0,0 +1,423 @@
+//! フック管理コマンド
+//!
+//! `pomodoro hooks` サブコマンド（一覧・検証・テスト実行・実行記録の表示）を処理する。
+//! いずれもデーモンを介さずに設定ファイル・実行記録ファイルを直接読み込む。
+
+use crate::cli::commands::{HooksArgs, HooksCommand, HooksLogsArgs, HooksTestArgs};
+use crate::hooks::{
+    HookConfig, HookConfigError, HookContext, HookDefinition, HookExecutor, HookKind, HookRunLog,
+    HookRunRecord,
+};
+use crate::types::{HookEvent, PomodoroConfig, TimerPhase};
+use anyhow::{bail, Context, Result};
+use chrono::{Local, Utc};
+use colored::Colorize;
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+pub async fn handle_hooks(args: HooksArgs) -> Result<()> {
+    match args.command {
+        HooksCommand::List { file } => handle_list(file),
+        HooksCommand::Validate { file } => handle_validate(file),
+        HooksCommand::Test(test_args) => handle_test(test_args).await,
+        HooksCommand::Logs(logs_args) => handle_logs(logs_args),
+    }
+}
+
+/// 設定ファイルのパス（省略時はデフォルトパス）
+fn config_path(file: Option<PathBuf>) -> Result<PathBuf> {
+    match file {
+        Some(path) => Ok(path),
+        None => Ok(HookConfig::default_config_path()?),
+    }
+}
+
+/// 設定ファイルを読み込む（ファイルが存在しない場合はNone）
+fn load_config(path: &Path) -> Result<Option<HookConfig>> {
+    match HookConfig::load_from_path(path) {
+        Ok(config) => Ok(Some(config)),
+        Err(HookConfigError::FileNotFound(_)) => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("{} を読み込めません", path.display())),
+    }
+}
+
+fn handle_list(file: Option<PathBuf>) -> Result<()> {
+    let path = config_path(file)?;
+    let Some(config) = load_config(&path)? else {
+        println!("フック設定ファイルがありません: {}", path.display());
+        return Ok(());
+    };
+
+    if config.hooks.is_empty() {
+        println!("登録されたフックはありません。");
+        return Ok(());
+    }
+
+    for event in HookEvent::ALL {
+        let mut hooks: Vec<&HookDefinition> = config
+            .hooks
+            .iter()
+            .filter(|hook| hook.event == event.as_str())
+            .collect();
+        if hooks.is_empty() {
+            continue;
+        }
+        // 実行順（優先度の高い順、同じ優先度は定義順）
+        hooks.sort_by_key(|hook| Reverse(hook.priority));
+
+        let mut header = event.as_str().bold().to_string();
+        if event.is_pre_transition() {
+            header.push_str(&format!(" {}", "[事前・拒否可能]".yellow()));
+        }
+        if config.is_parallel(&event) {
+            header.push_str(&format!(" {}", "[並列]".cyan()));
+        }
+        println!("{}", header);
+
+        for hook in hooks {
+            println!("  {}", format_hook(hook));
+        }
+    }
+
+    Ok(())
+}
+
+/// フック定義を1行に整形
+fn format_hook(hook: &HookDefinition) -> String {
+    let mut line = format!("{}  {}", hook.name.bold(), describe_target(hook).dimmed());
+    if hook.priority != 0 {
+        line.push_str(&format!("  priority={}", hook.priority));
+    }
+    line.push_str(&format!("  timeout={}s", hook.timeout_secs));
+    if hook.retries > 0 {
+        line.push_str(&format!(
+            "  retries={} (backoff {}s)",
+            hook.retries, hook.retry_backoff_secs
+        ));
+    }
+    if let Some(when) = &hook.when {
+        if let Ok(json) = serde_json::to_string(when) {
+            line.push_str(&format!("  when={}", json));
+        }
+    }
+    if !hook.enabled {
+        line.push_str(&format!("  {}", "[無効]".red()));
+    }
+    line
+}
+
+/// フックの実行内容（スクリプト・コマンド・URL）
+fn describe_target(hook: &HookDefinition) -> String {
+    if let (HookKind::Http, Some(http)) = (hook.kind, &hook.http) {
+        return format!("{} {}", http.method, http.url);
+    }
+    let program = match (&hook.shell, &hook.command) {
+        (Some(shell), _) => return format!("sh -c {:?}", shell),
+        (None, Some(command)) => command.clone(),
+        (None, None) => hook.script.display().to_string(),
+    };
+    std::iter::once(program)
+        .chain(hook.args.iter().cloned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn handle_validate(file: Option<PathBuf>) -> Result<()> {
+    let path = config_path(file)?;
+    if !path.exists() {
+        println!(
+            "フック設定ファイルがありません（フックは実行されません）: {}",
+            path.display()
+        );
+        return Ok(());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("{} を読み込めません", path.display()))?;
+    let config = HookConfig::parse_and_validate(&content)
+        .with_context(|| format!("{} は無効です", path.display()))?;
+
+    let enabled = config.hooks.iter().filter(|hook| hook.enabled).count();
+    println!(
+        "{} {}",
+        "✓".green().bold(),
+        format!(
+            "{} は有効です（{} フック、うち有効 {}）",
+            path.display(),
+            config.hooks.len(),
+            enabled
+        )
+        .green()
+    );
+
+    Ok(())
+}
+
+async fn handle_test(args: HooksTestArgs) -> Result<()> {
+    let path = config_path(args.file)?;
+    let Some(config) = load_config(&path)? else {
+        bail!("フック設定ファイルがありません: {}", path.display());
+    };
+
+    let hooks: Vec<HookDefinition> = config
+        .get_hooks_for_event(&args.event)
+        .into_iter()
+        .flatten()
+        .filter(|hook| args.hook.iter().all(|name| &hook.name == name))
+        .cloned()
+        .collect();
+    if hooks.is_empty() {
+        match &args.hook {
+            Some(name) => bail!(
+                "イベント {} に有効なフック '{}' はありません",
+                args.event.as_str(),
+                name
+            ),
+            None => {
+                println!(
+                    "イベント {} に登録された有効なフックはありません。",
+                    args.event.as_str()
+                );
+                return Ok(());
+            }
+        }
+    }
+
+    let context = synthetic_context(args.event, args.task);
+    let executor = HookExecutor::with_config(config);
+    let mut failures = 0;
+
+    println!("{} {}", "イベント:".bold(), args.event.as_str());
+    for hook in &hooks {
+        if let Some(when) = &hook.when {
+            if !when.matches(&context) {
+                println!(
+                    "{} {}  {}",
+                    "-".dimmed(),
+                    hook.name.bold(),
+                    "実行条件（when）を満たさないためスキップ".dimmed()
+                );
+                continue;
+            }
+        }
+
+        let record = executor.run_once(hook, &context).await;
+        if !record.success {
+            failures += 1;
+        }
+        println!("{}", format_record_summary(&record));
+        print_record_details(&record);
+    }
+
+    if failures > 0 {
+        bail!("{} 件のフックが失敗しました", failures);
+    }
+    Ok(())
+}
+
+/// テスト実行用の疑似的なコンテキスト（デフォルトのタイマー設定・1サイクル目）
+fn synthetic_context(event: HookEvent, task_name: Option<String>) -> HookContext {
+    let config = PomodoroConfig::default();
+    let phase = match event {
+        HookEvent::BeforeWorkStart | HookEvent::Stop => TimerPhase::Stopped,
+        HookEvent::Pause => TimerPhase::Paused,
+        HookEvent::BreakStart | HookEvent::BreakEnd | HookEvent::BeforeBreakEnd => {
+            TimerPhase::Breaking
+        }
+        HookEvent::LongBreakStart | HookEvent::LongBreakEnd | HookEvent::BeforeLongBreakEnd => {
+            TimerPhase::LongBreaking
+        }
+        HookEvent::WorkStart
+        | HookEvent::WorkEnd
+        | HookEvent::Resume
+        | HookEvent::BeforeBreakStart
+        | HookEvent::BeforeLongBreakStart => TimerPhase::Working,
+    };
+    let minutes = match phase {
+        TimerPhase::Breaking => config.break_minutes,
+        TimerPhase::LongBreaking => config.long_break_minutes,
+        _ => config.work_minutes,
+    };
+    let duration_secs = u64::from(minutes) * 60;
+    // 終了系のイベントはフェーズを最後まで経過した状態にする
+    let finished = matches!(
+        event,
+        HookEvent::WorkEnd
+            | HookEvent::BreakEnd
+            | HookEvent::LongBreakEnd
+            | HookEvent::BeforeBreakStart
+            | HookEvent::BeforeLongBreakStart
+            | HookEvent::BeforeBreakEnd
+            | HookEvent::BeforeLongBreakEnd
+    );
+    let elapsed_secs = if finished { duration_secs } else { 0 };
+
+    HookContext {
+        event,
+        task_name,
+        phase: phase.as_str().to_string(),
+        duration_secs,
+        elapsed_secs,
+        remaining_secs: duration_secs - elapsed_secs,
+        cycle: 1,
+        total_cycles: 4,
+        timestamp: Utc::now(),
+        session_id: Uuid::new_v4(),
+        config,
+    }
+}
+
+fn handle_logs(args: HooksLogsArgs) -> Result<()> {
+    let path = HookRunLog::default_path().context("ホームディレクトリが見つかりません")?;
+    let records: Vec<HookRunRecord> = HookRunLog::new(path)
+        .recent(usize::MAX)
+        .context("フックの実行記録を読み込めません")?
+        .into_iter()
+        .filter(|record| args.hook.iter().all(|name| &record.hook == name))
+        .filter(|record| !args.failed || !record.success)
+        .take(args.limit)
+        .collect();
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&records)?);
+        return Ok(());
+    }
+
+    if records.is_empty() {
+        println!("フックの実行記録はありません。");
+        return Ok(());
+    }
+
+    for record in &records {
+        let timestamp = record.timestamp.with_timezone(&Local);
+        println!(
+            "{}  {}  {}",
+            timestamp.format("%Y-%m-%d %H:%M:%S").to_string().dimmed(),
+            record.event.as_str(),
+            format_record_summary(record)
+        );
+        print_record_details(record);
+    }
+
+    Ok(())
+}
+
+/// 実行結果を1行に整形（HTTPフックはステータスコードを表示する）
+fn format_record_summary(record: &HookRunRecord) -> String {
+    let mark = if record.success {
+        "✓".green().bold()
+    } else {
+        "✗".red().bold()
+    };
+    let code = match (record.exit_code, record.kind) {
+        (Some(code), HookKind::Http) => format!("HTTP {}", code),
+        (Some(code), HookKind::Script) => format!("exit {}", code),
+        (None, _) => "exit -".to_string(),
+    };
+    let mut summary = format!(
+        "{} {}  ({}, {}ms)",
+        mark,
+        record.hook.bold(),
+        code,
+        record.duration_ms
+    );
+    if record.attempt > 1 {
+        summary.push_str(&format!("  {}回目", record.attempt));
+    }
+    summary
+}
+
+/// 失敗の内容と出力をインデントして表示
+fn print_record_details(record: &HookRunRecord) {
+    if let Some(error) = &record.error {
+        println!("    {} {}", "error:".red(), error);
+    }
+    if let Some(directive) = &record.directive {
+        if let Ok(json) = serde_json::to_string(directive) {
+            println!("    {} {}", "directive:".cyan(), json);
+        }
+    }
+    for (label, output) in [("stdout:", &record.stdout), ("stderr:", &record.stderr)] {
+        let output = output.trim_end();
+        if output.is_empty() {
+            continue;
+        }
+        println!("    {}", label.dimmed());
+        for line in output.lines() {
+            println!("      {}", line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_context_phases() {
+        let context = synthetic_context(HookEvent::WorkEnd, Some("設計".to_string()));
+        assert_eq!(context.phase, "working");
+        assert_eq!(context.duration_secs, 25 * 60);
+        assert_eq!(context.elapsed_secs, 25 * 60);
+        assert_eq!(context.remaining_secs, 0);
+        assert_eq!(context.task_name.as_deref(), Some("設計"));
+
+        let context = synthetic_context(HookEvent::LongBreakStart, None);
+        assert_eq!(context.phase, "long_breaking");
+        assert_eq!(context.duration_secs, 15 * 60);
+        assert_eq!(context.remaining_secs, 15 * 60);
+
+        let context = synthetic_context(HookEvent::BeforeWorkStart, None);
+        assert_eq!(context.phase, "stopped");
+        assert_eq!(context.cycle, 1);
+    }
+
+    #[test]
+    fn test_describe_target() {
+        let config = HookConfig::parse_and_validate(
+            r#"{
+                "version": "1.0",
+                "hooks": [
+                    {"name": "cmd", "event": "work_end", "command": "say", "args": ["{{task_name}}"]},
+                    {"name": "sh", "event": "work_end", "shell": "echo hi"},
+                    {"name": "http", "event": "work_end", "type": "http", "http": {"url": "https://example.com/hook"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(describe_target(&config.hooks[0]), "say {{task_name}}");
+        assert_eq!(describe_target(&config.hooks[1]), "sh -c \"echo hi\"");
+        assert_eq!(
+            describe_target(&config.hooks[2]),
+            "POST https://example.com/hook"
+        );
+    }
+
+    #[test]
+    fn test_format_record_summary() {
+        let mut record = HookRunRecord {
+            timestamp: Utc::now(),
+            event: HookEvent::WorkEnd,
+            hook: "slack".to_string(),
+            kind: HookKind::Http,
+            attempt: 1,
+            success: false,
+            exit_code: Some(500),
+            duration_ms: 42,
+            error: Some("HTTPステータスが成功ではありません".to_string()),
+            stdout: String::new(),
+            stderr: String::new(),
+            directive: None,
+        };
+
+        let summary = format_record_summary(&record);
+        assert!(summary.contains("slack"));
+        assert!(summary.contains("(HTTP 500, 42ms)"));
+
+        record.kind = HookKind::Script;
+        record.exit_code = None;
+        assert!(format_record_summary(&record).contains("(exit -, 42ms)"));
+    }
+}
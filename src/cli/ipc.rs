@@ -1,21 +1,28 @@
 //! IPCクライアント
 //!
 //! Unix Domain Socketを使用してデーモンサーバーと通信するクライアント。
+//! メッセージは改行区切りのJSON（1行1メッセージ）で送受信する。
 
 use anyhow::{Context, Result};
 use std::path::PathBuf;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::BufReader;
 use tokio::net::UnixStream;
 use tokio::time::{sleep, timeout, Duration};
 
 use crate::cli::commands::StartArgs;
-use crate::types::{IpcRequest, IpcResponse, StartParams};
+use crate::cli::spawn;
+use crate::hooks::executor::MAX_PRE_HOOK_TOTAL_SECS;
+use crate::paths;
+use crate::types::framing::{read_frame, write_frame, DEFAULT_MAX_MESSAGE_SIZE};
+use crate::types::{
+    IpcRequest, IpcResponse, StartParams, StreamMessage, TimerRequest, PROTOCOL_VERSION,
+};
 
 /// 接続タイムアウト（秒）
 const CONNECTION_TIMEOUT_SECS: u64 = 5;
 
-/// リクエストバッファサイズ
-const REQUEST_BUFFER_SIZE: usize = 4096;
+// 事前フックで拒否された理由を受け取れるよう、事前フックの合計時間より長く待つ
+const _: () = assert!(MAX_PRE_HOOK_TOTAL_SECS < CONNECTION_TIMEOUT_SECS);
 
 /// リトライ初期待機時間（ミリ秒）
 const INITIAL_RETRY_DELAY_MS: u64 = 100;
@@ -23,25 +30,128 @@ const INITIAL_RETRY_DELAY_MS: u64 = 100;
 /// リトライ最大待機時間（ミリ秒）
 const MAX_RETRY_DELAY_MS: u64 = 2000;
 
+/// 自動起動したデーモンの応答を待つリトライ回数（合計約5秒）
+const SPAWN_WAIT_RETRIES: u32 = 6;
+
 /// IPCクライアント
 ///
 /// Unix Domain Socketを使用してデーモンサーバーと通信する。
 pub struct IpcClient {
     socket_path: PathBuf,
+    max_message_size: usize,
+    auto_spawn: bool,
+    timer: Option<String>,
 }
 
 impl IpcClient {
     /// 新しいIPCクライアントを作成
+    ///
+    /// ソケットパスは `paths::socket_path` で決定する（`POMODORO_SOCKET`・`POMODORO_HOME` など）。
     pub fn new() -> Self {
+        Self::with_socket_path(paths::socket_path(None))
+    }
+
+    /// ソケットパスを指定してIPCクライアントを作成
+    pub fn with_socket_path(socket_path: PathBuf) -> Self {
         Self {
-            socket_path: get_socket_path(),
+            socket_path,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            auto_spawn: false,
+            timer: None,
         }
     }
 
-    /// ソケットパスを指定してIPCクライアントを作成（テスト用）
-    #[cfg(test)]
-    pub fn with_socket_path(socket_path: PathBuf) -> Self {
-        Self { socket_path }
+    /// 最大メッセージサイズを設定
+    ///
+    /// 送受信するメッセージ1件あたりの上限（バイト、改行を除く）。
+    /// デフォルトは `DEFAULT_MAX_MESSAGE_SIZE`。
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// デーモンの自動起動を設定
+    ///
+    /// 有効な場合、`ensure_daemon` はデーモンに接続できなければバックグラウンドで起動する。
+    /// デフォルトは無効。
+    pub fn with_auto_spawn(mut self, auto_spawn: bool) -> Self {
+        self.auto_spawn = auto_spawn;
+        self
+    }
+
+    /// 操作対象のタイマーを設定
+    ///
+    /// 以降のリクエストに `timer` フィールドとして付与する。
+    /// Noneの場合（デフォルト）はデーモンのデフォルトタイマーが対象になる。
+    pub fn with_timer(mut self, timer: Option<String>) -> Self {
+        self.timer = timer;
+        self
+    }
+
+    /// デーモンが起動していることを確認
+    ///
+    /// ソケットに接続できない場合、自動起動が有効であれば `pomodoro daemon` を
+    /// バックグラウンドで起動し、`send_request_with_retry` で応答を待つ。
+    /// 自動起動が無効な場合は何もしない（後続のリクエストが接続エラーになる）。
+    ///
+    /// # Returns
+    ///
+    /// デーモンを起動した場合はtrue
+    ///
+    /// # Errors
+    ///
+    /// - デーモンの起動に失敗した場合
+    /// - 起動したデーモンが時間内に応答しなかった場合
+    pub async fn ensure_daemon(&self) -> Result<bool> {
+        if !self.auto_spawn {
+            return Ok(false);
+        }
+
+        match UnixStream::connect(&self.socket_path).await {
+            Ok(_) => return Ok(false),
+            // ソケットがない、または応答しないソケットが残っている場合のみ起動する
+            Err(e)
+                if !matches!(
+                    e.kind(),
+                    std::io::ErrorKind::NotFound | std::io::ErrorKind::ConnectionRefused
+                ) =>
+            {
+                return Ok(false)
+            }
+            Err(_) => {}
+        }
+
+        let log_path = spawn::spawn_daemon(&self.socket_path)?;
+        self.send_request_with_retry(
+            IpcRequest::Hello {
+                protocol_version: Some(PROTOCOL_VERSION),
+            },
+            SPAWN_WAIT_RETRIES,
+        )
+        .await
+        .with_context(|| format!("Daemon did not start (see {})", log_path.display()))?;
+
+        Ok(true)
+    }
+
+    /// デーモンに接続
+    ///
+    /// 1つの接続で複数のリクエストを順に送信できるIpcSessionを返す。
+    /// 接続のタイムアウトは5秒。
+    pub async fn connect(&self) -> Result<IpcSession> {
+        let stream = timeout(
+            Duration::from_secs(CONNECTION_TIMEOUT_SECS),
+            UnixStream::connect(&self.socket_path),
+        )
+        .await
+        .context("Request timed out")?
+        .context("Failed to connect to daemon")?;
+
+        Ok(IpcSession {
+            stream: BufReader::new(stream),
+            max_message_size: self.max_message_size,
+            timer: self.timer.clone(),
+        })
     }
 
     /// リクエストを送信
@@ -62,6 +172,7 @@ impl IpcClient {
     /// - ソケット接続に失敗した場合
     /// - リクエスト送信に失敗した場合
     /// - レスポンス受信に失敗した場合
+    /// - メッセージが最大サイズを超えた場合
     /// - タイムアウトした場合
     pub async fn send_request(&self, req: IpcRequest) -> Result<IpcResponse> {
         // タイムアウト付きで実行
@@ -75,32 +186,8 @@ impl IpcClient {
 
     /// リクエストを送信（内部実装）
     async fn send_request_internal(&self, req: IpcRequest) -> Result<IpcResponse> {
-        // ソケットに接続
-        let mut stream = UnixStream::connect(&self.socket_path)
-            .await
-            .context("Failed to connect to daemon")?;
-
-        // リクエストをJSON形式でシリアライズ
-        let request_json = serde_json::to_string(&req).context("Failed to serialize request")?;
-
-        // リクエストを送信
-        stream
-            .write_all(request_json.as_bytes())
-            .await
-            .context("Failed to send request")?;
-
-        // レスポンスを受信
-        let mut buffer = vec![0u8; REQUEST_BUFFER_SIZE];
-        let n = stream
-            .read(&mut buffer)
-            .await
-            .context("Failed to read response")?;
-
-        // レスポンスをデシリアライズ
-        let response: IpcResponse =
-            serde_json::from_slice(&buffer[..n]).context("Failed to deserialize response")?;
-
-        Ok(response)
+        let mut session = self.connect().await?;
+        session.request_internal(&req).await
     }
 
     /// リトライ付きリクエスト送信
@@ -173,27 +260,153 @@ impl IpcClient {
         self.send_request(IpcRequest::Stop).await
     }
 
+    /// 現在のフェーズをスキップ
+    pub async fn skip(&self) -> Result<IpcResponse> {
+        self.send_request(IpcRequest::Skip).await
+    }
+
+    /// デーモンを終了
+    pub async fn shutdown(&self) -> Result<IpcResponse> {
+        self.send_request(IpcRequest::Shutdown).await
+    }
+
+    /// 設定の再読み込みを要求
+    pub async fn reload(&self) -> Result<IpcResponse> {
+        self.send_request(IpcRequest::Reload).await
+    }
+
+    /// すべてのタイマーの状態を取得
+    pub async fn list(&self) -> Result<IpcResponse> {
+        self.send_request(IpcRequest::List).await
+    }
+
     /// ステータスを取得
     pub async fn status(&self) -> Result<IpcResponse> {
         self.send_request(IpcRequest::Status).await
     }
+
+    /// ハンドシェイク
+    ///
+    /// このクライアントのプロトコルバージョンを送信し、デーモンのバージョンと対応機能を取得する。
+    /// バージョンに互換性がない場合は `ErrorCode::UnsupportedProtocolVersion` のエラーレスポンスが返る。
+    pub async fn hello(&self) -> Result<IpcResponse> {
+        self.send_request(IpcRequest::Hello {
+            protocol_version: Some(PROTOCOL_VERSION),
+        })
+        .await
+    }
+
+    /// イベントを購読
+    ///
+    /// デーモンとの接続を維持したまま、TimerEventと状態スナップショットを受信する。
+    /// 接続とリクエスト送信のタイムアウトは5秒。
+    pub async fn subscribe(&self) -> Result<EventSubscription> {
+        let mut session = self.connect().await?;
+
+        timeout(
+            Duration::from_secs(CONNECTION_TIMEOUT_SECS),
+            session.write_request(&IpcRequest::Subscribe),
+        )
+        .await
+        .context("Request timed out")??;
+
+        Ok(EventSubscription { session })
+    }
 }
 
-impl Default for IpcClient {
-    fn default() -> Self {
-        Self::new()
+/// デーモンとの接続
+///
+/// `IpcClient::connect` で開いた接続上で、リクエストを順に送信してレスポンスを受信する。
+pub struct IpcSession {
+    stream: BufReader<UnixStream>,
+    max_message_size: usize,
+    /// 操作対象のタイマー（`IpcClient::with_timer`）
+    timer: Option<String>,
+}
+
+impl IpcSession {
+    /// リクエストを送信
+    ///
+    /// 同じ接続上でリクエストを送信し、レスポンスを受信する。
+    /// タイムアウトは5秒。
+    pub async fn request(&mut self, req: IpcRequest) -> Result<IpcResponse> {
+        timeout(
+            Duration::from_secs(CONNECTION_TIMEOUT_SECS),
+            self.request_internal(&req),
+        )
+        .await
+        .context("Request timed out")?
+    }
+
+    /// リクエストを送信（内部実装）
+    async fn request_internal(&mut self, req: &IpcRequest) -> Result<IpcResponse> {
+        self.write_request(req).await?;
+
+        let frame = self
+            .read_frame()
+            .await
+            .context("Failed to read response")?
+            .context("Connection closed by daemon")?;
+
+        let response: IpcResponse =
+            serde_json::from_slice(&frame).context("Failed to deserialize response")?;
+
+        Ok(response)
+    }
+
+    /// リクエストを1行のJSONとして書き込む
+    ///
+    /// 対象タイマーが設定されている場合は `timer` フィールドを付与する。
+    async fn write_request(&mut self, req: &IpcRequest) -> Result<()> {
+        let request = TimerRequest {
+            timer: self.timer.clone(),
+            request: req.clone(),
+        };
+        let request_json = serde_json::to_vec(&request).context("Failed to serialize request")?;
+
+        write_frame(&mut self.stream, &request_json, self.max_message_size)
+            .await
+            .context("Failed to send request")
+    }
+
+    /// 1メッセージ分のフレームを読み取る
+    async fn read_frame(&mut self) -> Result<Option<Vec<u8>>> {
+        Ok(read_frame(&mut self.stream, self.max_message_size).await?)
     }
 }
 
-/// ソケットパスを取得
+/// イベント購読
 ///
-/// `~/.pomodoro/pomodoro.sock` を返す。
-fn get_socket_path() -> PathBuf {
-    let home = std::env::var("HOME")
-        .or_else(|_| std::env::var("USERPROFILE"))
-        .unwrap_or_else(|_| ".".to_string());
+/// `IpcClient::subscribe` で開いた接続から、デーモンが送信する
+/// StreamMessage（1行1メッセージのJSON）を順に読み取る。
+pub struct EventSubscription {
+    session: IpcSession,
+}
 
-    PathBuf::from(home).join(".pomodoro").join("pomodoro.sock")
+impl EventSubscription {
+    /// 次のメッセージを受信
+    ///
+    /// デーモンが接続を閉じた場合は `Ok(None)` を返す。
+    pub async fn next(&mut self) -> Result<Option<StreamMessage>> {
+        let Some(frame) = self
+            .session
+            .read_frame()
+            .await
+            .context("Failed to read stream message")?
+        else {
+            return Ok(None);
+        };
+
+        let message =
+            serde_json::from_slice(&frame).context("Failed to deserialize stream message")?;
+        Ok(Some(message))
+    }
+}
+
+impl Default for IpcClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // ============================================================================
@@ -206,6 +419,7 @@ mod tests {
     use crate::types::ResponseData;
     use std::path::Path;
     use tempfile::TempDir;
+    use tokio::io::AsyncWriteExt;
     use tokio::net::UnixListener;
 
     /// モックサーバーを起動
@@ -220,34 +434,38 @@ mod tests {
 
     /// モックサーバーがリクエストを受信してレスポンスを返す
     async fn mock_server_respond(listener: UnixListener, response: IpcResponse) {
-        let (mut stream, _) = listener.accept().await.unwrap();
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
 
         // リクエストを読み取る（検証はしない）
-        let mut buffer = vec![0u8; REQUEST_BUFFER_SIZE];
-        let _ = stream.read(&mut buffer).await.unwrap();
+        let _ = read_frame(&mut stream, DEFAULT_MAX_MESSAGE_SIZE)
+            .await
+            .unwrap();
 
         // レスポンスを返す
-        let response_json = serde_json::to_string(&response).unwrap();
-        stream.write_all(response_json.as_bytes()).await.unwrap();
-    }
-
-    #[test]
-    fn test_get_socket_path() {
-        let path = get_socket_path();
-        assert!(path.to_str().unwrap().contains(".pomodoro"));
-        assert!(path.to_str().unwrap().ends_with("pomodoro.sock"));
+        let response_json = serde_json::to_vec(&response).unwrap();
+        write_frame(&mut stream, &response_json, DEFAULT_MAX_MESSAGE_SIZE)
+            .await
+            .unwrap();
     }
 
     #[test]
     fn test_ipc_client_new() {
         let client = IpcClient::new();
-        assert!(client.socket_path.to_str().unwrap().contains(".pomodoro"));
+        assert_eq!(client.socket_path, paths::socket_path(None));
+        assert!(client.socket_path.ends_with("pomodoro.sock"));
     }
 
     #[test]
     fn test_ipc_client_default() {
         let client = IpcClient::default();
-        assert!(client.socket_path.to_str().unwrap().contains(".pomodoro"));
+        assert!(client.socket_path.ends_with("pomodoro.sock"));
+    }
+
+    #[test]
+    fn test_ipc_client_with_socket_path() {
+        let client = IpcClient::with_socket_path(PathBuf::from("/tmp/custom.sock"));
+        assert_eq!(client.socket_path, PathBuf::from("/tmp/custom.sock"));
     }
 
     #[tokio::test]
@@ -311,6 +529,32 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_ensure_daemon_disabled_does_not_spawn() {
+        let temp_dir = TempDir::new().unwrap();
+        let socket_path = temp_dir.path().join("nonexistent.sock");
+
+        let client = IpcClient::with_socket_path(socket_path);
+        assert!(!client.auto_spawn);
+
+        let spawned = client.ensure_daemon().await.unwrap();
+        assert!(!spawned);
+        assert!(!temp_dir.path().join("daemon.log").exists());
+    }
+
+    #[tokio::test]
+    async fn test_ensure_daemon_running_does_not_spawn() {
+        let temp_dir = TempDir::new().unwrap();
+        let socket_path = temp_dir.path().join("test.sock");
+
+        let _listener = start_mock_server(&socket_path).await;
+        let client = IpcClient::with_socket_path(socket_path).with_auto_spawn(true);
+
+        let spawned = client.ensure_daemon().await.unwrap();
+        assert!(!spawned);
+        assert!(!temp_dir.path().join("daemon.log").exists());
+    }
+
     #[tokio::test]
     async fn test_send_request_with_retry_success_first_try() {
         let temp_dir = TempDir::new().unwrap();
@@ -414,6 +658,219 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_subscribe_receives_messages() {
+        use crate::types::TimerEvent;
+
+        let temp_dir = TempDir::new().unwrap();
+        let socket_path = temp_dir.path().join("test_subscribe.sock");
+
+        let listener = start_mock_server(&socket_path).await;
+        let client = IpcClient::with_socket_path(socket_path.clone());
+
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+
+            let frame = read_frame(&mut stream, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap()
+                .unwrap();
+            let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
+            assert!(matches!(request, IpcRequest::Subscribe));
+
+            let lines = concat!(
+                r#"{"type":"snapshot","data":{"state":"working","remainingSeconds":10}}"#,
+                "\n",
+                r#"{"type":"event","event":{"event":"tick","remainingSeconds":9}}"#,
+                "\n",
+            );
+            stream.write_all(lines.as_bytes()).await.unwrap();
+        });
+
+        let mut subscription = client.subscribe().await.unwrap();
+
+        let first = subscription.next().await.unwrap().unwrap();
+        assert!(matches!(first, StreamMessage::Snapshot { data }
+            if data.remaining_seconds == Some(10)));
+
+        let second = subscription.next().await.unwrap().unwrap();
+        assert!(matches!(
+            second,
+            StreamMessage::Event {
+                event: TimerEvent::Tick {
+                    remaining_seconds: 9
+                }
+            }
+        ));
+
+        server_handle.await.unwrap();
+
+        // サーバーが接続を閉じたらNoneを返す
+        assert!(subscription.next().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_with_timer_sends_timer_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let socket_path = temp_dir.path().join("test_timer.sock");
+
+        let listener = start_mock_server(&socket_path).await;
+        let client =
+            IpcClient::with_socket_path(socket_path.clone()).with_timer(Some("prep".to_string()));
+
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let frame = read_frame(&mut stream, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap()
+                .unwrap();
+            let request: TimerRequest = serde_json::from_slice(&frame).unwrap();
+
+            let json = serde_json::to_vec(&IpcResponse::success("", None)).unwrap();
+            write_frame(&mut stream, &json, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap();
+            request
+        });
+
+        client.pause().await.unwrap();
+        let request = server_handle.await.unwrap();
+
+        assert_eq!(request.timer.as_deref(), Some("prep"));
+        assert!(matches!(request.request, IpcRequest::Pause));
+    }
+
+    #[tokio::test]
+    async fn test_hello_sends_protocol_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let socket_path = temp_dir.path().join("test_hello.sock");
+
+        let listener = start_mock_server(&socket_path).await;
+        let client = IpcClient::with_socket_path(socket_path.clone());
+
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let frame = read_frame(&mut stream, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap()
+                .unwrap();
+            let request: IpcRequest = serde_json::from_slice(&frame).unwrap();
+
+            let response = IpcResponse::success(
+                "",
+                Some(ResponseData {
+                    version: Some("0.5.0".to_string()),
+                    protocol_version: Some(PROTOCOL_VERSION),
+                    capabilities: Some(vec!["subscribe".to_string()]),
+                    ..Default::default()
+                }),
+            );
+            let json = serde_json::to_vec(&response).unwrap();
+            write_frame(&mut stream, &json, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap();
+            request
+        });
+
+        let response = client.hello().await.unwrap();
+        let request = server_handle.await.unwrap();
+
+        assert!(matches!(
+            request,
+            IpcRequest::Hello {
+                protocol_version: Some(PROTOCOL_VERSION)
+            }
+        ));
+        let data = response.data.unwrap();
+        assert_eq!(data.protocol_version, Some(PROTOCOL_VERSION));
+        assert_eq!(data.capabilities, Some(vec!["subscribe".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_session_multiple_requests() {
+        let temp_dir = TempDir::new().unwrap();
+        let socket_path = temp_dir.path().join("test_session.sock");
+
+        let listener = start_mock_server(&socket_path).await;
+        let client = IpcClient::with_socket_path(socket_path.clone());
+
+        // 1つの接続で受信したリクエストごとにレスポンスを返す
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let mut count = 0;
+            while let Some(frame) = read_frame(&mut stream, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap()
+            {
+                let _: IpcRequest = serde_json::from_slice(&frame).unwrap();
+                count += 1;
+                let response = IpcResponse::success(format!("response {}", count), None);
+                let json = serde_json::to_vec(&response).unwrap();
+                write_frame(&mut stream, &json, DEFAULT_MAX_MESSAGE_SIZE)
+                    .await
+                    .unwrap();
+            }
+            count
+        });
+
+        let mut session = client.connect().await.unwrap();
+        let first = session.request(IpcRequest::Status).await.unwrap();
+        let second = session.request(IpcRequest::Pause).await.unwrap();
+        drop(session);
+
+        assert_eq!(first.message, "response 1");
+        assert_eq!(second.message, "response 2");
+        assert_eq!(server_handle.await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_request_response_too_large() {
+        let temp_dir = TempDir::new().unwrap();
+        let socket_path = temp_dir.path().join("test_too_large.sock");
+
+        let listener = start_mock_server(&socket_path).await;
+        let client = IpcClient::with_socket_path(socket_path.clone()).with_max_message_size(64);
+
+        let response = IpcResponse::success("x".repeat(100), None);
+        let server_handle = tokio::spawn(mock_server_respond(listener, response));
+
+        let result = client.send_request(IpcRequest::Status).await;
+        server_handle.await.unwrap();
+
+        let err_msg = format!("{:#}", result.unwrap_err());
+        assert!(err_msg.contains("上限（64バイト）"));
+    }
+
+    #[tokio::test]
+    async fn test_send_request_request_too_large() {
+        let temp_dir = TempDir::new().unwrap();
+        let socket_path = temp_dir.path().join("test_request_too_large.sock");
+
+        let listener = start_mock_server(&socket_path).await;
+        let client = IpcClient::with_socket_path(socket_path.clone()).with_max_message_size(32);
+
+        let server_handle = tokio::spawn(async move {
+            let _ = listener.accept().await.unwrap();
+        });
+
+        let result = client
+            .send_request(IpcRequest::Start {
+                params: StartParams {
+                    task_name: Some("x".repeat(100)),
+                    ..Default::default()
+                },
+            })
+            .await;
+        server_handle.await.unwrap();
+
+        let err_msg = format!("{:#}", result.unwrap_err());
+        assert!(err_msg.contains("上限（32バイト）"));
+    }
+
     #[tokio::test]
     async fn test_status_command() {
         let temp_dir = TempDir::new().unwrap();
@@ -430,6 +887,7 @@ mod tests {
                 pomodoro_count: Some(2),
                 task_name: Some("Test".to_string()),
                 duration: Some(1500),
+                ..Default::default()
             }),
         );
         let server_handle = tokio::spawn(mock_server_respond(listener, response.clone()));
@@ -0,0 +1,225 @@
+//! 作業履歴コマンド
+//!
+//! `pomodoro log` サブコマンド（一覧・手動追加・編集・削除）を処理する。
+
+use crate::cli::commands::{LogAddArgs, LogArgs, LogCommand, LogEditArgs, LogListArgs};
+use crate::history::{HistoryEntry, HistoryStore, HistoryUpdate};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use colored::Colorize;
+
+pub fn handle_log(args: LogArgs) -> Result<()> {
+    match args.command {
+        LogCommand::List(list_args) => handle_list(list_args),
+        LogCommand::Add(add_args) => handle_add(add_args),
+        LogCommand::Edit(edit_args) => handle_edit(edit_args),
+        LogCommand::Delete { id } => handle_delete(id),
+    }
+}
+
+fn load_store() -> Result<HistoryStore> {
+    HistoryStore::load().context("Failed to load history")
+}
+
+/// 履歴をロックした上で読み込み、変更して保存する（デーモンの記録と競合しないように）
+fn update_store<T>(
+    f: impl FnOnce(&mut HistoryStore) -> Result<T, crate::history::HistoryError>,
+) -> Result<(HistoryStore, T)> {
+    HistoryStore::update(f).context("Failed to update history")
+}
+
+fn handle_list(args: LogListArgs) -> Result<()> {
+    let store = load_store()?;
+    let entries: Vec<&HistoryEntry> = match args.date {
+        Some(date) => store.entries_on(date),
+        None => store.entries().iter().collect(),
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("作業履歴はありません。");
+        return Ok(());
+    }
+
+    println!("{}", "作業履歴:".bold());
+    for entry in &entries {
+        println!("  {}", format_entry(entry));
+    }
+
+    let summary = HistoryStore::summarize(entries.iter().copied());
+    println!(
+        "合計: {} ポモドーロ / {}分（うち手動 {}件）",
+        summary.count,
+        summary.total_secs / 60,
+        summary.manual_count
+    );
+
+    Ok(())
+}
+
+fn handle_add(args: LogAddArgs) -> Result<()> {
+    let now = Local::now();
+    let started_at = parse_start_time(&args.start, now).map_err(anyhow::Error::msg)?;
+
+    let (store, id) = update_store(|store| {
+        store.add_manual(
+            args.task,
+            started_at,
+            args.duration,
+            now.with_timezone(&Utc),
+        )
+    })?;
+
+    println!(
+        "{} {}",
+        "✓".green().bold(),
+        "作業履歴を追加しました".green()
+    );
+    if let Some(entry) = store.get(id) {
+        println!("  {}", format_entry(entry));
+    }
+
+    Ok(())
+}
+
+fn handle_edit(args: LogEditArgs) -> Result<()> {
+    let now = Local::now();
+    let started_at = args
+        .start
+        .as_deref()
+        .map(|s| parse_start_time(s, now))
+        .transpose()
+        .map_err(anyhow::Error::msg)?;
+
+    let update = HistoryUpdate {
+        task_name: args.task,
+        started_at,
+        duration_secs: args.duration,
+    };
+    let (store, ()) = update_store(|store| store.edit(args.id, update, now.with_timezone(&Utc)))?;
+
+    println!(
+        "{} {}",
+        "✓".green().bold(),
+        "作業履歴を更新しました".green()
+    );
+    if let Some(entry) = store.get(args.id) {
+        println!("  {}", format_entry(entry));
+    }
+
+    Ok(())
+}
+
+fn handle_delete(id: u32) -> Result<()> {
+    let (_, removed) = update_store(|store| store.delete(id))?;
+
+    println!(
+        "{} {}",
+        "✓".green().bold(),
+        "作業履歴を削除しました".green()
+    );
+    println!("  {}", format_entry(&removed));
+
+    Ok(())
+}
+
+/// 履歴エントリを1行に整形
+fn format_entry(entry: &HistoryEntry) -> String {
+    let start = entry.started_at.with_timezone(&Local);
+    let end = entry.ended_at().with_timezone(&Local);
+    let mut line = format!(
+        "#{:<4} {} {}-{} {:>3}分",
+        entry.id,
+        start.format("%Y-%m-%d"),
+        start.format("%H:%M"),
+        end.format("%H:%M"),
+        entry.duration_secs / 60
+    );
+    if let Some(task) = &entry.task_name {
+        line.push_str(&format!("  {}", task.cyan()));
+    }
+    if let Some(timer) = &entry.timer {
+        line.push_str(&format!("  {}", format!("[{}]", timer).magenta()));
+    }
+    if entry.manual {
+        line.push_str(&format!("  {}", "[手動]".yellow()));
+    }
+    line
+}
+
+/// 開始時刻をパース
+///
+/// `HH:MM`（`now` と同じ日）、`YYYY-MM-DD HH:MM`、RFC 3339 形式を受け付ける。
+fn parse_start_time(s: &str, now: DateTime<Local>) -> Result<DateTime<Utc>, String> {
+    const INVALID: &str = "開始時刻は HH:MM または YYYY-MM-DD HH:MM 形式で指定してください";
+
+    let s = s.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(s) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+
+    let naive = if let Ok(time) = NaiveTime::parse_from_str(s, "%H:%M") {
+        now.date_naive().and_time(time)
+    } else if let Ok(datetime) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M") {
+        datetime
+    } else {
+        return Err(INVALID.to_string());
+    };
+
+    Local
+        .from_local_datetime(&naive)
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or_else(|| format!("開始時刻を一意に解決できません: {}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, Timelike};
+
+    #[test]
+    fn test_parse_start_time_hh_mm_uses_today() {
+        let now = Local.with_ymd_and_hms(2026, 1, 10, 18, 30, 0).unwrap();
+        let parsed = parse_start_time("14:00", now)
+            .unwrap()
+            .with_timezone(&Local);
+
+        assert_eq!(parsed.date_naive(), now.date_naive());
+        assert_eq!(parsed.hour(), 14);
+        assert_eq!(parsed.minute(), 0);
+    }
+
+    #[test]
+    fn test_parse_start_time_with_date() {
+        let now = Local.with_ymd_and_hms(2026, 1, 10, 18, 30, 0).unwrap();
+        let parsed = parse_start_time("2026-01-09 09:15", now)
+            .unwrap()
+            .with_timezone(&Local);
+
+        assert_eq!(
+            parsed.date_naive(),
+            NaiveDate::from_ymd_opt(2026, 1, 9).unwrap()
+        );
+        assert_eq!(parsed.hour(), 9);
+        assert_eq!(parsed.minute(), 15);
+    }
+
+    #[test]
+    fn test_parse_start_time_rfc3339() {
+        let now = Local::now();
+        let parsed = parse_start_time("2026-01-09T09:15:00Z", now).unwrap();
+        assert_eq!(parsed, Utc.with_ymd_and_hms(2026, 1, 9, 9, 15, 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_start_time_invalid() {
+        let now = Local::now();
+        assert!(parse_start_time("yesterday", now).is_err());
+        assert!(parse_start_time("25:00", now).is_err());
+    }
+}
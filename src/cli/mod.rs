@@ -3,13 +3,16 @@ pub mod animation;
 pub mod commands;
 pub mod completions;
 pub mod display;
+pub mod hooks;
 pub mod ipc;
+pub mod log;
 pub mod sound;
+pub mod spawn;
 
 pub use commands::{Cli, Commands, StartArgs};
 pub use completions::generate_completions;
 pub use display::{Display, EnhancedDisplayState};
-pub use ipc::IpcClient;
+pub use ipc::{EventSubscription, IpcClient};
 pub mod layout;
 pub mod terminal;
 pub mod time_format;
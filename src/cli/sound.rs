@@ -1,4 +1,5 @@
 use crate::cli::commands::ConfigArgs;
+use crate::cli::spawn::ClientConfig;
 use crate::sound::{config::SoundConfig, SoundSource};
 use anyhow::Result;
 use colored::Colorize;
@@ -23,7 +24,12 @@ pub fn handle_sounds() -> Result<()> {
     Ok(())
 }
 
-pub fn handle_config(args: ConfigArgs) -> Result<()> {
+/// サウンド設定・クライアント設定を更新して表示する
+///
+/// # Returns
+///
+/// サウンド設定を更新した場合はtrue（起動中のデーモンへの反映に使用）
+pub fn handle_config(args: ConfigArgs) -> Result<bool> {
     let mut config =
         SoundConfig::load().map_err(|e| anyhow::anyhow!("Failed to load config: {}", e))?;
     let mut updated = false;
@@ -38,10 +44,18 @@ pub fn handle_config(args: ConfigArgs) -> Result<()> {
         updated = true;
     }
 
+    let mut client_config = ClientConfig::load()?;
+    if let Some(auto_spawn) = args.auto_spawn {
+        client_config.auto_spawn = auto_spawn;
+        client_config.save()?;
+    }
+
     if updated {
         config
             .save()
             .map_err(|e| anyhow::anyhow!("Failed to save config: {}", e))?;
+    }
+    if updated || args.auto_spawn.is_some() {
         println!("{}", "設定を更新しました。".green());
     }
 
@@ -49,6 +63,15 @@ pub fn handle_config(args: ConfigArgs) -> Result<()> {
     println!("{}", "現在のサウンド設定:".bold());
     println!("  作業完了音: {}", config.work_end_sound);
     println!("  休憩完了音: {}", config.break_end_sound);
+    println!("{}", "デーモン:".bold());
+    println!(
+        "  自動起動: {}",
+        if client_config.auto_spawn {
+            "有効"
+        } else {
+            "無効"
+        }
+    );
 
-    Ok(())
+    Ok(updated)
 }
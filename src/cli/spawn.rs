@@ -0,0 +1,181 @@
+//! デーモンの自動起動
+//!
+//! デーモンに接続できない場合に、CLIから `pomodoro daemon` をバックグラウンドで起動する。
+//! 起動したデーモンは端末のセッションから切り離し、出力は `daemon.log` に追記する。
+//! 自動起動の有無は `~/.pomodoro/client-config.json` と `--no-spawn` フラグで制御する。
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::os::unix::fs::DirBuilderExt;
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// デーモンのログファイル名（ソケットと同じディレクトリに作成）
+const DAEMON_LOG_FILE_NAME: &str = "daemon.log";
+
+/// CLIクライアント設定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientConfig {
+    /// デーモンに接続できない場合に自動起動するかどうか（デフォルト: 有効）
+    #[serde(default = "default_auto_spawn")]
+    pub auto_spawn: bool,
+}
+
+fn default_auto_spawn() -> bool {
+    true
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            auto_spawn: default_auto_spawn(),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// デフォルトの設定ファイルパス（データディレクトリの `client-config.json`）を取得
+    fn get_config_path() -> Option<PathBuf> {
+        crate::paths::data_file("client-config.json")
+    }
+
+    /// 設定ファイルから読み込む
+    /// ファイルが存在しない場合はデフォルト値を返す
+    pub fn load() -> Result<Self> {
+        match Self::get_config_path() {
+            Some(path) => Self::load_from_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// 設定ファイルに保存する
+    pub fn save(&self) -> Result<()> {
+        let path = Self::get_config_path().context("Could not determine home directory")?;
+        self.save_to_file(&path)
+    }
+
+    /// 指定されたパスから読み込む
+    pub fn load_from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path).context("Failed to read client config")?;
+        serde_json::from_str(&content).context("Failed to parse client config")
+    }
+
+    /// 指定されたパスに保存する
+    pub fn save_to_file(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+/// デーモンのログファイルパスを取得
+pub fn daemon_log_path(socket_path: &Path) -> PathBuf {
+    socket_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(DAEMON_LOG_FILE_NAME)
+}
+
+/// デーモンをバックグラウンドで起動
+///
+/// 現在の実行ファイルを `daemon` サブコマンドで起動する（CLIと同じソケットを `--socket` で渡す）。
+/// 子プロセスは新しいセッションで実行し（端末を閉じても終了しない）、
+/// 標準出力・標準エラーはログファイルに追記する。起動完了は待たない。
+///
+/// # Returns
+///
+/// ログファイルのパス
+pub fn spawn_daemon(socket_path: &Path) -> Result<PathBuf> {
+    let exe = std::env::current_exe().context("Failed to locate pomodoro executable")?;
+    let log_path = daemon_log_path(socket_path);
+    if let Some(parent) = log_path.parent() {
+        // ソケットと同じディレクトリのため、デーモンと同じく所有者のみに制限する
+        fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(parent)
+            .context("Failed to create log directory")?;
+    }
+
+    let log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+        .context("Failed to open daemon log")?;
+    let log_err = log.try_clone().context("Failed to open daemon log")?;
+
+    let mut command = Command::new(exe);
+    command
+        .arg("daemon")
+        .arg("--socket")
+        .arg(socket_path)
+        .stdin(Stdio::null())
+        .stdout(log)
+        .stderr(log_err);
+    // SAFETY: setsidはasync-signal-safeで、fork後のプロセスでのみ実行される
+    unsafe {
+        command.pre_exec(|| {
+            libc::setsid();
+            Ok(())
+        });
+    }
+
+    command.spawn().context("Failed to spawn daemon")?;
+
+    Ok(log_path)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_client_config_default_enables_auto_spawn() {
+        assert!(ClientConfig::default().auto_spawn);
+    }
+
+    #[test]
+    fn test_client_config_load_non_existent() {
+        let dir = tempdir().unwrap();
+        let config = ClientConfig::load_from_file(&dir.path().join("none.json")).unwrap();
+        assert_eq!(config, ClientConfig::default());
+    }
+
+    #[test]
+    fn test_client_config_save_and_load() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("client-config.json");
+        let config = ClientConfig { auto_spawn: false };
+
+        config.save_to_file(&path).unwrap();
+        assert_eq!(ClientConfig::load_from_file(&path).unwrap(), config);
+    }
+
+    #[test]
+    fn test_client_config_missing_field_uses_default() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("client-config.json");
+        fs::write(&path, "{}").unwrap();
+
+        assert!(ClientConfig::load_from_file(&path).unwrap().auto_spawn);
+    }
+
+    #[test]
+    fn test_daemon_log_path() {
+        let path = daemon_log_path(Path::new("/tmp/pomodoro/pomodoro.sock"));
+        assert_eq!(path, PathBuf::from("/tmp/pomodoro/daemon.log"));
+    }
+}
@@ -0,0 +1,399 @@
+//! デーモンへの接続とリクエスト送信
+//!
+//! 1リクエストごとにUnixソケットへ接続し、レスポンスを型付きの値に変換して返す。
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tokio::io::BufReader;
+use tokio::net::UnixStream;
+use tokio::time::timeout;
+
+use super::{ClientError, DaemonInfo, StartOptions, TimerStatus, Update};
+#[cfg(feature = "daemon")]
+use crate::hooks::executor::MAX_PRE_HOOK_TOTAL_SECS;
+use crate::paths;
+use crate::types::framing::{read_frame, write_frame, DEFAULT_MAX_MESSAGE_SIZE};
+use crate::types::{
+    ErrorCode, IpcRequest, IpcResponse, ResponseData, StreamMessage, TimerRequest, PROTOCOL_VERSION,
+};
+
+/// デフォルトのタイムアウト（接続・リクエストごと）
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+// 事前フックで拒否された理由を受け取れるよう、事前フックの合計時間より長く待つ
+#[cfg(feature = "daemon")]
+const _: () = assert!(MAX_PRE_HOOK_TOTAL_SECS < DEFAULT_TIMEOUT.as_secs());
+
+/// ポモドーロデーモンのクライアント
+///
+/// ```ignore
+/// let client = PomodoroClient::new();
+/// client.start(&StartOptions::new().task("レビュー")).await?;
+/// let status = client.status().await?;
+/// println!("{:?} 残り{}秒", status.phase, status.remaining.as_secs());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PomodoroClient {
+    socket_path: PathBuf,
+    timer: Option<String>,
+    timeout: Duration,
+}
+
+impl PomodoroClient {
+    /// 新しいクライアントを作成
+    ///
+    /// ソケットパスはCLIと同じ規則（`POMODORO_SOCKET`・`POMODORO_HOME` など）で決定する。
+    pub fn new() -> Self {
+        Self::with_socket_path(paths::socket_path(None))
+    }
+
+    /// ソケットパスを指定してクライアントを作成
+    pub fn with_socket_path(socket_path: impl Into<PathBuf>) -> Self {
+        Self {
+            socket_path: socket_path.into(),
+            timer: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// 操作対象の名前付きタイマーを設定（省略時はデフォルトタイマー）
+    pub fn with_timer(mut self, timer: impl Into<String>) -> Self {
+        self.timer = Some(timer.into());
+        self
+    }
+
+    /// 接続・リクエストごとのタイムアウトを設定（デフォルト: 5秒）
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 接続先のソケットパスを取得
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    /// ハンドシェイクしてデーモンの情報を取得
+    ///
+    /// プロトコルバージョンに互換性がない場合は
+    /// `ErrorCode::UnsupportedProtocolVersion` のエラーを返す。
+    pub async fn hello(&self) -> Result<DaemonInfo, ClientError> {
+        let data = self
+            .request(IpcRequest::Hello {
+                protocol_version: Some(PROTOCOL_VERSION),
+            })
+            .await?;
+        expect_data(data)?.try_into()
+    }
+
+    /// タイマーを開始
+    pub async fn start(&self, options: &StartOptions) -> Result<TimerStatus, ClientError> {
+        let params = options.to_params()?;
+        let data = self.request(IpcRequest::Start { params }).await?;
+        expect_data(data)?.try_into()
+    }
+
+    /// タイマーを一時停止
+    pub async fn pause(&self) -> Result<(), ClientError> {
+        self.request(IpcRequest::Pause).await.map(|_| ())
+    }
+
+    /// タイマーを再開
+    pub async fn resume(&self) -> Result<(), ClientError> {
+        self.request(IpcRequest::Resume).await.map(|_| ())
+    }
+
+    /// タイマーを停止
+    pub async fn stop(&self) -> Result<(), ClientError> {
+        self.request(IpcRequest::Stop).await.map(|_| ())
+    }
+
+    /// 現在のフェーズをスキップ
+    pub async fn skip(&self) -> Result<TimerStatus, ClientError> {
+        let data = self.request(IpcRequest::Skip).await?;
+        expect_data(data)?.try_into()
+    }
+
+    /// タイマーの状態を取得
+    pub async fn status(&self) -> Result<TimerStatus, ClientError> {
+        let data = self.request(IpcRequest::Status).await?;
+        expect_data(data)?.try_into()
+    }
+
+    /// すべてのタイマーの状態を取得
+    pub async fn list(&self) -> Result<Vec<TimerStatus>, ClientError> {
+        let data = expect_data(self.request(IpcRequest::List).await?)?;
+        data.timers
+            .unwrap_or_default()
+            .into_iter()
+            .map(TimerStatus::try_from)
+            .collect()
+    }
+
+    /// タイマーのイベントを購読
+    ///
+    /// 接続直後の状態スナップショットに続いて、イベントと定期的なスナップショットを受信する。
+    pub async fn subscribe(&self) -> Result<Subscription, ClientError> {
+        let mut reader = self.connect().await?;
+        self.timed(write_request(
+            &mut reader,
+            self.timer.clone(),
+            IpcRequest::Subscribe,
+        ))
+        .await??;
+        Ok(Subscription { reader })
+    }
+
+    /// デーモンに接続
+    async fn connect(&self) -> Result<BufReader<UnixStream>, ClientError> {
+        let stream = self
+            .timed(UnixStream::connect(&self.socket_path))
+            .await?
+            .map_err(ClientError::Connect)?;
+        Ok(BufReader::new(stream))
+    }
+
+    /// リクエストを送信し、成功レスポンスのデータを返す
+    async fn request(&self, request: IpcRequest) -> Result<Option<ResponseData>, ClientError> {
+        let mut reader = self.connect().await?;
+        self.timed(async {
+            write_request(&mut reader, self.timer.clone(), request).await?;
+            let frame = read_frame(&mut reader, DEFAULT_MAX_MESSAGE_SIZE)
+                .await?
+                .ok_or_else(|| ClientError::Protocol("デーモンが接続を閉じました".to_string()))?;
+            let response: IpcResponse =
+                serde_json::from_slice(&frame).map_err(|e| ClientError::Protocol(e.to_string()))?;
+            into_result(response)
+        })
+        .await?
+    }
+
+    /// タイムアウト付きで実行
+    async fn timed<F: std::future::Future>(&self, future: F) -> Result<F::Output, ClientError> {
+        timeout(self.timeout, future)
+            .await
+            .map_err(|_| ClientError::Timeout)
+    }
+}
+
+impl Default for PomodoroClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// イベント購読
+///
+/// `PomodoroClient::subscribe` で開いた接続から更新を順に受信する。
+pub struct Subscription {
+    reader: BufReader<UnixStream>,
+}
+
+impl Subscription {
+    /// 次の更新を受信
+    ///
+    /// デーモンが接続を閉じた場合は `Ok(None)` を返す。
+    /// 購読を開始できなかった場合（タイマーが存在しないなど）はデーモンのエラーを返す。
+    pub async fn next(&mut self) -> Result<Option<Update>, ClientError> {
+        let Some(frame) = read_frame(&mut self.reader, DEFAULT_MAX_MESSAGE_SIZE).await? else {
+            return Ok(None);
+        };
+
+        match serde_json::from_slice::<StreamMessage>(&frame) {
+            Ok(message) => message.try_into().map(Some),
+            // 購読の開始に失敗した場合は通常のエラーレスポンスが届く
+            Err(e) => match serde_json::from_slice::<IpcResponse>(&frame) {
+                Ok(response) => into_result(response).and(Err(ClientError::Protocol(
+                    "購読中に予期しないレスポンスを受信しました".to_string(),
+                ))),
+                Err(_) => Err(ClientError::Protocol(e.to_string())),
+            },
+        }
+    }
+}
+
+/// リクエストを1行のJSONとして書き込む
+async fn write_request(
+    reader: &mut BufReader<UnixStream>,
+    timer: Option<String>,
+    request: IpcRequest,
+) -> Result<(), ClientError> {
+    let payload = serde_json::to_vec(&TimerRequest { timer, request })
+        .map_err(|e| ClientError::Protocol(e.to_string()))?;
+    write_frame(reader, &payload, DEFAULT_MAX_MESSAGE_SIZE).await?;
+    Ok(())
+}
+
+/// レスポンスを成功時のデータ、またはデーモンのエラーに変換
+fn into_result(response: IpcResponse) -> Result<Option<ResponseData>, ClientError> {
+    if response.is_success() {
+        return Ok(response.data);
+    }
+    let code = response.code.unwrap_or(ErrorCode::Unknown);
+    let message = if response.message.is_empty() {
+        code.as_str().to_string()
+    } else {
+        response.message
+    };
+    Err(ClientError::Daemon { code, message })
+}
+
+/// データを含むはずのレスポンスからデータを取り出す
+fn expect_data(data: Option<ResponseData>) -> Result<ResponseData, ClientError> {
+    data.ok_or_else(|| ClientError::Protocol("レスポンスにデータが含まれていません".to_string()))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+// 実際のデーモンと通信するため、`daemon` フィーチャーが必要
+#[cfg(all(test, feature = "daemon"))]
+mod tests {
+    use super::*;
+    use crate::daemon::{IpcServer, TimerRegistry};
+    use crate::types::{PomodoroConfig, TimerEvent, TimerPhase};
+    use std::sync::Arc;
+    use tempfile::{tempdir, TempDir};
+
+    /// 実際のIPCサーバーを起動し、クライアントを返す
+    async fn start_daemon() -> (PomodoroClient, TempDir) {
+        let dir = tempdir().unwrap();
+        let socket_path = dir.path().join("pomodoro.sock");
+        let server = IpcServer::new(&socket_path).unwrap();
+        let (timers, _rx) = TimerRegistry::new(PomodoroConfig::default(), None);
+        let timers = Arc::new(timers);
+
+        tokio::spawn(async move {
+            while let Ok(connection) = server.accept().await {
+                tokio::spawn(connection.serve(timers.clone()));
+            }
+        });
+
+        (PomodoroClient::with_socket_path(socket_path), dir)
+    }
+
+    #[tokio::test]
+    async fn test_start_and_status() {
+        let (client, _dir) = start_daemon().await;
+
+        let started = client
+            .start(
+                &StartOptions::new()
+                    .work(Duration::from_secs(50 * 60))
+                    .task("設計"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(started.phase, TimerPhase::Working);
+        assert_eq!(started.duration, Duration::from_secs(50 * 60));
+
+        let status = client.status().await.unwrap();
+        assert_eq!(status.phase, TimerPhase::Working);
+        assert_eq!(status.task_name.as_deref(), Some("設計"));
+    }
+
+    #[tokio::test]
+    async fn test_pause_resume_stop() {
+        let (client, _dir) = start_daemon().await;
+        client.start(&StartOptions::new()).await.unwrap();
+
+        client.pause().await.unwrap();
+        assert!(client.status().await.unwrap().is_paused());
+
+        client.resume().await.unwrap();
+        client.stop().await.unwrap();
+        assert_eq!(client.status().await.unwrap().phase, TimerPhase::Stopped);
+    }
+
+    #[tokio::test]
+    async fn test_daemon_error_is_typed() {
+        let (client, _dir) = start_daemon().await;
+
+        let error = client.pause().await.unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::NotRunning));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_options_are_rejected_before_sending() {
+        let (client, _dir) = start_daemon().await;
+
+        let result = client
+            .start(&StartOptions::new().work(Duration::from_secs(30)))
+            .await;
+
+        assert!(matches!(result, Err(ClientError::InvalidOptions(_))));
+        assert_eq!(client.status().await.unwrap().phase, TimerPhase::Stopped);
+    }
+
+    #[tokio::test]
+    async fn test_named_timers_and_list() {
+        let (client, _dir) = start_daemon().await;
+        let prep = client.clone().with_timer("prep");
+
+        prep.start(&StartOptions::new().task("準備")).await.unwrap();
+        let timers = client.list().await.unwrap();
+
+        assert_eq!(timers.len(), 2);
+        let prep_status = timers
+            .iter()
+            .find(|t| t.timer.as_deref() == Some("prep"))
+            .unwrap();
+        assert_eq!(prep_status.phase, TimerPhase::Working);
+        assert_eq!(client.status().await.unwrap().phase, TimerPhase::Stopped);
+    }
+
+    #[tokio::test]
+    async fn test_hello() {
+        let (client, _dir) = start_daemon().await;
+
+        let info = client.hello().await.unwrap();
+
+        assert_eq!(info.protocol_version, PROTOCOL_VERSION);
+        assert!(info.supports("subscribe"));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_receives_snapshot_and_events() {
+        let (client, _dir) = start_daemon().await;
+        let mut subscription = client.subscribe().await.unwrap();
+
+        let first = subscription.next().await.unwrap().unwrap();
+        assert!(matches!(first, Update::Snapshot(ref s) if s.phase == TimerPhase::Stopped));
+
+        client.start(&StartOptions::new()).await.unwrap();
+        let update = timeout(Duration::from_secs(2), async {
+            loop {
+                match subscription.next().await.unwrap().unwrap() {
+                    Update::Event(event @ TimerEvent::WorkStarted { .. }) => return event,
+                    _ => continue,
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(update, TimerEvent::WorkStarted { task_name: None });
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_unknown_timer_returns_daemon_error() {
+        let (client, _dir) = start_daemon().await;
+        let mut subscription = client.with_timer("missing").subscribe().await.unwrap();
+
+        let error = subscription.next().await.unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::NotFound));
+    }
+
+    #[tokio::test]
+    async fn test_connect_error_when_daemon_not_running() {
+        let dir = tempdir().unwrap();
+        let client = PomodoroClient::with_socket_path(dir.path().join("none.sock"));
+
+        let result = client.status().await;
+
+        assert!(matches!(result, Err(ClientError::Connect(_))));
+    }
+}
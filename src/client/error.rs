@@ -0,0 +1,76 @@
+//! クライアントのエラー型定義
+//!
+//! デーモンとの通信、レスポンスの解釈、デーモンが返したエラーを区別して扱う。
+
+use thiserror::Error;
+
+use crate::types::framing::FrameError;
+use crate::types::ErrorCode;
+
+/// クライアントのエラー型
+#[derive(Debug, Error)]
+pub enum ClientError {
+    /// デーモンに接続できない（起動していない、ソケットがないなど）
+    #[error("デーモンに接続できません: {0}")]
+    Connect(#[source] std::io::Error),
+
+    /// デーモンが時間内に応答しなかった
+    #[error("デーモンの応答がタイムアウトしました")]
+    Timeout,
+
+    /// 送受信に失敗した
+    #[error("デーモンとの通信に失敗しました: {0}")]
+    Transport(#[from] FrameError),
+
+    /// デーモンの応答を解釈できない
+    #[error("デーモンの応答を解釈できません: {0}")]
+    Protocol(String),
+
+    /// デーモンがエラーを返した
+    #[error("{message}")]
+    Daemon {
+        /// エラーコード
+        code: ErrorCode,
+        /// デーモンのエラーメッセージ
+        message: String,
+    },
+
+    /// 開始オプションが不正
+    #[error("開始オプションが不正です: {0}")]
+    InvalidOptions(String),
+}
+
+impl ClientError {
+    /// デーモンが返したエラーコードを取得（デーモンのエラー以外はNone）
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self {
+            ClientError::Daemon { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_daemon_error_code() {
+        let error = ClientError::Daemon {
+            code: ErrorCode::AlreadyRunning,
+            message: "タイマーは既に実行中です".to_string(),
+        };
+        assert_eq!(error.code(), Some(ErrorCode::AlreadyRunning));
+        assert_eq!(error.to_string(), "タイマーは既に実行中です");
+    }
+
+    #[test]
+    fn test_non_daemon_error_has_no_code() {
+        assert_eq!(ClientError::Timeout.code(), None);
+        assert_eq!(ClientError::Protocol("x".to_string()).code(), None);
+    }
+}
@@ -0,0 +1,15 @@
+//! クライアントライブラリ
+//!
+//! 他のRustツールからデーモンのタイマーを操作するための型付き非同期クライアント。
+//! CLI（clap）には依存せず、フェーズは `TimerPhase`、時間は `Duration`、
+//! エラーは `ClientError`（デーモンのエラーは `ErrorCode` 付き）で扱う。
+
+mod connection;
+mod error;
+mod options;
+mod status;
+
+pub use connection::{PomodoroClient, Subscription};
+pub use error::ClientError;
+pub use options::StartOptions;
+pub use status::{DaemonInfo, TimerStatus, Update};
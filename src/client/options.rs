@@ -0,0 +1,186 @@
+//! タイマー開始オプション
+//!
+//! CLIの引数定義に依存せずに開始パラメータを組み立てるビルダー。
+
+use std::time::Duration;
+
+use super::ClientError;
+use crate::types::{PomodoroConfig, StartParams};
+
+/// タイマーの開始オプション
+///
+/// 指定しなかった項目はデーモン側の現在の設定を使用する。
+///
+/// ```ignore
+/// let options = StartOptions::new()
+///     .work(Duration::from_secs(50 * 60))
+///     .short_break(Duration::from_secs(10 * 60))
+///     .task("設計レビュー");
+/// client.start(&options).await?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StartOptions {
+    work: Option<Duration>,
+    short_break: Option<Duration>,
+    long_break: Option<Duration>,
+    task: Option<String>,
+    auto_cycle: Option<bool>,
+    focus_mode: Option<bool>,
+}
+
+impl StartOptions {
+    /// 新しいStartOptionsを作成（すべてデーモンの設定に従う）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 作業時間を設定（分単位、1-120分）
+    pub fn work(mut self, duration: Duration) -> Self {
+        self.work = Some(duration);
+        self
+    }
+
+    /// 短い休憩時間を設定（分単位、1-60分）
+    pub fn short_break(mut self, duration: Duration) -> Self {
+        self.short_break = Some(duration);
+        self
+    }
+
+    /// 長い休憩時間を設定（分単位、1-60分）
+    pub fn long_break(mut self, duration: Duration) -> Self {
+        self.long_break = Some(duration);
+        self
+    }
+
+    /// タスク名を設定
+    pub fn task(mut self, task: impl Into<String>) -> Self {
+        self.task = Some(task.into());
+        self
+    }
+
+    /// 自動サイクルを設定
+    pub fn auto_cycle(mut self, enabled: bool) -> Self {
+        self.auto_cycle = Some(enabled);
+        self
+    }
+
+    /// フォーカスモード連携を設定
+    pub fn focus_mode(mut self, enabled: bool) -> Self {
+        self.focus_mode = Some(enabled);
+        self
+    }
+
+    /// IPCの開始パラメータに変換
+    ///
+    /// # Errors
+    ///
+    /// 時間が分単位でない場合、または範囲外の場合（`ClientError::InvalidOptions`）
+    pub fn to_params(&self) -> Result<StartParams, ClientError> {
+        let params = StartParams {
+            work_minutes: self
+                .work
+                .map(|d| whole_minutes("作業時間", d))
+                .transpose()?,
+            break_minutes: self
+                .short_break
+                .map(|d| whole_minutes("休憩時間", d))
+                .transpose()?,
+            long_break_minutes: self
+                .long_break
+                .map(|d| whole_minutes("長い休憩時間", d))
+                .transpose()?,
+            task_name: self.task.clone(),
+            auto_cycle: self.auto_cycle,
+            focus_mode: self.focus_mode,
+        };
+
+        // 範囲の検証はデーモンの設定と同じ規則で行う
+        let mut config = PomodoroConfig::default();
+        config.update_from_params(&params);
+        config.validate().map_err(ClientError::InvalidOptions)?;
+
+        Ok(params)
+    }
+}
+
+/// 時間を分に変換（分単位でない場合はエラー）
+fn whole_minutes(name: &str, duration: Duration) -> Result<u32, ClientError> {
+    let secs = duration.as_secs();
+    if secs % 60 != 0 || duration.subsec_nanos() != 0 {
+        return Err(ClientError::InvalidOptions(format!(
+            "{}は分単位で指定してください",
+            name
+        )));
+    }
+    u32::try_from(secs / 60)
+        .map_err(|_| ClientError::InvalidOptions(format!("{}が大きすぎます", name)))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_default_options_leave_params_unset() {
+        let params = StartOptions::new().to_params().unwrap();
+        assert!(params.work_minutes.is_none());
+        assert!(params.break_minutes.is_none());
+        assert!(params.long_break_minutes.is_none());
+        assert!(params.task_name.is_none());
+        assert!(params.auto_cycle.is_none());
+        assert!(params.focus_mode.is_none());
+    }
+
+    #[test]
+    fn test_builder_converts_durations_to_minutes() {
+        let params = StartOptions::new()
+            .work(MINUTE * 50)
+            .short_break(MINUTE * 10)
+            .long_break(MINUTE * 30)
+            .task("設計レビュー")
+            .auto_cycle(true)
+            .focus_mode(false)
+            .to_params()
+            .unwrap();
+
+        assert_eq!(params.work_minutes, Some(50));
+        assert_eq!(params.break_minutes, Some(10));
+        assert_eq!(params.long_break_minutes, Some(30));
+        assert_eq!(params.task_name.as_deref(), Some("設計レビュー"));
+        assert_eq!(params.auto_cycle, Some(true));
+        assert_eq!(params.focus_mode, Some(false));
+    }
+
+    #[test]
+    fn test_rejects_partial_minutes() {
+        let result = StartOptions::new()
+            .work(Duration::from_secs(90))
+            .to_params();
+        assert!(matches!(result, Err(ClientError::InvalidOptions(_))));
+
+        let result = StartOptions::new()
+            .work(MINUTE * 25 + Duration::from_millis(1))
+            .to_params();
+        assert!(matches!(result, Err(ClientError::InvalidOptions(_))));
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_durations() {
+        let result = StartOptions::new().work(MINUTE * 121).to_params();
+        assert!(matches!(result, Err(ClientError::InvalidOptions(_))));
+
+        let result = StartOptions::new().short_break(Duration::ZERO).to_params();
+        assert!(matches!(result, Err(ClientError::InvalidOptions(_))));
+
+        let result = StartOptions::new()
+            .long_break(Duration::from_secs(u64::MAX / 60 * 60))
+            .to_params();
+        assert!(matches!(result, Err(ClientError::InvalidOptions(_))));
+    }
+}
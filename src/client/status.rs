@@ -0,0 +1,195 @@
+//! クライアントが返す型付きの状態
+//!
+//! IPCの `ResponseData`・`StreamMessage` を、フェーズや時間を型で表した値に変換する。
+
+use std::time::Duration;
+
+use super::ClientError;
+use crate::types::{ResponseData, StreamMessage, TimerEvent, TimerPhase};
+
+/// タイマーの状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimerStatus {
+    /// タイマー名（一覧取得時のみ）
+    pub timer: Option<String>,
+    /// 現在のフェーズ
+    pub phase: TimerPhase,
+    /// 現在のフェーズの残り時間
+    pub remaining: Duration,
+    /// 現在のフェーズの合計時間
+    pub duration: Duration,
+    /// 完了したポモドーロ回数
+    pub pomodoro_count: u32,
+    /// 現在のタスク名
+    pub task_name: Option<String>,
+}
+
+impl TimerStatus {
+    /// 現在のフェーズの経過時間
+    pub fn elapsed(&self) -> Duration {
+        self.duration.saturating_sub(self.remaining)
+    }
+
+    /// カウントダウン中かどうか
+    pub fn is_running(&self) -> bool {
+        self.phase.is_active()
+    }
+
+    /// 一時停止中かどうか
+    pub fn is_paused(&self) -> bool {
+        self.phase == TimerPhase::Paused
+    }
+}
+
+impl TryFrom<ResponseData> for TimerStatus {
+    type Error = ClientError;
+
+    fn try_from(data: ResponseData) -> Result<Self, Self::Error> {
+        let state = data
+            .state
+            .ok_or_else(|| ClientError::Protocol("タイマーの状態が含まれていません".to_string()))?;
+        let phase = TimerPhase::parse(&state)
+            .ok_or_else(|| ClientError::Protocol(format!("不明なフェーズです: {}", state)))?;
+
+        Ok(Self {
+            timer: data.timer,
+            phase,
+            remaining: Duration::from_secs(data.remaining_seconds.unwrap_or(0).into()),
+            duration: Duration::from_secs(data.duration.unwrap_or(0).into()),
+            pomodoro_count: data.pomodoro_count.unwrap_or(0),
+            task_name: data.task_name,
+        })
+    }
+}
+
+/// デーモンの情報（ハンドシェイクの結果）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaemonInfo {
+    /// デーモンのバージョン
+    pub version: String,
+    /// デーモンのプロトコルバージョン
+    pub protocol_version: u32,
+    /// デーモンが対応する機能（`subscribe`、`timers` など）
+    pub capabilities: Vec<String>,
+}
+
+impl DaemonInfo {
+    /// 指定した機能に対応しているか
+    pub fn supports(&self, capability: &str) -> bool {
+        self.capabilities.iter().any(|c| c == capability)
+    }
+}
+
+impl TryFrom<ResponseData> for DaemonInfo {
+    type Error = ClientError;
+
+    fn try_from(data: ResponseData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: data
+                .version
+                .ok_or_else(|| ClientError::Protocol("バージョンが含まれていません".to_string()))?,
+            protocol_version: data.protocol_version.ok_or_else(|| {
+                ClientError::Protocol("プロトコルバージョンが含まれていません".to_string())
+            })?,
+            capabilities: data.capabilities.unwrap_or_default(),
+        })
+    }
+}
+
+/// イベント購読で受信する更新
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Update {
+    /// タイマーイベント
+    Event(TimerEvent),
+    /// 状態スナップショット
+    Snapshot(TimerStatus),
+}
+
+impl TryFrom<StreamMessage> for Update {
+    type Error = ClientError;
+
+    fn try_from(message: StreamMessage) -> Result<Self, Self::Error> {
+        match message {
+            StreamMessage::Event { event } => Ok(Update::Event(event)),
+            StreamMessage::Snapshot { data } => Ok(Update::Snapshot(data.try_into()?)),
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_data(state: &str) -> ResponseData {
+        ResponseData {
+            state: Some(state.to_string()),
+            remaining_seconds: Some(600),
+            pomodoro_count: Some(2),
+            task_name: Some("API実装".to_string()),
+            duration: Some(1500),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_timer_status_from_response_data() {
+        let status = TimerStatus::try_from(status_data("working")).unwrap();
+
+        assert_eq!(status.phase, TimerPhase::Working);
+        assert_eq!(status.remaining, Duration::from_secs(600));
+        assert_eq!(status.duration, Duration::from_secs(1500));
+        assert_eq!(status.elapsed(), Duration::from_secs(900));
+        assert_eq!(status.pomodoro_count, 2);
+        assert_eq!(status.task_name.as_deref(), Some("API実装"));
+        assert!(status.is_running());
+        assert!(!status.is_paused());
+    }
+
+    #[test]
+    fn test_timer_status_long_break() {
+        let status = TimerStatus::try_from(status_data("long_breaking")).unwrap();
+        assert_eq!(status.phase, TimerPhase::LongBreaking);
+    }
+
+    #[test]
+    fn test_timer_status_rejects_missing_or_unknown_state() {
+        let result = TimerStatus::try_from(ResponseData::default());
+        assert!(matches!(result, Err(ClientError::Protocol(_))));
+
+        let result = TimerStatus::try_from(status_data("napping"));
+        assert!(matches!(result, Err(ClientError::Protocol(_))));
+    }
+
+    #[test]
+    fn test_daemon_info_supports() {
+        let info = DaemonInfo::try_from(ResponseData {
+            version: Some("0.5.0".to_string()),
+            protocol_version: Some(1),
+            capabilities: Some(vec!["subscribe".to_string(), "timers".to_string()]),
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert!(info.supports("timers"));
+        assert!(!info.supports("teleport"));
+    }
+
+    #[test]
+    fn test_update_from_stream_message() {
+        let update = Update::try_from(StreamMessage::Event {
+            event: TimerEvent::Paused,
+        })
+        .unwrap();
+        assert_eq!(update, Update::Event(TimerEvent::Paused));
+
+        let update = Update::try_from(StreamMessage::Snapshot {
+            data: status_data("paused"),
+        })
+        .unwrap();
+        assert!(matches!(update, Update::Snapshot(status) if status.is_paused()));
+    }
+}
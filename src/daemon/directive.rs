@@ -0,0 +1,362 @@
+//! フックからの指示の適用
+//!
+//! フックの出力に含まれていた指示（`HookDirective`）を、フックを発火したタイマーに適用する。
+//! 指示によるタイマーの操作が別のフックを発火し、その指示がさらに操作する……という
+//! ループを防ぐため、次の制限を設ける。
+//!
+//! - タイマーの操作（`action`・`extend_secs`）は、タイマーごとに `ACTION_WINDOW` あたり
+//!   `MAX_ACTIONS_PER_WINDOW` 回まで
+//! - 事前フック（`before_*`）の指示ではタイマーを操作しない（タスク名・タグ・通知のみ）
+//! - フックの発火後に削除されたタイマーへの指示は無視する
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::daemon::{TimerEngine, TimerRegistry};
+use crate::hooks::{DirectiveAction, DirectiveNotification, HookDirectiveRequest};
+
+/// タイマーの操作回数を数える期間
+pub const ACTION_WINDOW: Duration = Duration::from_secs(60);
+
+/// `ACTION_WINDOW` あたりにタイマーごとに適用できる操作の回数
+pub const MAX_ACTIONS_PER_WINDOW: usize = 3;
+
+/// 指示の適用結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectiveOutcome {
+    /// 適用したタイマーの名前
+    pub timer: String,
+    /// 送信する通知（通知の送信は呼び出し側で行う）
+    pub notification: Option<DirectiveNotification>,
+    /// 適用しなかった指示とその理由
+    pub ignored: Vec<String>,
+}
+
+/// フックからの指示を適用する
+pub struct DirectiveApplier {
+    timers: Arc<TimerRegistry>,
+    /// タイマー（セッションID）ごとの直近の操作時刻
+    recent_actions: HashMap<Uuid, VecDeque<Instant>>,
+}
+
+impl DirectiveApplier {
+    /// 新しいDirectiveApplierを作成
+    pub fn new(timers: Arc<TimerRegistry>) -> Self {
+        Self {
+            timers,
+            recent_actions: HashMap::new(),
+        }
+    }
+
+    /// 指示を適用する
+    ///
+    /// タスク名・タグ、残り時間の延長、一時停止・スキップの順に適用する。
+    /// スキップは通常の操作と同じく事前フックを実行し、拒否された場合は適用しない。
+    /// 事前フックの実行中はタイマーのロックを解放するため、ティックやほかの操作は止まらない。
+    ///
+    /// # Errors
+    ///
+    /// フックを発火したタイマーが見つからない場合
+    pub async fn apply(&mut self, request: &HookDirectiveRequest) -> Result<DirectiveOutcome> {
+        self.apply_at(request, Instant::now()).await
+    }
+
+    async fn apply_at(
+        &mut self,
+        request: &HookDirectiveRequest,
+        now: Instant,
+    ) -> Result<DirectiveOutcome> {
+        let mut target = None;
+        for (name, timer) in self.timers.timers() {
+            if timer.engine().lock().await.session_id() == request.session_id {
+                target = Some((name, timer));
+                break;
+            }
+        }
+        let (name, timer) = target.ok_or_else(|| {
+            anyhow!(
+                "フック '{}' の指示の対象タイマーが見つかりません",
+                request.hook
+            )
+        })?;
+
+        let directive = &request.directive;
+        let mut ignored = Vec::new();
+        let mut engine = timer.engine().lock().await;
+
+        if let Some(task_name) = directive.task_name_after(engine.get_state().task_name.as_deref())
+        {
+            if let Err(e) = engine.set_task_name(Some(task_name)) {
+                ignored.push(format!("task_name: {}", e));
+            }
+        }
+
+        if directive.changes_timer() {
+            if request.event.is_pre_transition() {
+                ignored.push(format!(
+                    "{} のフックの指示ではタイマーを操作できません",
+                    request.event.as_str()
+                ));
+            } else if !self.allow_action(request.session_id, now) {
+                ignored.push(format!(
+                    "タイマーの操作が多すぎます（{}秒あたり{}回まで）",
+                    ACTION_WINDOW.as_secs(),
+                    MAX_ACTIONS_PER_WINDOW
+                ));
+            } else {
+                if let Some(secs) = directive.extend_secs {
+                    let secs = u32::try_from(secs).unwrap_or(u32::MAX);
+                    if let Err(e) = engine.extend(secs) {
+                        ignored.push(format!("extend_secs: {}", e));
+                    }
+                }
+                let result = match directive.action {
+                    Some(DirectiveAction::Pause) => engine.pause(),
+                    Some(DirectiveAction::Skip) => {
+                        // 事前フックの実行中はロックを解放する
+                        drop(engine);
+                        TimerEngine::skip_with_pre_hooks(timer.engine()).await
+                    }
+                    None => Ok(()),
+                };
+                if let Err(e) = result {
+                    ignored.push(format!("action: {}", e));
+                }
+            }
+        }
+
+        Ok(DirectiveOutcome {
+            timer: name,
+            notification: directive.notify.clone(),
+            ignored,
+        })
+    }
+
+    /// 操作回数の上限を確認し、許可する場合は操作を記録する
+    fn allow_action(&mut self, session_id: Uuid, now: Instant) -> bool {
+        let actions = self.recent_actions.entry(session_id).or_default();
+        while actions
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= ACTION_WINDOW)
+        {
+            actions.pop_front();
+        }
+        if actions.len() >= MAX_ACTIONS_PER_WINDOW {
+            return false;
+        }
+        actions.push_back(now);
+        true
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::HookDirective;
+    use crate::types::{HookEvent, PomodoroConfig, StartParams, TimerPhase};
+
+    /// デフォルトタイマーを開始した状態のレジストリ
+    async fn create_running_timers() -> (Arc<TimerRegistry>, Uuid) {
+        let (timers, _rx) = TimerRegistry::new(PomodoroConfig::default(), None);
+        let timers = Arc::new(timers);
+        let session_id = {
+            let timer = timers.default_timer();
+            let mut engine = timer.engine().lock().await;
+            engine
+                .start(&StartParams {
+                    task_name: Some("ログイン修正".to_string()),
+                    ..Default::default()
+                })
+                .unwrap();
+            engine.session_id()
+        };
+        (timers, session_id)
+    }
+
+    fn create_request(session_id: Uuid, event: HookEvent, json: &str) -> HookDirectiveRequest {
+        HookDirectiveRequest {
+            session_id,
+            event,
+            hook: "tracker".to_string(),
+            directive: serde_json::from_str::<HookDirective>(json).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_task_name_tags_and_notification() {
+        let (timers, session_id) = create_running_timers().await;
+        let mut applier = DirectiveApplier::new(timers.clone());
+        let request = create_request(
+            session_id,
+            HookEvent::WorkStart,
+            r#"{"task_name": "PROJ-123", "add_tags": ["bug"], "notify": {"message": "取得しました"}}"#,
+        );
+
+        let outcome = applier.apply(&request).await.unwrap();
+
+        assert_eq!(outcome.timer, "default");
+        assert!(outcome.ignored.is_empty());
+        assert_eq!(outcome.notification.unwrap().message, "取得しました");
+        let timer = timers.default_timer();
+        let engine = timer.engine().lock().await;
+        assert_eq!(
+            engine.get_state().task_name.as_deref(),
+            Some("PROJ-123 #bug")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_extend_and_pause() {
+        let (timers, session_id) = create_running_timers().await;
+        let mut applier = DirectiveApplier::new(timers.clone());
+        let request = create_request(
+            session_id,
+            HookEvent::WorkStart,
+            r#"{"extend_secs": 300, "action": "pause"}"#,
+        );
+
+        let outcome = applier.apply(&request).await.unwrap();
+
+        assert!(outcome.ignored.is_empty(), "{:?}", outcome.ignored);
+        let timer = timers.default_timer();
+        let engine = timer.engine().lock().await;
+        assert_eq!(engine.get_state().phase, TimerPhase::Paused);
+        assert_eq!(engine.get_state().remaining_seconds, 25 * 60 + 300);
+    }
+
+    #[tokio::test]
+    async fn test_apply_limits_timer_actions() {
+        let (timers, session_id) = create_running_timers().await;
+        let mut applier = DirectiveApplier::new(timers.clone());
+        let request = create_request(session_id, HookEvent::BreakStart, r#"{"action": "skip"}"#);
+        let start = Instant::now();
+
+        for _ in 0..MAX_ACTIONS_PER_WINDOW {
+            let outcome = applier.apply_at(&request, start).await.unwrap();
+            assert!(outcome.ignored.is_empty(), "{:?}", outcome.ignored);
+        }
+        let phase = timers
+            .default_timer()
+            .engine()
+            .lock()
+            .await
+            .get_state()
+            .phase;
+
+        // 上限に達した後の操作は適用しない
+        let outcome = applier.apply_at(&request, start).await.unwrap();
+        assert!(outcome.ignored[0].contains("多すぎます"));
+        assert_eq!(
+            timers
+                .default_timer()
+                .engine()
+                .lock()
+                .await
+                .get_state()
+                .phase,
+            phase
+        );
+
+        // 期間が過ぎれば再び適用する
+        let outcome = applier
+            .apply_at(&request, start + ACTION_WINDOW)
+            .await
+            .unwrap();
+        assert!(outcome.ignored.is_empty(), "{:?}", outcome.ignored);
+    }
+
+    #[tokio::test]
+    async fn test_apply_pre_transition_directive_does_not_control_timer() {
+        let (timers, session_id) = create_running_timers().await;
+        let mut applier = DirectiveApplier::new(timers.clone());
+        let request = create_request(
+            session_id,
+            HookEvent::BeforeWorkStart,
+            r#"{"task_name": "PROJ-7", "action": "pause"}"#,
+        );
+
+        let outcome = applier.apply(&request).await.unwrap();
+
+        assert!(outcome.ignored[0].contains("before_work_start"));
+        let timer = timers.default_timer();
+        let engine = timer.engine().lock().await;
+        assert_eq!(engine.get_state().phase, TimerPhase::Working);
+        assert_eq!(engine.get_state().task_name.as_deref(), Some("PROJ-7"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_failed_action_is_reported() {
+        let (timers, _rx) = TimerRegistry::new(PomodoroConfig::default(), None);
+        let timers = Arc::new(timers);
+        let session_id = timers.default_timer().engine().lock().await.session_id();
+        let mut applier = DirectiveApplier::new(timers);
+        let request = create_request(session_id, HookEvent::Stop, r#"{"action": "pause"}"#);
+
+        let outcome = applier.apply(&request).await.unwrap();
+
+        assert_eq!(outcome.ignored.len(), 1);
+        assert!(outcome.ignored[0].starts_with("action:"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_apply_skip_releases_lock_during_pre_hooks() {
+        use crate::hooks::{HookConfig, HookExecutor};
+
+        let config = HookConfig::parse_and_validate(
+            r#"{"version": "1.0", "hooks": [{"name": "slow", "event": "before_break_start", "shell": "sleep 1"}]}"#,
+        )
+        .unwrap();
+        let (timers, _rx) = TimerRegistry::new(
+            PomodoroConfig::default(),
+            Some(Arc::new(HookExecutor::with_config(config))),
+        );
+        let timers = Arc::new(timers);
+        let engine = timers.default_timer().engine().clone();
+        engine.lock().await.start(&StartParams::default()).unwrap();
+        let session_id = engine.lock().await.session_id();
+
+        let apply = tokio::spawn({
+            let timers = timers.clone();
+            async move {
+                let request =
+                    create_request(session_id, HookEvent::WorkStart, r#"{"action": "skip"}"#);
+                DirectiveApplier::new(timers).apply(&request).await
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // 事前フックの実行中もエンジンを操作できる
+        let locked = tokio::time::timeout(Duration::from_millis(200), engine.lock()).await;
+        assert_eq!(
+            locked
+                .expect("engine is locked during pre-hooks")
+                .get_state()
+                .phase,
+            TimerPhase::Working
+        );
+
+        let outcome = apply.await.unwrap().unwrap();
+        assert!(outcome.ignored.is_empty(), "{:?}", outcome.ignored);
+        assert_eq!(engine.lock().await.get_state().phase, TimerPhase::Breaking);
+    }
+
+    #[tokio::test]
+    async fn test_apply_unknown_timer_is_error() {
+        let (timers, _session_id) = create_running_timers().await;
+        let mut applier = DirectiveApplier::new(timers);
+        let request = create_request(Uuid::new_v4(), HookEvent::WorkEnd, r#"{"action": "pause"}"#);
+
+        let err = applier.apply(&request).await.unwrap_err();
+
+        assert!(err.to_string().contains("見つかりません"));
+    }
+}
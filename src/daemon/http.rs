@@ -0,0 +1,1008 @@
+//! HTTP/JSON制御API
+//!
+//! `127.0.0.1` 上でタイマー操作と作業履歴の参照を行うREST APIを提供する。
+//! タイマー操作はUnixソケットと同じ `handle_timer_request` で処理するため、
+//! レスポンスの形式と挙動はIPCと一致する。
+//! 対象のタイマーはクエリパラメータ `timer`（例: `/v1/timer/pause?timer=prep`）で指定し、
+//! 省略時はデフォルトタイマーが対象になる。
+//! すべてのリクエストに `~/.pomodoro/http-token` のBearerトークンが必要。
+//!
+//! 設定ファイル (`~/.pomodoro/http-api.json`) で有効化しない限り起動しない。
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::io::{
+    AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader,
+};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::{timeout, Duration};
+use uuid::Uuid;
+
+use crate::daemon::ipc::handle_timer_request;
+use crate::daemon::TimerRegistry;
+use crate::history::{HistoryEntry, HistoryStore, HistorySummary};
+use crate::types::{ErrorCode, IpcRequest, IpcResponse, StartParams, TimerRequest};
+
+/// デフォルトのポート番号
+pub const DEFAULT_HTTP_PORT: u16 = 7373;
+
+/// リクエストヘッダーの最大サイズ（バイト）
+const MAX_HEADER_SIZE: usize = 8 * 1024;
+
+/// リクエストボディの最大サイズ（バイト）
+const MAX_BODY_SIZE: usize = 64 * 1024;
+
+/// リクエスト読み取りのタイムアウト（秒）
+pub(super) const REQUEST_TIMEOUT_SECS: u64 = 5;
+
+/// JSONレスポンスのContent-Type
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+// ============================================================================
+// Config
+// ============================================================================
+
+/// HTTP API設定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpApiConfig {
+    /// HTTP APIを有効にするかどうか（デフォルト: 無効）
+    #[serde(default)]
+    pub enabled: bool,
+    /// 待ち受けるポート番号（`127.0.0.1` のみ）
+    #[serde(default = "default_port")]
+    pub port: u16,
+}
+
+fn default_port() -> u16 {
+    DEFAULT_HTTP_PORT
+}
+
+impl Default for HttpApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_HTTP_PORT,
+        }
+    }
+}
+
+impl HttpApiConfig {
+    /// デフォルトの設定ファイルパス（データディレクトリの `http-api.json`）を取得
+    fn get_config_path() -> Option<PathBuf> {
+        crate::paths::data_file("http-api.json")
+    }
+
+    /// 設定ファイルから読み込む
+    /// ファイルが存在しない場合はデフォルト値（無効）を返す
+    pub fn load() -> Result<Self> {
+        match Self::get_config_path() {
+            Some(path) => Self::load_from_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// 指定されたパスから読み込む
+    pub fn load_from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).context("Failed to read HTTP API config")?;
+        serde_json::from_str(&content).context("Failed to parse HTTP API config")
+    }
+
+    /// 指定されたパスに保存する
+    pub fn save_to_file(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+/// デフォルトのトークンファイルパス（データディレクトリの `http-token`）を取得
+pub fn default_token_path() -> Option<PathBuf> {
+    crate::paths::data_file("http-token")
+}
+
+/// トークンを読み込む（存在しない場合は生成して保存する）
+///
+/// 生成したトークンファイルは所有者のみ読み書き可能（0600）にする。
+pub fn load_or_create_token(path: &Path) -> Result<String> {
+    if path.exists() {
+        let token = std::fs::read_to_string(path).context("Failed to read HTTP API token")?;
+        let token = token.trim();
+        if !token.is_empty() {
+            return Ok(token.to_string());
+        }
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create token directory")?;
+    }
+
+    let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .context("Failed to create HTTP API token")?;
+    std::io::Write::write_all(&mut file, token.as_bytes())
+        .context("Failed to write HTTP API token")?;
+
+    Ok(token)
+}
+
+// ============================================================================
+// Server
+// ============================================================================
+
+/// HTTP APIサーバー
+pub struct HttpApiServer {
+    /// TCPリスナー（`127.0.0.1` のみ）
+    listener: TcpListener,
+    /// Bearerトークン
+    token: Arc<str>,
+    /// 履歴ファイルのパス（Noneの場合はデフォルトパス）
+    history_path: Option<PathBuf>,
+}
+
+impl HttpApiServer {
+    /// 指定されたポートで待ち受けるサーバーを作成
+    ///
+    /// ポートに0を指定した場合は空いているポートを使用する。
+    pub async fn bind(port: u16, token: String) -> Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+            .await
+            .with_context(|| format!("Failed to bind HTTP API to 127.0.0.1:{}", port))?;
+
+        Ok(Self {
+            listener,
+            token: token.into(),
+            history_path: None,
+        })
+    }
+
+    /// 設定からサーバーを作成
+    ///
+    /// トークンは `~/.pomodoro/http-token` から読み込み、なければ生成する。
+    pub async fn from_config(config: &HttpApiConfig) -> Result<Self> {
+        let token_path = default_token_path().context("Could not determine home directory")?;
+        let token = load_or_create_token(&token_path)?;
+        Self::bind(config.port, token).await
+    }
+
+    /// 履歴ファイルのパスを指定
+    pub fn with_history_path(mut self, path: PathBuf) -> Self {
+        self.history_path = Some(path);
+        self
+    }
+
+    /// 待ち受けアドレスを取得
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        self.listener
+            .local_addr()
+            .context("Failed to get HTTP API address")
+    }
+
+    /// リクエストの受け付けを開始
+    ///
+    /// 接続ごとにタスクを起動して処理する。リスナーのエラーが発生するまで戻らない。
+    pub async fn run(self, timers: Arc<TimerRegistry>) -> Result<()> {
+        loop {
+            let (stream, _) = self
+                .listener
+                .accept()
+                .await
+                .context("Failed to accept HTTP connection")?;
+
+            let timers = timers.clone();
+            let token = self.token.clone();
+            let history_path = self.history_path.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream, &timers, &token, history_path).await {
+                    eprintln!("HTTP API connection error: {}", e);
+                }
+            });
+        }
+    }
+}
+
+/// 1接続（1リクエスト）を処理
+async fn handle_connection(
+    stream: TcpStream,
+    timers: &TimerRegistry,
+    token: &str,
+    history_path: Option<PathBuf>,
+) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+
+    let response = match timeout(
+        Duration::from_secs(REQUEST_TIMEOUT_SECS),
+        read_request(&mut reader),
+    )
+    .await
+    {
+        Err(_) => return Ok(()),
+        Ok(Err(HttpError::Io(e))) => return Err(e).context("Failed to read HTTP request"),
+        Ok(Err(e)) => error_response(e.status(), e.code(), e.to_string()),
+        Ok(Ok(request)) => route(request, timers, token, history_path).await,
+    };
+
+    write_response(&mut writer, &response)
+        .await
+        .context("Failed to write HTTP response")
+}
+
+// ============================================================================
+// Request / Response
+// ============================================================================
+
+/// HTTPリクエストの読み取りエラー
+#[derive(Debug, Error)]
+pub(super) enum HttpError {
+    /// リクエストの形式が不正
+    #[error("HTTPリクエストが不正です: {0}")]
+    BadRequest(String),
+
+    /// ヘッダーまたはボディが大きすぎる
+    #[error("リクエストが大きすぎます")]
+    PayloadTooLarge,
+
+    /// IOエラー
+    #[error("IOエラー: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl HttpError {
+    pub(super) fn status(&self) -> u16 {
+        match self {
+            HttpError::BadRequest(_) | HttpError::Io(_) => 400,
+            HttpError::PayloadTooLarge => 413,
+        }
+    }
+
+    pub(super) fn code(&self) -> ErrorCode {
+        match self {
+            HttpError::PayloadTooLarge => ErrorCode::MessageTooLarge,
+            _ => ErrorCode::InvalidRequest,
+        }
+    }
+}
+
+/// HTTPリクエスト
+#[derive(Debug)]
+pub(super) struct HttpRequest {
+    pub(super) method: String,
+    pub(super) path: String,
+    query: Option<String>,
+    authorization: Option<String>,
+    body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// クエリパラメータの値を取得（パーセントエンコーディングと `+` はデコードする）
+    fn query_param(&self, name: &str) -> Option<String> {
+        self.query.as_deref()?.split('&').find_map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key) == name).then(|| percent_decode(value))
+        })
+    }
+}
+
+/// `application/x-www-form-urlencoded` の値をデコード
+///
+/// 不正な `%` の並びはそのまま残し、UTF-8として不正なバイトは置き換える。
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let hex = bytes
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                if let Some(byte) = hex {
+                    decoded.push(byte);
+                    i += 3;
+                    continue;
+                }
+                decoded.push(b'%');
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// HTTPレスポンス
+#[derive(Debug)]
+pub(super) struct HttpResponse {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// 指定したContent-Typeのレスポンスを作成
+    pub(super) fn new(status: u16, content_type: &'static str, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            content_type,
+            body,
+        }
+    }
+}
+
+/// HTTP/1.1リクエストを読み取る
+pub(super) async fn read_request<R>(reader: &mut R) -> Result<HttpRequest, HttpError>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut head = reader.take(MAX_HEADER_SIZE as u64);
+    let mut lines = Vec::new();
+
+    loop {
+        let mut line = String::new();
+        let n = head.read_line(&mut line).await?;
+        if n == 0 || !line.ends_with('\n') {
+            return Err(if head.limit() == 0 {
+                HttpError::PayloadTooLarge
+            } else {
+                HttpError::BadRequest("ヘッダーが途中で終了しました".to_string())
+            });
+        }
+
+        let line = line.trim_end_matches(['\r', '\n']).to_string();
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line);
+    }
+
+    let mut lines = lines.into_iter();
+    let request_line = lines
+        .next()
+        .ok_or_else(|| HttpError::BadRequest("リクエスト行がありません".to_string()))?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(HttpError::BadRequest(format!(
+            "リクエスト行が不正です: {}",
+            request_line
+        )));
+    };
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), Some(query.to_string())),
+        None => (target.to_string(), None),
+    };
+
+    let mut content_length = 0;
+    let mut authorization = None;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(HttpError::BadRequest(format!(
+                "ヘッダーが不正です: {}",
+                line
+            )));
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value
+                .parse::<usize>()
+                .map_err(|_| HttpError::BadRequest("Content-Lengthが不正です".to_string()))?;
+        } else if name.eq_ignore_ascii_case("authorization") {
+            authorization = Some(value.to_string());
+        }
+    }
+
+    if content_length > MAX_BODY_SIZE {
+        return Err(HttpError::PayloadTooLarge);
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body).await?;
+
+    Ok(HttpRequest {
+        method: method.to_string(),
+        path,
+        query,
+        authorization,
+        body,
+    })
+}
+
+/// HTTPレスポンスを書き込む
+pub(super) async fn write_response<W>(
+    writer: &mut W,
+    response: &HttpResponse,
+) -> std::io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        reason_phrase(response.status),
+        response.content_type,
+        response.body.len()
+    );
+    if response.status == 401 {
+        head.push_str("WWW-Authenticate: Bearer\r\n");
+    }
+    head.push_str("\r\n");
+
+    writer.write_all(head.as_bytes()).await?;
+    writer.write_all(&response.body).await?;
+    writer.flush().await
+}
+
+/// ステータスコードの理由句
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+/// JSONレスポンスを作成
+fn json_response(status: u16, body: &impl Serialize) -> HttpResponse {
+    match serde_json::to_vec(body) {
+        Ok(body) => HttpResponse::new(status, JSON_CONTENT_TYPE, body),
+        Err(e) => HttpResponse::new(
+            500,
+            JSON_CONTENT_TYPE,
+            format!(r#"{{"status":"error","message":"{}"}}"#, e).into_bytes(),
+        ),
+    }
+}
+
+/// エラーレスポンスを作成
+pub(super) fn error_response(
+    status: u16,
+    code: ErrorCode,
+    message: impl Into<String>,
+) -> HttpResponse {
+    json_response(status, &IpcResponse::error(code, message))
+}
+
+/// IPCレスポンスのHTTPステータスコード
+fn status_for(response: &IpcResponse) -> u16 {
+    match response.code {
+        None => 200,
+        Some(
+            ErrorCode::AlreadyRunning
+            | ErrorCode::NotRunning
+            | ErrorCode::NotPaused
+            | ErrorCode::FollowingLeader
+            | ErrorCode::HookRejected
+            | ErrorCode::StateChanged,
+        ) => 409,
+        Some(
+            ErrorCode::InvalidRequest
+            | ErrorCode::UnsupportedProtocolVersion
+            | ErrorCode::InvalidConfig,
+        ) => 400,
+        Some(ErrorCode::MessageTooLarge) => 413,
+        Some(ErrorCode::Unauthorized) => 401,
+        Some(ErrorCode::NotFound) => 404,
+        Some(ErrorCode::Internal | ErrorCode::Unknown) => 500,
+    }
+}
+
+// ============================================================================
+// Routing
+// ============================================================================
+
+/// 履歴クエリのレスポンス
+#[derive(Debug, Serialize)]
+struct HistoryResponse<'a> {
+    status: &'static str,
+    entries: Vec<&'a HistoryEntry>,
+    summary: HistorySummary,
+}
+
+/// リクエストを処理してレスポンスを作成
+async fn route(
+    request: HttpRequest,
+    timers: &TimerRegistry,
+    token: &str,
+    history_path: Option<PathBuf>,
+) -> HttpResponse {
+    if !is_authorized(&request, token) {
+        return error_response(401, ErrorCode::Unauthorized, "認証トークンが不正です");
+    }
+
+    let ipc_request = match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/v1/timer/status") => IpcRequest::Status,
+        ("POST", "/v1/timer/start") => match parse_start_params(&request.body) {
+            Ok(params) => IpcRequest::Start { params },
+            Err(e) => {
+                return error_response(
+                    400,
+                    ErrorCode::InvalidRequest,
+                    format!("リクエストの解析に失敗しました: {}", e),
+                )
+            }
+        },
+        ("POST", "/v1/timer/pause") => IpcRequest::Pause,
+        ("POST", "/v1/timer/resume") => IpcRequest::Resume,
+        ("POST", "/v1/timer/stop") => IpcRequest::Stop,
+        ("POST", "/v1/timer/skip") => IpcRequest::Skip,
+        ("GET", "/v1/history") => return history_response(&request, history_path),
+        (
+            _,
+            "/v1/timer/status" | "/v1/timer/start" | "/v1/timer/pause" | "/v1/timer/resume"
+            | "/v1/timer/stop" | "/v1/timer/skip" | "/v1/history",
+        ) => {
+            return error_response(
+                405,
+                ErrorCode::InvalidRequest,
+                format!("{} は使用できません", request.method),
+            )
+        }
+        _ => {
+            return error_response(
+                404,
+                ErrorCode::NotFound,
+                format!("エンドポイントが見つかりません: {}", request.path),
+            )
+        }
+    };
+
+    let request = TimerRequest {
+        timer: request.query_param("timer"),
+        request: ipc_request,
+    };
+    let response = handle_timer_request(request, timers).await;
+    json_response(status_for(&response), &response)
+}
+
+/// Bearerトークンを検証
+fn is_authorized(request: &HttpRequest, token: &str) -> bool {
+    request
+        .authorization
+        .as_deref()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| constant_time_eq(given.trim().as_bytes(), token.as_bytes()))
+}
+
+/// タイミング攻撃を避けるための比較
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// startのボディ（空の場合はデフォルト）をパース
+fn parse_start_params(body: &[u8]) -> Result<StartParams, serde_json::Error> {
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(StartParams::default());
+    }
+    serde_json::from_slice(body)
+}
+
+/// 履歴を返す（`?date=YYYY-MM-DD` で日付を絞り込み）
+fn history_response(request: &HttpRequest, history_path: Option<PathBuf>) -> HttpResponse {
+    let date = match request.query_param("date") {
+        Some(value) => match NaiveDate::parse_from_str(&value, "%Y-%m-%d") {
+            Ok(date) => Some(date),
+            Err(_) => {
+                return error_response(
+                    400,
+                    ErrorCode::InvalidRequest,
+                    "日付は YYYY-MM-DD 形式で指定してください",
+                )
+            }
+        },
+        None => None,
+    };
+
+    let store = match history_path {
+        Some(path) => HistoryStore::load_from_file(&path),
+        None => HistoryStore::load(),
+    };
+    let store = match store {
+        Ok(store) => store,
+        Err(e) => return error_response(500, ErrorCode::Internal, e.to_string()),
+    };
+
+    let entries = match date {
+        Some(date) => store.entries_on(date),
+        None => store.entries().iter().collect(),
+    };
+    let summary = HistoryStore::summarize(entries.iter().copied());
+
+    json_response(
+        200,
+        &HistoryResponse {
+            status: "success",
+            entries,
+            summary,
+        },
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::registry::NamedTimerEvent;
+    use crate::types::PomodoroConfig;
+    use chrono::{Local, TimeZone, Utc};
+    use tempfile::tempdir;
+    use tokio::sync::mpsc;
+
+    const TOKEN: &str = "test-token";
+
+    /// テスト用レジストリとイベントレシーバーのペア
+    struct TestTimers {
+        timers: Arc<TimerRegistry>,
+        #[allow(dead_code)]
+        event_rx: mpsc::UnboundedReceiver<NamedTimerEvent>,
+    }
+
+    fn create_test_timers() -> TestTimers {
+        let (timers, rx) = TimerRegistry::new(PomodoroConfig::default(), None);
+        TestTimers {
+            timers: Arc::new(timers),
+            event_rx: rx,
+        }
+    }
+
+    /// サーバーを起動してアドレスを返す
+    async fn start_server(timers: Arc<TimerRegistry>, history_path: PathBuf) -> SocketAddr {
+        let server = HttpApiServer::bind(0, TOKEN.to_string())
+            .await
+            .unwrap()
+            .with_history_path(history_path);
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(server.run(timers));
+        addr
+    }
+
+    /// 生のHTTPリクエストを送信し、ステータスコードとボディを返す
+    async fn send_raw(addr: SocketAddr, raw: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(raw.as_bytes()).await.unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        let status = response[9..12].parse().unwrap();
+        let body = response
+            .split_once("\r\n\r\n")
+            .map(|(_, body)| body.to_string())
+            .unwrap_or_default();
+        (status, body)
+    }
+
+    async fn send(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+        let raw = format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer {}\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            TOKEN,
+            body.len(),
+            body
+        );
+        send_raw(addr, &raw).await
+    }
+
+    // ------------------------------------------------------------------------
+    // Config Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_config_default_is_disabled() {
+        let config = HttpApiConfig::default();
+        assert!(!config.enabled);
+        assert_eq!(config.port, DEFAULT_HTTP_PORT);
+    }
+
+    #[test]
+    fn test_config_load_non_existent() {
+        let dir = tempdir().unwrap();
+        let config = HttpApiConfig::load_from_file(&dir.path().join("none.json")).unwrap();
+        assert_eq!(config, HttpApiConfig::default());
+    }
+
+    #[test]
+    fn test_config_save_and_load() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("http-api.json");
+        let config = HttpApiConfig {
+            enabled: true,
+            port: 9000,
+        };
+
+        config.save_to_file(&path).unwrap();
+        assert_eq!(HttpApiConfig::load_from_file(&path).unwrap(), config);
+    }
+
+    #[test]
+    fn test_config_port_defaults_when_missing() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("http-api.json");
+        std::fs::write(&path, r#"{"enabled":true}"#).unwrap();
+
+        let config = HttpApiConfig::load_from_file(&path).unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.port, DEFAULT_HTTP_PORT);
+    }
+
+    #[test]
+    fn test_load_or_create_token() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested").join("http-token");
+
+        let token = load_or_create_token(&path).unwrap();
+        assert_eq!(token.len(), 64);
+        // 2回目は同じトークンを読み込む
+        assert_eq!(load_or_create_token(&path).unwrap(), token);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Parsing Tests
+    // ------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_read_request_with_body_and_query() {
+        let raw = "POST /v1/timer/start?x=1 HTTP/1.1\r\nauthorization: Bearer abc\r\nContent-Length: 2\r\n\r\n{}";
+        let mut reader = BufReader::new(raw.as_bytes());
+
+        let request = read_request(&mut reader).await.unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/v1/timer/start");
+        assert_eq!(request.query_param("x").as_deref(), Some("1"));
+        assert_eq!(request.authorization.as_deref(), Some("Bearer abc"));
+        assert_eq!(request.body, b"{}");
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("prep"), "prep");
+        assert_eq!(percent_decode("my%2Dtimer"), "my-timer");
+        assert_eq!(percent_decode("%e8%a8%ad%E8%A8%88+a"), "設計 a");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%4"), "%zz%4");
+    }
+
+    #[tokio::test]
+    async fn test_read_request_rejects_large_body() {
+        let raw = format!(
+            "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_SIZE + 1
+        );
+        let mut reader = BufReader::new(raw.as_bytes());
+
+        let result = read_request(&mut reader).await;
+        assert!(matches!(result, Err(HttpError::PayloadTooLarge)));
+    }
+
+    #[tokio::test]
+    async fn test_read_request_rejects_large_header() {
+        let raw = format!(
+            "GET / HTTP/1.1\r\nX-Long: {}\r\n\r\n",
+            "a".repeat(MAX_HEADER_SIZE)
+        );
+        let mut reader = BufReader::new(raw.as_bytes());
+
+        let result = read_request(&mut reader).await;
+        assert!(matches!(result, Err(HttpError::PayloadTooLarge)));
+    }
+
+    #[tokio::test]
+    async fn test_read_request_malformed() {
+        let mut reader = BufReader::new(&b"GARBAGE\r\n\r\n"[..]);
+        let result = read_request(&mut reader).await;
+        assert!(matches!(result, Err(HttpError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"token", b"token"));
+        assert!(!constant_time_eq(b"token", b"tokem"));
+        assert!(!constant_time_eq(b"token", b"token2"));
+    }
+
+    #[test]
+    fn test_status_for_error_codes() {
+        assert_eq!(status_for(&IpcResponse::success("", None)), 200);
+        assert_eq!(
+            status_for(&IpcResponse::error(ErrorCode::AlreadyRunning, "")),
+            409
+        );
+        assert_eq!(
+            status_for(&IpcResponse::error(ErrorCode::Internal, "")),
+            500
+        );
+    }
+
+    // ------------------------------------------------------------------------
+    // Server Tests
+    // ------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_requires_token() {
+        let dir = tempdir().unwrap();
+        let test_timers = create_test_timers();
+        let addr = start_server(test_timers.timers.clone(), dir.path().join("h.json")).await;
+
+        let (status, body) = send_raw(
+            addr,
+            "GET /v1/timer/status HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        )
+        .await;
+        assert_eq!(status, 401);
+        assert!(body.contains("\"code\":\"unauthorized\""));
+
+        let (status, _) = send_raw(
+            addr,
+            "GET /v1/timer/status HTTP/1.1\r\nAuthorization: Bearer wrong\r\n\r\n",
+        )
+        .await;
+        assert_eq!(status, 401);
+    }
+
+    #[tokio::test]
+    async fn test_timer_control_matches_ipc() {
+        let dir = tempdir().unwrap();
+        let test_timers = create_test_timers();
+        let addr = start_server(test_timers.timers.clone(), dir.path().join("h.json")).await;
+
+        let (status, body) = send(addr, "POST", "/v1/timer/start", r#"{"taskName":"API"}"#).await;
+        assert_eq!(status, 200);
+        let response: IpcResponse = serde_json::from_str(&body).unwrap();
+        assert_eq!(response.message, "タイマーを開始しました");
+        assert_eq!(response.data.unwrap().task_name, Some("API".to_string()));
+
+        // 2回目のstartはIPCと同じエラーコードを返す
+        let (status, body) = send(addr, "POST", "/v1/timer/start", "").await;
+        assert_eq!(status, 409);
+        let response: IpcResponse = serde_json::from_str(&body).unwrap();
+        assert_eq!(response.code, Some(ErrorCode::AlreadyRunning));
+
+        let (status, _) = send(addr, "POST", "/v1/timer/pause", "").await;
+        assert_eq!(status, 200);
+        let (status, _) = send(addr, "POST", "/v1/timer/resume", "").await;
+        assert_eq!(status, 200);
+        let (status, body) = send(addr, "POST", "/v1/timer/skip", "").await;
+        assert_eq!(status, 200);
+        assert!(body.contains("\"breaking\""));
+
+        let (status, body) = send(addr, "GET", "/v1/timer/status", "").await;
+        assert_eq!(status, 200);
+        assert!(body.contains("\"breaking\""));
+
+        let (status, _) = send(addr, "POST", "/v1/timer/stop", "").await;
+        assert_eq!(status, 200);
+        assert!(!test_timers
+            .timers
+            .default_timer()
+            .engine()
+            .lock()
+            .await
+            .get_state()
+            .is_running());
+    }
+
+    #[tokio::test]
+    async fn test_timer_query_selects_named_timer() {
+        let dir = tempdir().unwrap();
+        let test_timers = create_test_timers();
+        let addr = start_server(test_timers.timers.clone(), dir.path().join("h.json")).await;
+
+        let (status, _) = send(addr, "POST", "/v1/timer/start?timer=prep", "").await;
+        assert_eq!(status, 200);
+        // クエリの値はデコードしてから照合する
+        let (status, body) = send(addr, "GET", "/v1/timer/status?timer=%70rep", "").await;
+        assert_eq!(status, 200);
+        assert!(body.contains("\"working\""));
+
+        // デフォルトタイマーは操作されない
+        let (status, body) = send(addr, "GET", "/v1/timer/status", "").await;
+        assert_eq!(status, 200);
+        assert!(body.contains("\"stopped\""));
+
+        let (status, body) = send(addr, "POST", "/v1/timer/pause?timer=other", "").await;
+        assert_eq!(status, 404);
+        assert!(body.contains("\"code\":\"not_found\""));
+
+        // 名前付きタイマーはstopで削除される
+        let (status, _) = send(addr, "POST", "/v1/timer/stop?timer=prep", "").await;
+        assert_eq!(status, 200);
+        assert!(test_timers.timers.get("prep").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_start_with_invalid_body() {
+        let dir = tempdir().unwrap();
+        let test_timers = create_test_timers();
+        let addr = start_server(test_timers.timers.clone(), dir.path().join("h.json")).await;
+
+        let (status, body) = send(addr, "POST", "/v1/timer/start", "not json").await;
+        assert_eq!(status, 400);
+        assert!(body.contains("\"code\":\"invalid_request\""));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_route_and_method() {
+        let dir = tempdir().unwrap();
+        let test_timers = create_test_timers();
+        let addr = start_server(test_timers.timers.clone(), dir.path().join("h.json")).await;
+
+        let (status, _) = send(addr, "GET", "/v1/unknown", "").await;
+        assert_eq!(status, 404);
+
+        let (status, _) = send(addr, "GET", "/v1/timer/start", "").await;
+        assert_eq!(status, 405);
+    }
+
+    #[tokio::test]
+    async fn test_history_query() {
+        let dir = tempdir().unwrap();
+        let history_path = dir.path().join("history.json");
+
+        let started = Local.with_ymd_and_hms(2026, 1, 10, 14, 0, 0).unwrap();
+        let mut store = HistoryStore::default();
+        store.record_completed(
+            Some("開発".to_string()),
+            (started + chrono::Duration::minutes(25)).with_timezone(&Utc),
+            25 * 60,
+        );
+        store.save_to_file(&history_path).unwrap();
+
+        let test_timers = create_test_timers();
+        let addr = start_server(test_timers.timers.clone(), history_path).await;
+
+        let (status, body) = send(addr, "GET", "/v1/history?date=2026-01-10", "").await;
+        assert_eq!(status, 200);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["entries"].as_array().unwrap().len(), 1);
+        assert_eq!(json["entries"][0]["task_name"], "開発");
+        assert_eq!(json["summary"]["count"], 1);
+        assert_eq!(json["summary"]["total_secs"], 1500);
+
+        let (_, body) = send(addr, "GET", "/v1/history?date=2026-01-11", "").await;
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(json["entries"].as_array().unwrap().is_empty());
+
+        let (status, _) = send(addr, "GET", "/v1/history?date=yesterday", "").await;
+        assert_eq!(status, 400);
+    }
+}
@@ -2,24 +2,60 @@
 //!
 //! Unix Domain Socketを使用したプロセス間通信サーバーを提供する。
 //! CLIクライアントからのリクエストを受け付け、タイマーエンジンを操作する。
+//!
+//! メッセージは改行区切りのJSON（1行1メッセージ）で送受信し、
+//! 1つの接続で複数のリクエストを順に処理できる。
+//! `jsonrpc` フィールドを含むメッセージはJSON-RPC 2.0として処理する（`jsonrpc` モジュール）。
+//!
+//! ソケットとそのディレクトリは所有者のみアクセス可能なパーミッションで作成し、
+//! 接続元プロセスのUIDがデーモンと異なる場合は接続を拒否する。
 
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
 use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncReadExt, BufReader};
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::net::{UnixListener, UnixStream};
-use tokio::sync::Mutex;
-use tokio::time::{timeout, Duration};
-
-use crate::daemon::TimerEngine;
-use crate::types::{IpcRequest, IpcResponse, ResponseData, StartParams};
+use tokio::sync::{broadcast, Mutex, Notify};
+use tokio::time::{interval, timeout, Duration, MissedTickBehavior};
+
+use crate::daemon::jsonrpc::{self, RpcError, RpcResponse};
+use crate::daemon::{ConfigReloader, TimerEngine, TimerEvent, TimerRegistry, TimerRegistryError};
+use crate::metrics::Metrics;
+use crate::types::framing::{read_frame, write_frame, FrameError, DEFAULT_MAX_MESSAGE_SIZE};
+use crate::types::{
+    ErrorCode, IpcRequest, IpcResponse, ResponseData, StartParams, StreamMessage, TimerRequest,
+    DEFAULT_TIMER_NAME, PROTOCOL_VERSION,
+};
 
 /// 接続タイムアウト（秒）
 const CONNECTION_TIMEOUT_SECS: u64 = 5;
 
-/// リクエストバッファサイズ
-const REQUEST_BUFFER_SIZE: usize = 4096;
+/// 接続を維持したままリクエストを待つ最大時間（秒）
+const IDLE_TIMEOUT_SECS: u64 = 60;
+
+/// イベント購読時の定期スナップショット間隔（秒）
+const SNAPSHOT_INTERVAL_SECS: u64 = 5;
+
+/// Helloで通知するデーモンの対応機能
+const CAPABILITIES: &[&str] = &[
+    "subscribe",
+    "ndjson_framing",
+    "error_codes",
+    "jsonrpc",
+    "timers",
+];
+
+/// イベント配信の形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamFormat {
+    /// StreamMessageをそのまま送信
+    Native,
+    /// JSON-RPC 2.0の通知として送信
+    JsonRpc,
+}
 
 /// IPCサーバー
 ///
@@ -30,13 +66,25 @@ pub struct IpcServer {
     listener: UnixListener,
     /// ソケットパス
     socket_path: std::path::PathBuf,
+    /// 最大メッセージサイズ（バイト）
+    max_message_size: usize,
+    /// 終了要求の通知（`IpcRequest::Shutdown`）
+    shutdown: Arc<Notify>,
+    /// 設定の再読み込み機能（`IpcRequest::Reload`）
+    reloader: Option<Arc<ConfigReloader>>,
+    /// リクエスト数の記録先
+    metrics: Option<Arc<Metrics>>,
 }
 
 impl IpcServer {
     /// 新しいIPCサーバーを作成
     ///
     /// 指定されたパスにUnix Domain Socketを作成し、リッスンを開始する。
-    /// 既存のソケットファイルがある場合は削除する。
+    /// 既存のソケットファイルは、応答するデーモンがない場合のみ削除する。
+    ///
+    /// 親ディレクトリは存在しない場合に0700で作成し、ソケットは0600に設定する
+    /// （設定前のソケットが他ユーザーから見えないよう、0700の一時ディレクトリでバインドしてから移動する）。
+    /// 既存の親ディレクトリがグループまたは他ユーザーから書き込み可能な場合は警告を出す。
     ///
     /// # Arguments
     ///
@@ -44,109 +92,565 @@ impl IpcServer {
     ///
     /// # Errors
     ///
+    /// - 既存のソケットで別のデーモンが応答している場合
     /// - ソケットファイルの削除に失敗した場合
     /// - ソケットのバインドに失敗した場合
     pub fn new(socket_path: &Path) -> Result<Self> {
         // 親ディレクトリが存在することを確認
         if let Some(parent) = socket_path.parent() {
             if !parent.exists() {
-                std::fs::create_dir_all(parent).context("Failed to create socket directory")?;
+                create_private_dir(parent).context("Failed to create socket directory")?;
+            } else if is_insecure_dir(parent)? {
+                eprintln!(
+                    "Warning: socket directory {:?} is writable by group or others; \
+                     run `chmod 700 {}` to restrict access",
+                    parent,
+                    parent.display()
+                );
             }
         }
 
-        // 既存のソケットファイルを削除
+        // 既存のソケットファイルを削除（起動中のデーモンから奪わない）
         if socket_path.exists() {
+            if std::os::unix::net::UnixStream::connect(socket_path).is_ok() {
+                anyhow::bail!("Another daemon is already listening on {:?}", socket_path);
+            }
             std::fs::remove_file(socket_path).context("Failed to remove existing socket file")?;
         }
 
-        let listener = UnixListener::bind(socket_path).context("Failed to bind Unix socket")?;
+        let listener = bind_private(socket_path)?;
 
         Ok(Self {
             listener,
             socket_path: socket_path.to_path_buf(),
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            shutdown: Arc::new(Notify::new()),
+            reloader: None,
+            metrics: None,
         })
     }
 
+    /// 最大メッセージサイズを設定
+    ///
+    /// 受信・送信するメッセージ1件あたりの上限（バイト、改行を除く）。
+    /// デフォルトは `DEFAULT_MAX_MESSAGE_SIZE`。
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// 最大メッセージサイズを取得
+    pub fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
     /// クライアント接続を受け付ける
     ///
     /// 新しいクライアント接続があるまでブロックする。
+    /// 接続元のUIDがデーモンと異なる場合は、エラーレスポンスを返して切断し、次の接続を待つ。
     ///
     /// # Returns
     ///
-    /// 接続されたUnixStream
-    pub async fn accept(&self) -> Result<UnixStream> {
-        let (stream, _) = self
-            .listener
-            .accept()
-            .await
-            .context("Failed to accept connection")?;
-        Ok(stream)
+    /// 接続されたクライアントとのIpcConnection
+    pub async fn accept(&self) -> Result<IpcConnection> {
+        loop {
+            let (stream, _) = self
+                .listener
+                .accept()
+                .await
+                .context("Failed to accept connection")?;
+
+            let peer_uid = stream
+                .peer_cred()
+                .context("Failed to get peer credentials")?
+                .uid();
+            let mut connection = IpcConnection::new(stream, self.max_message_size);
+            if is_same_user(peer_uid) {
+                connection.shutdown = Some(self.shutdown.clone());
+                connection.reloader = self.reloader.clone();
+                connection.metrics = self.metrics.clone();
+                return Ok(connection);
+            }
+
+            eprintln!("Rejected IPC connection from uid {}", peer_uid);
+            tokio::spawn(connection.reject());
+        }
     }
 
-    /// リクエストを受信
+    /// ソケットパスを取得
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    /// 設定の再読み込み機能を設定
     ///
-    /// ストリームからJSONリクエストを読み取り、デシリアライズする。
-    /// タイムアウトは5秒。
+    /// 設定しない場合、`IpcRequest::Reload` はエラーになる。
+    pub fn with_reloader(mut self, reloader: Arc<ConfigReloader>) -> Self {
+        self.reloader = Some(reloader);
+        self
+    }
+
+    /// コマンドごとのリクエスト数の記録先を設定
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// 終了を要求する
+    ///
+    /// `shutdown_requested` で待機しているタスクに通知する（待機前の要求も保持される）。
+    pub fn request_shutdown(&self) {
+        self.shutdown.notify_one();
+    }
+
+    /// クライアントから終了が要求されるまで待つ
+    ///
+    /// このサーバーが受け付けた接続で `IpcRequest::Shutdown` を受信すると完了する。
+    pub async fn shutdown_requested(&self) {
+        self.shutdown.notified().await;
+    }
+}
+
+impl Drop for IpcServer {
+    fn drop(&mut self) {
+        // サーバー終了時にソケットファイルを削除
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+/// クライアントとの接続
+///
+/// 改行区切りのJSONでリクエストを受信し、レスポンスを送信する。
+pub struct IpcConnection {
+    /// 受信側（フレーム単位で読み取るためバッファリングする）
+    reader: BufReader<OwnedReadHalf>,
+    /// 送信側
+    writer: OwnedWriteHalf,
+    /// 最大メッセージサイズ（バイト）
+    max_message_size: usize,
+    /// 終了要求の通知先（IpcServerが受け付けた接続のみ）
+    shutdown: Option<Arc<Notify>>,
+    /// 設定の再読み込み機能（IpcServerが受け付けた接続のみ）
+    reloader: Option<Arc<ConfigReloader>>,
+    /// リクエスト数の記録先（IpcServerが受け付けた接続のみ）
+    metrics: Option<Arc<Metrics>>,
+}
+
+impl IpcConnection {
+    /// ストリームから接続を作成
     ///
     /// # Arguments
     ///
     /// * `stream` - クライアントストリーム
+    /// * `max_message_size` - 最大メッセージサイズ（バイト）
+    pub fn new(stream: UnixStream, max_message_size: usize) -> Self {
+        let (reader, writer) = stream.into_split();
+        Self {
+            reader: BufReader::new(reader),
+            writer,
+            max_message_size,
+            shutdown: None,
+            reloader: None,
+            metrics: None,
+        }
+    }
+
+    /// リクエストを受信
+    ///
+    /// ストリームから1メッセージ分のJSONリクエストを読み取り、デシリアライズする。
+    /// タイムアウトは5秒。
     ///
     /// # Returns
     ///
     /// パースされたIpcRequest
-    pub async fn receive_request(stream: &mut UnixStream) -> Result<IpcRequest> {
-        let mut buffer = vec![0u8; REQUEST_BUFFER_SIZE];
-
-        let n = timeout(
+    ///
+    /// # Errors
+    ///
+    /// - クライアントが接続を閉じた場合
+    /// - メッセージが最大サイズを超えた場合
+    /// - JSONのパースに失敗した場合
+    pub async fn receive_request(&mut self) -> Result<IpcRequest> {
+        let frame = timeout(
             Duration::from_secs(CONNECTION_TIMEOUT_SECS),
-            stream.read(&mut buffer),
+            read_frame(&mut self.reader, self.max_message_size),
         )
         .await
         .context("Request read timed out")?
-        .context("Failed to read from socket")?;
-
-        if n == 0 {
-            anyhow::bail!("Connection closed by client");
-        }
+        .context("Failed to read from socket")?
+        .context("Connection closed by client")?;
 
         let request: IpcRequest =
-            serde_json::from_slice(&buffer[..n]).context("Failed to parse request JSON")?;
+            serde_json::from_slice(&frame).context("Failed to parse request JSON")?;
 
         Ok(request)
     }
 
     /// レスポンスを送信
     ///
-    /// IpcResponseをJSON形式でストリームに書き込む。
+    /// IpcResponseを1行のJSONとしてストリームに書き込む。
+    /// レスポンスが最大メッセージサイズを超える場合は、代わりにエラーレスポンスを送信する。
     ///
     /// # Arguments
     ///
-    /// * `stream` - クライアントストリーム
     /// * `response` - 送信するレスポンス
-    pub async fn send_response(stream: &mut UnixStream, response: &IpcResponse) -> Result<()> {
+    pub async fn send_response(&mut self, response: &IpcResponse) -> Result<()> {
         let json = serde_json::to_vec(response).context("Failed to serialize response")?;
 
-        stream
-            .write_all(&json)
-            .await
-            .context("Failed to write to socket")?;
+        match write_frame(&mut self.writer, &json, self.max_message_size).await {
+            Err(e @ FrameError::TooLarge { .. }) => {
+                self.send_error(
+                    ErrorCode::MessageTooLarge,
+                    format!("レスポンスを送信できません: {}", e),
+                )
+                .await
+            }
+            result => result.context("Failed to write to socket"),
+        }
+    }
 
-        stream.flush().await.context("Failed to flush socket")?;
+    /// JSON-RPCの応答を送信
+    ///
+    /// 応答が最大メッセージサイズを超える場合は、代わりにエラー応答を送信する。
+    async fn send_rpc(&mut self, reply: &impl serde::Serialize) -> Result<()> {
+        let json = serde_json::to_vec(reply).context("Failed to serialize response")?;
+
+        match write_frame(&mut self.writer, &json, self.max_message_size).await {
+            Err(e @ FrameError::TooLarge { .. }) => {
+                let error = RpcResponse::error(
+                    serde_json::Value::Null,
+                    RpcError::from_code(
+                        ErrorCode::MessageTooLarge,
+                        format!("レスポンスを送信できません: {}", e),
+                    ),
+                );
+                let json = serde_json::to_vec(&error).context("Failed to serialize response")?;
+                write_frame(&mut self.writer, &json, usize::MAX)
+                    .await
+                    .context("Failed to write to socket")
+            }
+            result => result.context("Failed to write to socket"),
+        }
+    }
 
+    /// 終了要求に応答し、サーバーへ通知する
+    async fn accept_shutdown(&mut self) -> Result<()> {
+        let Some(shutdown) = self.shutdown.clone() else {
+            let message = "この接続では終了要求を受け付けられません".to_string();
+            return self.send_error(ErrorCode::InvalidRequest, message).await;
+        };
+
+        self.send_response(&IpcResponse::success("デーモンを終了します", None))
+            .await?;
+        shutdown.notify_one();
         Ok(())
     }
 
-    /// ソケットパスを取得
-    pub fn socket_path(&self) -> &Path {
-        &self.socket_path
+    /// 設定を再読み込みしてレスポンスを作成
+    fn reload_config(&self) -> IpcResponse {
+        let Some(reloader) = &self.reloader else {
+            return IpcResponse::error(
+                ErrorCode::InvalidRequest,
+                "この接続では再読み込みを受け付けられません",
+            );
+        };
+
+        match reloader.reload() {
+            Ok(summary) => IpcResponse::success(
+                format!(
+                    "設定を再読み込みしました（フック: {}件）",
+                    summary.hook_count
+                ),
+                None,
+            ),
+            Err(e) => IpcResponse::error(
+                ErrorCode::InvalidConfig,
+                format!("{}（現在の設定を維持します）", e),
+            ),
+        }
+    }
+
+    /// JSON-RPCのメソッド名ごとにリクエスト数を記録する（バッチは要素ごと）
+    ///
+    /// 未対応のメソッドは `unknown` にまとめる。
+    fn record_rpc_methods(&self, value: &serde_json::Value) {
+        let Some(metrics) = &self.metrics else {
+            return;
+        };
+        let messages = match value {
+            serde_json::Value::Array(messages) => messages.as_slice(),
+            message => std::slice::from_ref(message),
+        };
+        for message in messages {
+            let method = message["method"].as_str().unwrap_or_default();
+            let method = jsonrpc::METHODS
+                .iter()
+                .find(|m| **m == method)
+                .copied()
+                .unwrap_or("unknown");
+            metrics.record_ipc_request(method);
+        }
+    }
+
+    /// 認証エラーを送信して切断する
+    async fn reject(mut self) {
+        let _ = self
+            .send_error(
+                ErrorCode::Unauthorized,
+                "デーモンと異なるユーザーからの接続は許可されていません".to_string(),
+            )
+            .await;
+    }
+
+    /// エラーレスポンスを送信
+    ///
+    /// サイズ超過を通知できるよう、最大メッセージサイズの制限を適用しない。
+    async fn send_error(&mut self, code: ErrorCode, message: String) -> Result<()> {
+        let json = serde_json::to_vec(&IpcResponse::error(code, message))
+            .context("Failed to serialize response")?;
+
+        write_frame(&mut self.writer, &json, usize::MAX)
+            .await
+            .context("Failed to write to socket")
+    }
+
+    /// 接続を処理
+    ///
+    /// クライアントが接続を閉じるまでリクエストを順に受信し、レスポンスを返す。
+    /// 不正なJSONにはエラーレスポンスを返して次のリクエストを待つ。
+    /// 最大サイズを超えるメッセージを受信した場合は、エラーレスポンスを返して接続を閉じる。
+    /// `IpcRequest::Subscribe` を受信した場合はイベント配信に切り替わる。
+    /// `IpcRequest::Shutdown` を受信した場合は応答後にサーバーへ終了を通知し、接続を閉じる。
+    /// `IpcRequest::Reload` はサーバーに設定された `ConfigReloader` で処理する。
+    /// リクエストの `timer` フィールドで対象のタイマーを選び、省略時はデフォルトタイマーを使う。
+    /// JSON-RPCのメッセージは `jsonrpc::handle_message` で処理し（params の `timer` で対象を選ぶ）、
+    /// `timer.subscribe` の場合は通知形式でイベント配信に切り替わる。
+    ///
+    /// # Arguments
+    ///
+    /// * `timers` - タイマーのレジストリ
+    pub async fn serve(mut self, timers: Arc<TimerRegistry>) -> Result<()> {
+        loop {
+            let frame = match timeout(
+                Duration::from_secs(IDLE_TIMEOUT_SECS),
+                read_frame(&mut self.reader, self.max_message_size),
+            )
+            .await
+            {
+                // 一定時間リクエストがなければ接続を閉じる
+                Err(_) => return Ok(()),
+                Ok(Ok(Some(frame))) => frame,
+                Ok(Ok(None)) => return Ok(()),
+                Ok(Err(e @ FrameError::TooLarge { .. })) => {
+                    // 残りのデータと同期が取れないため、エラーを返して切断する
+                    self.send_error(ErrorCode::MessageTooLarge, e.to_string())
+                        .await?;
+                    return Err(e).context("Failed to read from socket");
+                }
+                Ok(Err(e)) => return Err(e).context("Failed to read from socket"),
+            };
+
+            let value: serde_json::Value = match serde_json::from_slice(&frame) {
+                Ok(value) => value,
+                Err(e) if jsonrpc::looks_like_jsonrpc(&frame) => {
+                    self.send_rpc(&jsonrpc::parse_error(&e)).await?;
+                    continue;
+                }
+                Err(e) => {
+                    let message = format!("リクエストの解析に失敗しました: {}", e);
+                    self.send_error(ErrorCode::InvalidRequest, message).await?;
+                    continue;
+                }
+            };
+
+            if jsonrpc::is_jsonrpc(&value) {
+                self.record_rpc_methods(&value);
+                let outcome = jsonrpc::handle_message(value, &timers).await;
+                if let Some(reply) = outcome.reply {
+                    self.send_rpc(&reply).await?;
+                }
+                if let Some(timer) = outcome.subscribe.and_then(|name| timers.get(&name)) {
+                    let events = timer.events().subscribe();
+                    return self
+                        .stream(timer.engine().clone(), events, StreamFormat::JsonRpc)
+                        .await;
+                }
+                continue;
+            }
+
+            let request: TimerRequest = match serde_json::from_value(value) {
+                Ok(request) => request,
+                Err(e) => {
+                    let message = format!("リクエストの解析に失敗しました: {}", e);
+                    self.send_error(ErrorCode::InvalidRequest, message).await?;
+                    continue;
+                }
+            };
+
+            if let Some(metrics) = &self.metrics {
+                metrics.record_ipc_request(request.request.command_name());
+            }
+
+            match request.request {
+                IpcRequest::Subscribe => match timers.find(request.timer_name()) {
+                    Ok(timer) => {
+                        let events = timer.events().subscribe();
+                        return self.stream_events(timer.engine().clone(), events).await;
+                    }
+                    Err(e) => self.send_response(&registry_error_response(e)).await?,
+                },
+                IpcRequest::Shutdown => return self.accept_shutdown().await,
+                IpcRequest::Reload => {
+                    let response = self.reload_config();
+                    self.send_response(&response).await?;
+                }
+                _ => {
+                    let response = handle_timer_request(request, &timers).await;
+                    self.send_response(&response).await?;
+                }
+            }
+        }
+    }
+
+    /// イベントを配信し続ける
+    ///
+    /// `IpcRequest::Subscribe` を受け付けた接続に対して、接続直後の状態スナップショット、
+    /// 以降のすべてのTimerEvent、状態変化後および定期的なスナップショットを
+    /// 1行1メッセージのJSON（NDJSON）で送信する。
+    /// クライアントが切断するか、イベントチャネルが閉じるまで戻らない。
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - タイマーエンジン（スナップショット取得用）
+    /// * `events` - タイマーイベントの購読レシーバー
+    pub async fn stream_events(
+        &mut self,
+        engine: Arc<Mutex<TimerEngine>>,
+        events: broadcast::Receiver<TimerEvent>,
+    ) -> Result<()> {
+        self.stream(engine, events, StreamFormat::Native).await
+    }
+
+    /// 指定された形式でイベントを配信し続ける
+    async fn stream(
+        &mut self,
+        engine: Arc<Mutex<TimerEngine>>,
+        mut events: broadcast::Receiver<TimerEvent>,
+        format: StreamFormat,
+    ) -> Result<()> {
+        let mut snapshot_ticker = interval(Duration::from_secs(SNAPSHOT_INTERVAL_SECS));
+        snapshot_ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut probe = [0u8; 1];
+
+        loop {
+            let message = tokio::select! {
+                // 定期スナップショット（初回は即時発火）
+                _ = snapshot_ticker.tick() => Some(snapshot(&engine).await),
+
+                // タイマーイベント
+                result = events.recv() => match result {
+                    Ok(event) => {
+                        let changes_state = !matches!(event, TimerEvent::Tick { .. });
+                        self.write_message(StreamMessage::Event { event }, format).await?;
+                        // フェーズが変わるイベントの後は最新状態を送る
+                        if changes_state {
+                            Some(snapshot(&engine).await)
+                        } else {
+                            None
+                        }
+                    }
+                    // 取りこぼした場合はスナップショットで同期し直す
+                    Err(broadcast::error::RecvError::Lagged(_)) => Some(snapshot(&engine).await),
+                    Err(broadcast::error::RecvError::Closed) => return Ok(()),
+                },
+
+                // クライアントの切断検知
+                result = self.reader.read(&mut probe) => match result {
+                    Ok(0) | Err(_) => return Ok(()),
+                    Ok(_) => None,
+                },
+            };
+
+            if let Some(message) = message {
+                self.write_message(message, format).await?;
+            }
+        }
+    }
+
+    /// ストリームメッセージを1行のJSONとして書き込む
+    async fn write_message(&mut self, message: StreamMessage, format: StreamFormat) -> Result<()> {
+        let json = match format {
+            StreamFormat::Native => serde_json::to_vec(&message),
+            StreamFormat::JsonRpc => serde_json::to_vec(&jsonrpc::notification(message)),
+        }
+        .context("Failed to serialize stream message")?;
+
+        write_frame(&mut self.writer, &json, self.max_message_size)
+            .await
+            .context("Failed to write to socket")
     }
 }
 
-impl Drop for IpcServer {
-    fn drop(&mut self) {
-        // サーバー終了時にソケットファイルを削除
-        let _ = std::fs::remove_file(&self.socket_path);
+/// 所有者のみアクセス可能（0700）なディレクトリを作成
+pub(crate) fn create_private_dir(path: &Path) -> std::io::Result<()> {
+    std::fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(path)
+}
+
+/// 0600のソケットをバインドする
+///
+/// バインド直後のソケットはumaskに従った権限になるため、所有者だけが入れる0700の一時ディレクトリ内で
+/// バインドして0600に設定してから、`socket_path` へ移動する。
+/// umaskの変更はプロセス全体（他のスレッドのファイル作成）に影響するため使わない。
+fn bind_private(socket_path: &Path) -> Result<UnixListener> {
+    let file_name = socket_path
+        .file_name()
+        .context("Socket path has no file name")?;
+    let staging_dir = socket_path.with_file_name(format!(".bind-{}", std::process::id()));
+
+    // 同じPIDで異常終了したプロセスの残骸
+    if staging_dir.exists() {
+        std::fs::remove_dir_all(&staging_dir)
+            .context("Failed to remove stale socket staging directory")?;
+    }
+    std::fs::DirBuilder::new()
+        .mode(0o700)
+        .create(&staging_dir)
+        .context("Failed to create socket staging directory")?;
+
+    let staging_path = staging_dir.join(file_name);
+    let result = (|| {
+        let listener = UnixListener::bind(&staging_path).context("Failed to bind Unix socket")?;
+        std::fs::set_permissions(&staging_path, std::fs::Permissions::from_mode(0o600))
+            .context("Failed to set socket permissions")?;
+        std::fs::rename(&staging_path, socket_path).context("Failed to move Unix socket")?;
+        Ok(listener)
+    })();
+    let _ = std::fs::remove_dir_all(&staging_dir);
+    result
+}
+
+/// ディレクトリがグループまたは他ユーザーから書き込み可能かどうか
+fn is_insecure_dir(path: &Path) -> Result<bool> {
+    let metadata = std::fs::metadata(path).context("Failed to read socket directory metadata")?;
+    Ok(metadata.permissions().mode() & 0o022 != 0)
+}
+
+/// 接続元のUIDがデーモンのUIDと一致するかどうか
+///
+/// UIDはLinuxではSO_PEERCRED、macOSではgetpeereidで取得される。
+fn is_same_user(peer_uid: u32) -> bool {
+    // SAFETY: getuidは常に成功し、副作用がない
+    peer_uid == unsafe { libc::getuid() }
+}
+
+/// 現在の状態スナップショットを作成
+async fn snapshot(engine: &Arc<Mutex<TimerEngine>>) -> StreamMessage {
+    let engine = engine.lock().await;
+    StreamMessage::Snapshot {
+        data: status_data(&engine),
     }
 }
 
@@ -163,34 +667,114 @@ impl Drop for IpcServer {
 ///
 /// 処理結果を含むIpcResponse
 pub async fn handle_request(request: IpcRequest, engine: Arc<Mutex<TimerEngine>>) -> IpcResponse {
-    let mut engine = engine.lock().await;
-
+    // start・skipは事前フックの実行中にロックを保持しないよう、ハンドラ内でロックする
     match request {
-        IpcRequest::Start { params } => handle_start(&mut engine, params),
-        IpcRequest::Pause => handle_pause(&mut engine),
-        IpcRequest::Resume => handle_resume(&mut engine),
-        IpcRequest::Stop => handle_stop(&mut engine),
-        IpcRequest::Status => handle_status(&engine),
+        IpcRequest::Start { params } => handle_start(&engine, params).await,
+        IpcRequest::Pause => handle_pause(&mut *engine.lock().await),
+        IpcRequest::Resume => handle_resume(&mut *engine.lock().await),
+        IpcRequest::Stop => handle_stop(&mut *engine.lock().await),
+        IpcRequest::Skip => handle_skip(&engine).await,
+        IpcRequest::Status => handle_status(&*engine.lock().await),
+        IpcRequest::Subscribe => IpcResponse::error(
+            ErrorCode::InvalidRequest,
+            "subscribeはイベント購読用の接続でのみ使用できます",
+        ),
+        IpcRequest::Shutdown => IpcResponse::error(
+            ErrorCode::InvalidRequest,
+            "shutdownはIPCソケットでのみ使用できます",
+        ),
+        IpcRequest::Reload => IpcResponse::error(
+            ErrorCode::InvalidRequest,
+            "reloadはIPCソケットでのみ使用できます",
+        ),
+        IpcRequest::List => handle_list_single(&*engine.lock().await),
+        IpcRequest::Hello { protocol_version } => handle_hello(protocol_version),
     }
 }
 
+/// 対象タイマーを指定したリクエストを処理
+///
+/// `start` は存在しないタイマーを作成し、名前付きタイマーは `stop` で削除する。
+/// `start` が失敗した場合（事前フックによる拒否など）、停止中の名前付きタイマーは残さない。
+/// `list` はすべてのタイマーの状態を返す。その他のリクエストは `handle_request` で処理する。
+///
+/// # Arguments
+///
+/// * `request` - 対象タイマーを指定したリクエスト
+/// * `timers` - タイマーのレジストリ
+pub async fn handle_timer_request(request: TimerRequest, timers: &TimerRegistry) -> IpcResponse {
+    let name = request.timer_name().to_string();
+    let timer = match &request.request {
+        IpcRequest::List => return handle_list(timers).await,
+        IpcRequest::Hello { .. } => Ok(timers.default_timer()),
+        IpcRequest::Start { .. } => timers.get_or_create(&name),
+        _ => timers.find(&name),
+    };
+    let timer = match timer {
+        Ok(timer) => timer,
+        Err(e) => return registry_error_response(e),
+    };
+
+    let is_start = matches!(request.request, IpcRequest::Start { .. });
+    let is_stop = matches!(request.request, IpcRequest::Stop);
+    let response = handle_request(request.request, timer.engine().clone()).await;
+    if is_stop && response.is_success() {
+        timers.remove(&name);
+    } else if is_start && !response.is_success() {
+        timers.remove_if_stopped(&name).await;
+    }
+    response
+}
+
+/// listコマンドを処理（単一のタイマー）
+fn handle_list_single(engine: &TimerEngine) -> IpcResponse {
+    let data = ResponseData {
+        timers: Some(vec![ResponseData {
+            timer: Some(DEFAULT_TIMER_NAME.to_string()),
+            ..state_data(engine)
+        }]),
+        ..Default::default()
+    };
+    IpcResponse::success("", Some(data))
+}
+
+/// listコマンドを処理
+async fn handle_list(timers: &TimerRegistry) -> IpcResponse {
+    let mut list = Vec::new();
+    for (name, timer) in timers.timers() {
+        let engine = timer.engine().lock().await;
+        list.push(ResponseData {
+            timer: Some(name),
+            ..state_data(&engine)
+        });
+    }
+
+    let data = ResponseData {
+        timers: Some(list),
+        ..Default::default()
+    };
+    IpcResponse::success("", Some(data))
+}
+
+/// レジストリのエラーをエラーレスポンスに変換
+pub(crate) fn registry_error_response(error: TimerRegistryError) -> IpcResponse {
+    let code = match error {
+        TimerRegistryError::NotFound(_) => ErrorCode::NotFound,
+        TimerRegistryError::InvalidName(_)
+        | TimerRegistryError::TooManyTimers
+        | TimerRegistryError::Unsupported => ErrorCode::InvalidRequest,
+    };
+    IpcResponse::error(code, error.to_string())
+}
+
 /// startコマンドを処理
-fn handle_start(engine: &mut TimerEngine, params: StartParams) -> IpcResponse {
-    match engine.start(&params) {
-        Ok(()) => {
-            let state = engine.get_state();
-            IpcResponse::success(
-                "タイマーを開始しました",
-                Some(ResponseData {
-                    state: Some(state.phase.as_str().to_string()),
-                    remaining_seconds: Some(state.remaining_seconds),
-                    pomodoro_count: Some(state.pomodoro_count),
-                    task_name: state.task_name.clone(),
-                    duration: Some(state.current_duration()),
-                }),
-            )
-        }
-        Err(e) => IpcResponse::error(e.to_string()),
+async fn handle_start(engine: &Mutex<TimerEngine>, params: StartParams) -> IpcResponse {
+    match TimerEngine::start_with_pre_hooks(engine, &params).await {
+        Ok(()) => IpcResponse::success(
+            "タイマーを開始しました",
+            Some(state_data(&*engine.lock().await)),
+        ),
+        Err(e) => error_response(e),
     }
 }
 
@@ -198,7 +782,7 @@ fn handle_start(engine: &mut TimerEngine, params: StartParams) -> IpcResponse {
 fn handle_pause(engine: &mut TimerEngine) -> IpcResponse {
     match engine.pause() {
         Ok(()) => IpcResponse::success("タイマーを一時停止しました", None),
-        Err(e) => IpcResponse::error(e.to_string()),
+        Err(e) => error_response(e),
     }
 }
 
@@ -206,7 +790,7 @@ fn handle_pause(engine: &mut TimerEngine) -> IpcResponse {
 fn handle_resume(engine: &mut TimerEngine) -> IpcResponse {
     match engine.resume() {
         Ok(()) => IpcResponse::success("タイマーを再開しました", None),
-        Err(e) => IpcResponse::error(e.to_string()),
+        Err(e) => error_response(e),
     }
 }
 
@@ -214,23 +798,85 @@ fn handle_resume(engine: &mut TimerEngine) -> IpcResponse {
 fn handle_stop(engine: &mut TimerEngine) -> IpcResponse {
     match engine.stop() {
         Ok(()) => IpcResponse::success("タイマーを停止しました", None),
-        Err(e) => IpcResponse::error(e.to_string()),
+        Err(e) => error_response(e),
+    }
+}
+
+/// skipコマンドを処理
+async fn handle_skip(engine: &Mutex<TimerEngine>) -> IpcResponse {
+    match TimerEngine::skip_with_pre_hooks(engine).await {
+        Ok(()) => IpcResponse::success(
+            "フェーズをスキップしました",
+            Some(state_data(&*engine.lock().await)),
+        ),
+        Err(e) => error_response(e),
     }
 }
 
 /// statusコマンドを処理
 fn handle_status(engine: &TimerEngine) -> IpcResponse {
+    IpcResponse::success("", Some(status_data(engine)))
+}
+
+/// helloコマンドを処理
+///
+/// クライアントのプロトコルバージョンがデーモンより新しい場合はエラーを返す。
+fn handle_hello(protocol_version: Option<u32>) -> IpcResponse {
+    if let Some(version) = protocol_version {
+        if version == 0 || version > PROTOCOL_VERSION {
+            return IpcResponse::error(
+                ErrorCode::UnsupportedProtocolVersion,
+                format!(
+                    "プロトコルバージョン {} には対応していません（デーモン: {}）",
+                    version, PROTOCOL_VERSION
+                ),
+            );
+        }
+    }
+
+    let data = ResponseData {
+        version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        protocol_version: Some(PROTOCOL_VERSION),
+        capabilities: Some(CAPABILITIES.iter().map(|c| c.to_string()).collect()),
+        ..Default::default()
+    };
+    IpcResponse::success("", Some(data))
+}
+
+/// エンジンのエラーをエラーレスポンスに変換
+///
+/// エラーが `ErrorCode` の場合はそのコードを、それ以外は `ErrorCode::Internal` を使う。
+fn error_response(error: anyhow::Error) -> IpcResponse {
+    let code = error
+        .downcast_ref::<ErrorCode>()
+        .copied()
+        .unwrap_or(ErrorCode::Internal);
+    IpcResponse::error(code, error.to_string())
+}
+
+/// 現在の状態をレスポンスデータに変換
+fn state_data(engine: &TimerEngine) -> ResponseData {
     let state = engine.get_state();
-    IpcResponse::success(
-        "",
-        Some(ResponseData {
-            state: Some(state.phase.as_str().to_string()),
-            remaining_seconds: Some(state.remaining_seconds),
-            pomodoro_count: Some(state.pomodoro_count),
-            task_name: state.task_name.clone(),
-            duration: Some(state.current_duration()),
-        }),
-    )
+    ResponseData {
+        state: Some(state.phase.as_str().to_string()),
+        remaining_seconds: Some(state.remaining_seconds),
+        pomodoro_count: Some(state.pomodoro_count),
+        task_name: state.task_name.clone(),
+        duration: Some(state.current_duration()),
+        ..Default::default()
+    }
+}
+
+/// 現在の状態と、再実行しても失敗したままのフックをレスポンスデータに変換
+fn status_data(engine: &TimerEngine) -> ResponseData {
+    let hook_failures = engine
+        .hook_executor()
+        .map(|executor| executor.hook_failures())
+        .filter(|failures| !failures.is_empty());
+    ResponseData {
+        hook_failures,
+        ..state_data(engine)
+    }
 }
 
 // ============================================================================
@@ -241,9 +887,10 @@ fn handle_status(engine: &TimerEngine) -> IpcResponse {
 mod tests {
     use super::*;
     use crate::daemon::TimerEvent;
-    use crate::types::PomodoroConfig;
+    use crate::types::{HookEvent, PomodoroConfig};
     use std::path::PathBuf;
     use tempfile::tempdir;
+    use tokio::io::AsyncWriteExt;
     use tokio::sync::mpsc;
 
     // ------------------------------------------------------------------------
@@ -263,6 +910,14 @@ mod tests {
         dir.keep().join("test.sock")
     }
 
+    /// デフォルトタイマーのみのレジストリ
+    fn single_registry(engine: Arc<Mutex<TimerEngine>>) -> Arc<TimerRegistry> {
+        let (events, _) = broadcast::channel(16);
+        Arc::new(TimerRegistry::single(crate::daemon::TimerHandle::new(
+            engine, events,
+        )))
+    }
+
     fn create_test_engine() -> TestEngine {
         let (tx, rx) = mpsc::unbounded_channel();
         let config = PomodoroConfig::default();
@@ -304,6 +959,22 @@ mod tests {
         assert!(server2.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_ipc_server_new_refuses_live_socket() {
+        let socket_path = create_test_socket_path();
+        let _server1 = IpcServer::new(&socket_path).unwrap();
+
+        // 起動中のサーバーのソケットは削除しない
+        let result = IpcServer::new(&socket_path);
+        assert!(result.is_err());
+        assert!(result
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("already listening"));
+        assert!(UnixStream::connect(&socket_path).await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_ipc_server_new_creates_parent_directory() {
         let dir = tempdir().unwrap();
@@ -314,6 +985,74 @@ mod tests {
         assert!(socket_path.parent().unwrap().exists());
     }
 
+    #[tokio::test]
+    async fn test_ipc_server_new_creates_private_directory() {
+        let dir = tempdir().unwrap();
+        let socket_path = dir.path().join("nested").join("test.sock");
+
+        let _server = IpcServer::new(&socket_path).unwrap();
+
+        let dir_mode = std::fs::metadata(socket_path.parent().unwrap())
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(dir_mode & 0o777, 0o700);
+    }
+
+    #[tokio::test]
+    async fn test_ipc_server_socket_is_owner_only() {
+        let socket_path = create_test_socket_path();
+        let _server = IpcServer::new(&socket_path).unwrap();
+
+        let mode = std::fs::metadata(&socket_path)
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[tokio::test]
+    async fn test_ipc_server_binds_via_private_staging_directory() {
+        let dir = tempdir().unwrap();
+        let socket_path = dir.path().join("test.sock");
+
+        // 同じPIDの残骸があっても起動できる
+        let stale = dir.path().join(format!(".bind-{}", std::process::id()));
+        std::fs::create_dir(&stale).unwrap();
+        std::fs::write(stale.join("test.sock"), "dummy").unwrap();
+
+        let _server = IpcServer::new(&socket_path).unwrap();
+
+        // 一時ディレクトリは残らず、移動後のソケットで接続できる
+        let entries: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(entries, vec![std::ffi::OsString::from("test.sock")]);
+        assert!(UnixStream::connect(&socket_path).await.is_ok());
+    }
+
+    #[test]
+    fn test_is_insecure_dir() {
+        let dir = tempdir().unwrap();
+
+        std::fs::set_permissions(dir.path(), std::fs::Permissions::from_mode(0o700)).unwrap();
+        assert!(!is_insecure_dir(dir.path()).unwrap());
+
+        std::fs::set_permissions(dir.path(), std::fs::Permissions::from_mode(0o775)).unwrap();
+        assert!(is_insecure_dir(dir.path()).unwrap());
+
+        std::fs::set_permissions(dir.path(), std::fs::Permissions::from_mode(0o707)).unwrap();
+        assert!(is_insecure_dir(dir.path()).unwrap());
+    }
+
+    #[test]
+    fn test_is_same_user() {
+        let uid = unsafe { libc::getuid() };
+        assert!(is_same_user(uid));
+        assert!(!is_same_user(uid.wrapping_add(1)));
+    }
+
     #[tokio::test]
     async fn test_ipc_server_socket_path() {
         let socket_path = create_test_socket_path();
@@ -352,8 +1091,8 @@ mod tests {
         });
 
         // Accept connection
-        let stream = server.accept().await;
-        assert!(stream.is_ok());
+        let connection = server.accept().await;
+        assert!(connection.is_ok());
 
         let client_result = client_handle.await.unwrap();
         assert!(client_result.is_ok());
@@ -372,13 +1111,13 @@ mod tests {
         let client_handle = tokio::spawn(async move {
             tokio::time::sleep(Duration::from_millis(50)).await;
             let mut stream = UnixStream::connect(&client_path).await.unwrap();
-            let request = r#"{"command":"status"}"#;
+            let request = "{\"command\":\"status\"}\n";
             stream.write_all(request.as_bytes()).await.unwrap();
             stream
         });
 
-        let mut stream = server.accept().await.unwrap();
-        let request = IpcServer::receive_request(&mut stream).await;
+        let mut connection = server.accept().await.unwrap();
+        let request = connection.receive_request().await;
 
         assert!(request.is_ok());
         assert!(matches!(request.unwrap(), IpcRequest::Status));
@@ -395,13 +1134,13 @@ mod tests {
         let client_handle = tokio::spawn(async move {
             tokio::time::sleep(Duration::from_millis(50)).await;
             let mut stream = UnixStream::connect(&client_path).await.unwrap();
-            let request = r#"{"command":"start","taskName":"テスト"}"#;
+            let request = "{\"command\":\"start\",\"taskName\":\"テスト\"}\n";
             stream.write_all(request.as_bytes()).await.unwrap();
             stream
         });
 
-        let mut stream = server.accept().await.unwrap();
-        let request = IpcServer::receive_request(&mut stream).await.unwrap();
+        let mut connection = server.accept().await.unwrap();
+        let request = connection.receive_request().await.unwrap();
 
         if let IpcRequest::Start { params } = request {
             assert_eq!(params.task_name, Some("テスト".to_string()));
@@ -425,8 +1164,8 @@ mod tests {
             drop(stream);
         });
 
-        let mut stream = server.accept().await.unwrap();
-        let result = IpcServer::receive_request(&mut stream).await;
+        let mut connection = server.accept().await.unwrap();
+        let result = connection.receive_request().await;
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("closed"));
@@ -449,9 +1188,9 @@ mod tests {
             String::from_utf8(buffer[..n].to_vec()).unwrap()
         });
 
-        let mut stream = server.accept().await.unwrap();
+        let mut connection = server.accept().await.unwrap();
         let response = IpcResponse::success("OK", None);
-        let result = IpcServer::send_response(&mut stream, &response).await;
+        let result = connection.send_response(&response).await;
 
         assert!(result.is_ok());
 
@@ -476,6 +1215,40 @@ mod tests {
         assert_eq!(data.state, Some("stopped".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_handle_request_status_includes_hook_failures() {
+        let config = crate::hooks::HookConfig::parse_and_validate(
+            r#"{"version": "1.0", "hooks": [{"name": "notify", "event": "stop", "shell": "exit 1"}]}"#,
+        )
+        .unwrap();
+        let executor = Arc::new(crate::hooks::HookExecutor::with_config(config));
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let engine = Arc::new(Mutex::new(TimerEngine::new_with_hook_executor(
+            PomodoroConfig::default(),
+            tx,
+            executor.clone(),
+        )));
+
+        let response = handle_request(IpcRequest::Status, engine.clone()).await;
+        assert!(response.data.unwrap().hook_failures.is_none());
+
+        handle_request(
+            IpcRequest::Start {
+                params: StartParams::default(),
+            },
+            engine.clone(),
+        )
+        .await;
+        handle_request(IpcRequest::Stop, engine.clone()).await;
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        let response = handle_request(IpcRequest::Status, engine).await;
+        let failures = response.data.unwrap().hook_failures.unwrap();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].hook, "notify");
+        assert_eq!(failures[0].event, HookEvent::Stop);
+    }
+
     #[tokio::test]
     async fn test_handle_request_start() {
         let test_engine = create_test_engine();
@@ -515,6 +1288,7 @@ mod tests {
         let response = handle_request(request, engine).await;
 
         assert_eq!(response.status, "error");
+        assert_eq!(response.code, Some(ErrorCode::AlreadyRunning));
         assert!(response.message.contains("既に実行中"));
     }
 
@@ -543,6 +1317,7 @@ mod tests {
         let response = handle_request(IpcRequest::Pause, test_engine.engine).await;
 
         assert_eq!(response.status, "error");
+        assert_eq!(response.code, Some(ErrorCode::NotRunning));
         assert!(response.message.contains("実行されていません"));
     }
 
@@ -579,6 +1354,7 @@ mod tests {
         let response = handle_request(IpcRequest::Resume, engine).await;
 
         assert_eq!(response.status, "error");
+        assert_eq!(response.code, Some(ErrorCode::NotPaused));
         assert!(response.message.contains("一時停止していません"));
     }
 
@@ -607,9 +1383,723 @@ mod tests {
         let response = handle_request(IpcRequest::Stop, test_engine.engine).await;
 
         assert_eq!(response.status, "error");
+        assert_eq!(response.code, Some(ErrorCode::NotRunning));
         assert!(response.message.contains("実行されていません"));
     }
 
+    #[tokio::test]
+    async fn test_handle_request_skip() {
+        let test_engine = create_test_engine();
+        let engine = test_engine.engine;
+
+        let start_request = IpcRequest::Start {
+            params: StartParams::default(),
+        };
+        handle_request(start_request, engine.clone()).await;
+
+        let response = handle_request(IpcRequest::Skip, engine).await;
+
+        assert_eq!(response.status, "success");
+        assert_eq!(response.message, "フェーズをスキップしました");
+        assert_eq!(response.data.unwrap().state, Some("breaking".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_handle_request_skip_not_running() {
+        let test_engine = create_test_engine();
+
+        let response = handle_request(IpcRequest::Skip, test_engine.engine).await;
+
+        assert_eq!(response.status, "error");
+        assert_eq!(response.code, Some(ErrorCode::NotRunning));
+    }
+
+    #[tokio::test]
+    async fn test_handle_request_subscribe_is_rejected() {
+        let test_engine = create_test_engine();
+
+        let response = handle_request(IpcRequest::Subscribe, test_engine.engine).await;
+
+        assert_eq!(response.status, "error");
+        assert_eq!(response.code, Some(ErrorCode::InvalidRequest));
+    }
+
+    #[tokio::test]
+    async fn test_handle_request_hello() {
+        let test_engine = create_test_engine();
+
+        let request = IpcRequest::Hello {
+            protocol_version: Some(PROTOCOL_VERSION),
+        };
+        let response = handle_request(request, test_engine.engine).await;
+
+        assert_eq!(response.status, "success");
+        let data = response.data.unwrap();
+        assert_eq!(data.version.as_deref(), Some(env!("CARGO_PKG_VERSION")));
+        assert_eq!(data.protocol_version, Some(PROTOCOL_VERSION));
+        assert!(data
+            .capabilities
+            .unwrap()
+            .contains(&"subscribe".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_handle_request_hello_unsupported_version() {
+        let test_engine = create_test_engine();
+
+        let request = IpcRequest::Hello {
+            protocol_version: Some(PROTOCOL_VERSION + 1),
+        };
+        let response = handle_request(request, test_engine.engine).await;
+
+        assert_eq!(response.status, "error");
+        assert_eq!(response.code, Some(ErrorCode::UnsupportedProtocolVersion));
+    }
+
+    #[test]
+    fn test_error_response_internal_for_untyped_error() {
+        let response = error_response(anyhow::anyhow!("unexpected"));
+
+        assert_eq!(response.code, Some(ErrorCode::Internal));
+        assert_eq!(response.message, "unexpected");
+    }
+
+    // ------------------------------------------------------------------------
+    // Event Stream Tests
+    // ------------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn test_stream_events_sends_snapshot_and_events() {
+        use tokio::io::{AsyncBufReadExt, BufReader};
+
+        let socket_path = create_test_socket_path();
+        let server = IpcServer::new(&socket_path).unwrap();
+        let test_engine = create_test_engine();
+        let (events_tx, _) = broadcast::channel(16);
+
+        let client_path = socket_path.clone();
+        let client_handle = tokio::spawn(async move {
+            let stream = UnixStream::connect(&client_path).await.unwrap();
+            let mut lines = BufReader::new(stream).lines();
+            let mut messages = Vec::new();
+            for _ in 0..3 {
+                let line = lines.next_line().await.unwrap().unwrap();
+                messages.push(serde_json::from_str::<StreamMessage>(&line).unwrap());
+            }
+            messages
+        });
+
+        let mut connection = server.accept().await.unwrap();
+        let engine = test_engine.engine.clone();
+        let events_rx = events_tx.subscribe();
+        let server_handle =
+            tokio::spawn(async move { connection.stream_events(engine, events_rx).await });
+
+        // 初回スナップショットの送信を待ってからイベントを発行
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        events_tx.send(TimerEvent::Paused).unwrap();
+
+        let messages = client_handle.await.unwrap();
+        assert!(matches!(&messages[0], StreamMessage::Snapshot { data }
+            if data.state.as_deref() == Some("stopped")));
+        assert!(matches!(
+            &messages[1],
+            StreamMessage::Event {
+                event: TimerEvent::Paused
+            }
+        ));
+        assert!(matches!(&messages[2], StreamMessage::Snapshot { .. }));
+
+        // クライアント切断でストリームが終了する
+        let result = tokio::time::timeout(Duration::from_secs(1), server_handle).await;
+        assert!(result.unwrap().unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stream_events_tick_has_no_snapshot() {
+        use tokio::io::{AsyncBufReadExt, BufReader};
+
+        let socket_path = create_test_socket_path();
+        let server = IpcServer::new(&socket_path).unwrap();
+        let test_engine = create_test_engine();
+        let (events_tx, _) = broadcast::channel(16);
+
+        let client_path = socket_path.clone();
+        let client_handle = tokio::spawn(async move {
+            let stream = UnixStream::connect(&client_path).await.unwrap();
+            let mut lines = BufReader::new(stream).lines();
+            let mut messages = Vec::new();
+            for _ in 0..3 {
+                let line = lines.next_line().await.unwrap().unwrap();
+                messages.push(serde_json::from_str::<StreamMessage>(&line).unwrap());
+            }
+            messages
+        });
+
+        let mut connection = server.accept().await.unwrap();
+        let engine = test_engine.engine.clone();
+        let events_rx = events_tx.subscribe();
+        tokio::spawn(async move { connection.stream_events(engine, events_rx).await });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        events_tx
+            .send(TimerEvent::Tick {
+                remaining_seconds: 10,
+            })
+            .unwrap();
+        events_tx.send(TimerEvent::Stopped).unwrap();
+
+        let messages = client_handle.await.unwrap();
+        assert!(matches!(&messages[0], StreamMessage::Snapshot { .. }));
+        assert!(matches!(
+            &messages[1],
+            StreamMessage::Event {
+                event: TimerEvent::Tick {
+                    remaining_seconds: 10
+                }
+            }
+        ));
+        assert!(matches!(
+            &messages[2],
+            StreamMessage::Event {
+                event: TimerEvent::Stopped
+            }
+        ));
+    }
+
+    // ------------------------------------------------------------------------
+    // Connection Serving Tests
+    // ------------------------------------------------------------------------
+
+    /// 接続を処理するサーバータスクを起動し、クライアント側の行リーダーとライターを返す
+    async fn spawn_serving_connection(
+        server: IpcServer,
+        engine: Arc<Mutex<TimerEngine>>,
+    ) -> (
+        tokio::io::Lines<BufReader<OwnedReadHalf>>,
+        OwnedWriteHalf,
+        tokio::task::JoinHandle<Result<()>>,
+    ) {
+        use tokio::io::AsyncBufReadExt;
+
+        let client_path = server.socket_path().to_path_buf();
+        let client = UnixStream::connect(&client_path).await.unwrap();
+        let connection = server.accept().await.unwrap();
+        let server_handle = tokio::spawn(async move {
+            let _server = server;
+            connection.serve(single_registry(engine)).await
+        });
+
+        let (reader, writer) = client.into_split();
+        (BufReader::new(reader).lines(), writer, server_handle)
+    }
+
+    #[tokio::test]
+    async fn test_serve_multiple_requests_per_connection() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+        let test_engine = create_test_engine();
+        let (mut lines, mut writer, server_handle) =
+            spawn_serving_connection(server, test_engine.engine.clone()).await;
+
+        // 2つのリクエストを1回の書き込みで送信しても個別に処理される
+        writer
+            .write_all(
+                "{\"command\":\"start\",\"taskName\":\"タスク\"}\n{\"command\":\"status\"}\n"
+                    .as_bytes(),
+            )
+            .await
+            .unwrap();
+
+        let first: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        let second: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+
+        assert_eq!(first.message, "タイマーを開始しました");
+        assert_eq!(second.status, "success");
+        assert_eq!(second.data.unwrap().task_name, Some("タスク".to_string()));
+
+        // クライアントが閉じると処理が終了する
+        drop(writer);
+        let result = tokio::time::timeout(Duration::from_secs(1), server_handle).await;
+        assert!(result.unwrap().unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_serve_invalid_json_keeps_connection() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+        let test_engine = create_test_engine();
+        let (mut lines, mut writer, _server_handle) =
+            spawn_serving_connection(server, test_engine.engine.clone()).await;
+
+        writer
+            .write_all(b"not json\n{\"command\":\"status\"}\n")
+            .await
+            .unwrap();
+
+        let first: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        let second: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+
+        assert_eq!(first.status, "error");
+        assert_eq!(first.code, Some(ErrorCode::InvalidRequest));
+        assert!(first.message.contains("解析に失敗"));
+        assert_eq!(second.status, "success");
+    }
+
+    #[tokio::test]
+    async fn test_serve_long_task_name() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+        let test_engine = create_test_engine();
+        let (mut lines, mut writer, _server_handle) =
+            spawn_serving_connection(server, test_engine.engine.clone()).await;
+
+        // 旧実装の4096バイトバッファを超えるリクエスト
+        let task_name = "長".repeat(2000);
+        let request = serde_json::to_vec(&IpcRequest::Start {
+            params: StartParams {
+                task_name: Some(task_name.clone()),
+                ..Default::default()
+            },
+        })
+        .unwrap();
+        assert!(request.len() > 4096);
+        writer.write_all(&request).await.unwrap();
+        writer.write_all(b"\n").await.unwrap();
+
+        let response: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response.status, "success");
+        assert_eq!(response.data.unwrap().task_name, Some(task_name));
+    }
+
+    #[tokio::test]
+    async fn test_serve_oversize_message_closes_connection() {
+        let server = IpcServer::new(&create_test_socket_path())
+            .unwrap()
+            .with_max_message_size(64);
+        assert_eq!(server.max_message_size(), 64);
+        let test_engine = create_test_engine();
+        let (mut lines, mut writer, server_handle) =
+            spawn_serving_connection(server, test_engine.engine.clone()).await;
+
+        let oversize = format!("{{\"command\":\"{}\"}}\n", "x".repeat(100));
+        writer.write_all(oversize.as_bytes()).await.unwrap();
+
+        let response: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response.status, "error");
+        assert_eq!(response.code, Some(ErrorCode::MessageTooLarge));
+        assert!(response.message.contains("上限（64バイト）"));
+
+        // サーバー側は接続を閉じる
+        assert!(lines.next_line().await.unwrap().is_none());
+        let result = tokio::time::timeout(Duration::from_secs(1), server_handle).await;
+        assert!(result.unwrap().unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_response_oversize_sends_error_instead() {
+        use tokio::io::AsyncBufReadExt;
+
+        let server = IpcServer::new(&create_test_socket_path())
+            .unwrap()
+            .with_max_message_size(200);
+        let client = UnixStream::connect(server.socket_path()).await.unwrap();
+        let mut connection = server.accept().await.unwrap();
+
+        let data = ResponseData {
+            task_name: Some("x".repeat(500)),
+            ..Default::default()
+        };
+        connection
+            .send_response(&IpcResponse::success("", Some(data)))
+            .await
+            .unwrap();
+
+        let mut lines = BufReader::new(client).lines();
+        let response: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response.status, "error");
+        assert!(response.message.contains("レスポンスを送信できません"));
+    }
+
+    #[tokio::test]
+    async fn test_serve_subscribe_switches_to_stream() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+        let test_engine = create_test_engine();
+        let (mut lines, mut writer, _server_handle) =
+            spawn_serving_connection(server, test_engine.engine.clone()).await;
+
+        writer
+            .write_all(b"{\"command\":\"subscribe\"}\n")
+            .await
+            .unwrap();
+
+        let message: StreamMessage =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert!(matches!(message, StreamMessage::Snapshot { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_serve_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let hooks_path = dir.path().join("hooks.json");
+        let reloader = ConfigReloader::new(
+            Some(Arc::new(crate::hooks::HookExecutor::with_config(
+                Default::default(),
+            ))),
+            Default::default(),
+        )
+        .with_paths(hooks_path.clone(), dir.path().join("sound-config.json"));
+        let server = IpcServer::new(&create_test_socket_path())
+            .unwrap()
+            .with_reloader(Arc::new(reloader));
+        let test_engine = create_test_engine();
+
+        let client = UnixStream::connect(server.socket_path()).await.unwrap();
+        let connection = server.accept().await.unwrap();
+        let timers = single_registry(test_engine.engine.clone());
+        tokio::spawn(async move { connection.serve(timers).await });
+
+        let (reader, mut writer) = client.into_split();
+        let mut lines = tokio::io::AsyncBufReadExt::lines(BufReader::new(reader));
+
+        writer
+            .write_all(b"{\"command\":\"reload\"}\n")
+            .await
+            .unwrap();
+        let response: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert!(response.is_success());
+        assert_eq!(response.message, "設定を再読み込みしました（フック: 0件）");
+
+        // 不正な設定はエラーを返し、同じ接続で次のリクエストを受け付ける
+        std::fs::write(&hooks_path, "{ invalid").unwrap();
+        writer
+            .write_all(b"{\"command\":\"reload\"}\n")
+            .await
+            .unwrap();
+        let response: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response.code, Some(ErrorCode::InvalidConfig));
+
+        writer
+            .write_all(b"{\"command\":\"status\"}\n")
+            .await
+            .unwrap();
+        let response: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert!(response.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_serve_reload_without_reloader() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+        let test_engine = create_test_engine();
+
+        let client = UnixStream::connect(server.socket_path()).await.unwrap();
+        let connection = server.accept().await.unwrap();
+        let timers = single_registry(test_engine.engine.clone());
+        tokio::spawn(async move { connection.serve(timers).await });
+
+        let (reader, mut writer) = client.into_split();
+        let mut lines = tokio::io::AsyncBufReadExt::lines(BufReader::new(reader));
+        writer
+            .write_all(b"{\"command\":\"reload\"}\n")
+            .await
+            .unwrap();
+
+        let response: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response.code, Some(ErrorCode::InvalidRequest));
+    }
+
+    #[tokio::test]
+    async fn test_serve_shutdown_notifies_server() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+        let test_engine = create_test_engine();
+
+        let client = UnixStream::connect(server.socket_path()).await.unwrap();
+        let connection = server.accept().await.unwrap();
+        let timers = single_registry(test_engine.engine.clone());
+        let serve_handle = tokio::spawn(async move { connection.serve(timers).await });
+
+        let (reader, mut writer) = client.into_split();
+        let mut lines = tokio::io::AsyncBufReadExt::lines(BufReader::new(reader));
+        writer
+            .write_all(b"{\"command\":\"shutdown\"}\n")
+            .await
+            .unwrap();
+
+        let response: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response.message, "デーモンを終了します");
+
+        // サーバーに終了要求が届き、接続は閉じられる
+        tokio::time::timeout(Duration::from_secs(1), server.shutdown_requested())
+            .await
+            .unwrap();
+        assert!(serve_handle.await.unwrap().is_ok());
+        assert!(lines.next_line().await.unwrap().is_none());
+    }
+
+    // ------------------------------------------------------------------------
+    // Named Timer Tests
+    // ------------------------------------------------------------------------
+
+    fn timer_request(timer: Option<&str>, request: IpcRequest) -> TimerRequest {
+        TimerRequest {
+            timer: timer.map(str::to_string),
+            request,
+        }
+    }
+
+    fn create_registry() -> TimerRegistry {
+        let executor = Arc::new(crate::hooks::HookExecutor::with_config(Default::default()));
+        TimerRegistry::new(PomodoroConfig::default(), Some(executor)).0
+    }
+
+    #[tokio::test]
+    async fn test_handle_timer_request_start_creates_named_timer() {
+        let timers = create_registry();
+        let params = StartParams {
+            work_minutes: Some(10),
+            ..Default::default()
+        };
+
+        let response = handle_timer_request(
+            timer_request(Some("prep"), IpcRequest::Start { params }),
+            &timers,
+        )
+        .await;
+
+        assert!(response.is_success());
+        assert_eq!(response.data.unwrap().remaining_seconds, Some(600));
+        let prep = timers.get("prep").unwrap();
+        assert!(prep.engine().lock().await.get_state().is_running());
+        // デフォルトタイマーには影響しない
+        let default = timers.default_timer();
+        assert!(!default.engine().lock().await.get_state().is_running());
+    }
+
+    #[tokio::test]
+    async fn test_handle_timer_request_missing_timer() {
+        let timers = create_registry();
+
+        let response =
+            handle_timer_request(timer_request(Some("prep"), IpcRequest::Pause), &timers).await;
+
+        assert_eq!(response.code, Some(ErrorCode::NotFound));
+        assert!(timers.get("prep").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_handle_timer_request_invalid_name() {
+        let timers = create_registry();
+        let request = IpcRequest::Start {
+            params: StartParams::default(),
+        };
+
+        let response =
+            handle_timer_request(timer_request(Some("bad name"), request), &timers).await;
+
+        assert_eq!(response.code, Some(ErrorCode::InvalidRequest));
+    }
+
+    #[tokio::test]
+    async fn test_handle_timer_request_stop_removes_named_timer() {
+        let timers = create_registry();
+        let start = || IpcRequest::Start {
+            params: StartParams::default(),
+        };
+        handle_timer_request(timer_request(Some("prep"), start()), &timers).await;
+        handle_timer_request(timer_request(None, start()), &timers).await;
+
+        let response =
+            handle_timer_request(timer_request(Some("prep"), IpcRequest::Stop), &timers).await;
+        assert!(response.is_success());
+        assert!(timers.get("prep").is_none());
+
+        // デフォルトタイマーは停止しても残る
+        let response = handle_timer_request(timer_request(None, IpcRequest::Stop), &timers).await;
+        assert!(response.is_success());
+        assert!(timers.get(DEFAULT_TIMER_NAME).is_some());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_handle_timer_request_rejected_starts_do_not_fill_registry() {
+        let config = crate::hooks::HookConfig::parse_and_validate(
+            r#"{"version": "1.0", "hooks": [{"name": "guard", "event": "before_work_start", "shell": "echo no >&2; exit 1"}]}"#,
+        )
+        .unwrap();
+        let executor = Arc::new(crate::hooks::HookExecutor::with_config(config));
+        let (timers, _rx) = TimerRegistry::new(PomodoroConfig::default(), Some(executor));
+
+        for i in 0..crate::daemon::registry::MAX_TIMERS {
+            let request = IpcRequest::Start {
+                params: StartParams::default(),
+            };
+            let response =
+                handle_timer_request(timer_request(Some(&format!("t{}", i)), request), &timers)
+                    .await;
+            assert_eq!(response.code, Some(ErrorCode::HookRejected));
+        }
+
+        // 拒否されたタイマーは残らず、デフォルトタイマーだけが存在する
+        let names: Vec<String> = timers.timers().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec![DEFAULT_TIMER_NAME.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_handle_timer_request_list() {
+        let timers = create_registry();
+        let request = IpcRequest::Start {
+            params: StartParams {
+                task_name: Some("会議準備".to_string()),
+                ..Default::default()
+            },
+        };
+        handle_timer_request(timer_request(Some("prep"), request), &timers).await;
+
+        let response = handle_timer_request(timer_request(None, IpcRequest::List), &timers).await;
+
+        let list = response.data.unwrap().timers.unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].timer.as_deref(), Some("default"));
+        assert_eq!(list[0].state.as_deref(), Some("stopped"));
+        assert_eq!(list[1].timer.as_deref(), Some("prep"));
+        assert_eq!(list[1].state.as_deref(), Some("working"));
+        assert_eq!(list[1].task_name.as_deref(), Some("会議準備"));
+    }
+
+    #[tokio::test]
+    async fn test_serve_subscribe_missing_timer() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+        let test_engine = create_test_engine();
+
+        let client = UnixStream::connect(server.socket_path()).await.unwrap();
+        let connection = server.accept().await.unwrap();
+        let timers = single_registry(test_engine.engine.clone());
+        tokio::spawn(async move { connection.serve(timers).await });
+
+        let (reader, mut writer) = client.into_split();
+        let mut lines = tokio::io::AsyncBufReadExt::lines(BufReader::new(reader));
+        writer
+            .write_all(b"{\"command\":\"subscribe\",\"timer\":\"prep\"}\n")
+            .await
+            .unwrap();
+
+        let response: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response.code, Some(ErrorCode::NotFound));
+    }
+
+    #[tokio::test]
+    async fn test_handle_request_shutdown_is_rejected() {
+        let test_engine = create_test_engine();
+        let response = handle_request(IpcRequest::Shutdown, test_engine.engine).await;
+
+        assert_eq!(response.code, Some(ErrorCode::InvalidRequest));
+    }
+
+    #[tokio::test]
+    async fn test_request_shutdown_before_waiting() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+
+        server.request_shutdown();
+        tokio::time::timeout(Duration::from_secs(1), server.shutdown_requested())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_serve_jsonrpc_alongside_native() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+        let test_engine = create_test_engine();
+        let (mut lines, mut writer, _server_handle) =
+            spawn_serving_connection(server, test_engine.engine.clone()).await;
+
+        // 同じ接続でJSON-RPC・パースエラー・ネイティブのリクエストを混在させる
+        writer
+            .write_all(
+                b"{\"jsonrpc\":\"2.0\",\"method\":\"timer.start\",\"id\":1}\n\
+                  {\"jsonrpc\":\"2.0\",\n\
+                  {\"command\":\"status\"}\n",
+            )
+            .await
+            .unwrap();
+
+        let first: serde_json::Value =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        let second: serde_json::Value =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        let third: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+
+        assert_eq!(first["id"], 1);
+        assert_eq!(first["result"]["state"], "working");
+        assert_eq!(second["error"]["code"], jsonrpc::PARSE_ERROR);
+        assert_eq!(third.data.unwrap().state, Some("working".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_serve_records_request_metrics() {
+        let metrics = Arc::new(Metrics::new());
+        let server = IpcServer::new(&create_test_socket_path())
+            .unwrap()
+            .with_metrics(metrics.clone());
+        let test_engine = create_test_engine();
+        let (mut lines, mut writer, _server_handle) =
+            spawn_serving_connection(server, test_engine.engine.clone()).await;
+
+        writer
+            .write_all(
+                b"{\"command\":\"status\"}\n\
+                  {\"command\":\"status\"}\n\
+                  [{\"jsonrpc\":\"2.0\",\"method\":\"timer.status\",\"id\":1},\
+                   {\"jsonrpc\":\"2.0\",\"method\":\"timer.bogus\",\"id\":2}]\n",
+            )
+            .await
+            .unwrap();
+        for _ in 0..3 {
+            lines.next_line().await.unwrap().unwrap();
+        }
+
+        let output = metrics.render(&[]);
+        assert!(output.contains("pomodoro_ipc_requests_total{command=\"status\"} 2\n"));
+        assert!(output.contains("pomodoro_ipc_requests_total{command=\"timer.status\"} 1\n"));
+        assert!(output.contains("pomodoro_ipc_requests_total{command=\"unknown\"} 1\n"));
+        assert!(!output.contains("timer.bogus"));
+    }
+
+    #[tokio::test]
+    async fn test_serve_jsonrpc_subscribe_sends_notifications() {
+        let server = IpcServer::new(&create_test_socket_path()).unwrap();
+        let test_engine = create_test_engine();
+        let (mut lines, mut writer, _server_handle) =
+            spawn_serving_connection(server, test_engine.engine.clone()).await;
+
+        writer
+            .write_all(b"{\"jsonrpc\":\"2.0\",\"method\":\"timer.subscribe\",\"id\":\"s\"}\n")
+            .await
+            .unwrap();
+
+        let response: serde_json::Value =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response["id"], "s");
+        assert_eq!(response["result"]["subscribed"], true);
+
+        let notification: serde_json::Value =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(notification["jsonrpc"], "2.0");
+        assert_eq!(notification["method"], jsonrpc::SNAPSHOT_NOTIFICATION);
+        assert_eq!(notification["params"]["state"], "stopped");
+        assert!(notification.get("id").is_none());
+    }
+
     // ------------------------------------------------------------------------
     // Integration Tests
     // ------------------------------------------------------------------------
@@ -628,7 +2118,7 @@ mod tests {
             let mut stream = UnixStream::connect(&client_path).await.unwrap();
 
             // Send status request
-            let request = r#"{"command":"status"}"#;
+            let request = "{\"command\":\"status\"}\n";
             stream.write_all(request.as_bytes()).await.unwrap();
 
             // Read response
@@ -638,12 +2128,10 @@ mod tests {
         });
 
         // Server handling
-        let mut stream = server.accept().await.unwrap();
-        let request = IpcServer::receive_request(&mut stream).await.unwrap();
+        let mut connection = server.accept().await.unwrap();
+        let request = connection.receive_request().await.unwrap();
         let response = handle_request(request, test_engine.engine).await;
-        IpcServer::send_response(&mut stream, &response)
-            .await
-            .unwrap();
+        connection.send_response(&response).await.unwrap();
 
         // Verify client received response
         let client_response = client_handle.await.unwrap();
@@ -664,7 +2152,7 @@ mod tests {
             tokio::time::sleep(Duration::from_millis(50)).await;
             let mut stream = UnixStream::connect(&client_path).await.unwrap();
             stream
-                .write_all(r#"{"command":"start","taskName":"タスク1"}"#.as_bytes())
+                .write_all("{\"command\":\"start\",\"taskName\":\"タスク1\"}\n".as_bytes())
                 .await
                 .unwrap();
             let mut buffer = vec![0u8; 4096];
@@ -672,12 +2160,10 @@ mod tests {
             String::from_utf8(buffer[..n].to_vec()).unwrap()
         });
 
-        let mut stream1 = server.accept().await.unwrap();
-        let request1 = IpcServer::receive_request(&mut stream1).await.unwrap();
+        let mut connection1 = server.accept().await.unwrap();
+        let request1 = connection1.receive_request().await.unwrap();
         let response1 = handle_request(request1, engine.clone()).await;
-        IpcServer::send_response(&mut stream1, &response1)
-            .await
-            .unwrap();
+        connection1.send_response(&response1).await.unwrap();
 
         let result1 = client1.await.unwrap();
         assert!(result1.contains("\"working\""));
@@ -688,7 +2174,7 @@ mod tests {
             tokio::time::sleep(Duration::from_millis(50)).await;
             let mut stream = UnixStream::connect(&client_path).await.unwrap();
             stream
-                .write_all(r#"{"command":"status"}"#.as_bytes())
+                .write_all(b"{\"command\":\"status\"}\n")
                 .await
                 .unwrap();
             let mut buffer = vec![0u8; 4096];
@@ -696,12 +2182,10 @@ mod tests {
             String::from_utf8(buffer[..n].to_vec()).unwrap()
         });
 
-        let mut stream2 = server.accept().await.unwrap();
-        let request2 = IpcServer::receive_request(&mut stream2).await.unwrap();
+        let mut connection2 = server.accept().await.unwrap();
+        let request2 = connection2.receive_request().await.unwrap();
         let response2 = handle_request(request2, engine).await;
-        IpcServer::send_response(&mut stream2, &response2)
-            .await
-            .unwrap();
+        connection2.send_response(&response2).await.unwrap();
 
         let result2 = client2.await.unwrap();
         assert!(result2.contains("\"working\""));
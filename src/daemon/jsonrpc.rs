@@ -0,0 +1,805 @@
+//! JSON-RPC 2.0フロントエンド
+//!
+//! IPCソケット上で、`jsonrpc` フィールドを含むメッセージ（またはバッチ配列）を
+//! JSON-RPC 2.0として処理する。メソッドは `IpcRequest` に変換して
+//! `handle_timer_request` で実行するため、挙動はネイティブのIPCと一致する。
+//! `timer.*` メソッドでは params の `timer` で対象のタイマーを指定し、
+//! 省略時はデフォルトタイマーが対象になる。
+//!
+//! | メソッド | IpcRequest |
+//! |----------|------------|
+//! | `timer.start` | `Start`（paramsは `StartParams`、省略可） |
+//! | `timer.pause` / `timer.resume` / `timer.stop` / `timer.skip` | 各コマンド |
+//! | `timer.status` | `Status` |
+//! | `timer.subscribe` | `Subscribe`（paramsは `{"timer": "prep"}`、省略可。以降 `timer.event` / `timer.snapshot` 通知を送信） |
+//! | `daemon.hello` | `Hello`（paramsは `{"protocolVersion": 1}`、省略可） |
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::daemon::ipc::{handle_timer_request, registry_error_response};
+use crate::daemon::TimerRegistry;
+use crate::types::{
+    ErrorCode, IpcRequest, IpcResponse, ResponseData, StreamMessage, TimerRequest,
+    DEFAULT_TIMER_NAME,
+};
+
+/// JSON-RPCのバージョン
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// JSONのパースに失敗した
+pub const PARSE_ERROR: i32 = -32700;
+/// リクエストオブジェクトが不正
+pub const INVALID_REQUEST: i32 = -32600;
+/// メソッドが存在しない
+pub const METHOD_NOT_FOUND: i32 = -32601;
+/// パラメータが不正
+pub const INVALID_PARAMS: i32 = -32602;
+/// 内部エラー
+pub const INTERNAL_ERROR: i32 = -32603;
+
+/// 対応するメソッド名
+pub const METHODS: &[&str] = &[
+    "timer.start",
+    "timer.pause",
+    "timer.resume",
+    "timer.stop",
+    "timer.skip",
+    "timer.status",
+    "timer.subscribe",
+    "daemon.hello",
+];
+
+/// イベント通知のメソッド名
+pub const EVENT_NOTIFICATION: &str = "timer.event";
+/// スナップショット通知のメソッド名
+pub const SNAPSHOT_NOTIFICATION: &str = "timer.snapshot";
+
+/// JSON-RPCのエラーオブジェクト
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RpcError {
+    /// エラーコード
+    pub code: i32,
+    /// エラーメッセージ
+    pub message: String,
+    /// 追加情報（デーモンのエラーコード名など）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl RpcError {
+    /// エラーを作成
+    pub fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    /// デーモンのエラーコードからエラーを作成
+    ///
+    /// アプリケーション固有のエラーは -32000 番台に割り当て、
+    /// `data.code` にIPCと同じエラーコード名を格納する。
+    pub fn from_code(code: ErrorCode, message: impl Into<String>) -> Self {
+        let rpc_code = match code {
+            ErrorCode::InvalidRequest => INVALID_REQUEST,
+            ErrorCode::Internal | ErrorCode::Unknown => INTERNAL_ERROR,
+            ErrorCode::AlreadyRunning => -32001,
+            ErrorCode::NotRunning => -32002,
+            ErrorCode::NotPaused => -32003,
+            ErrorCode::UnsupportedProtocolVersion => -32004,
+            ErrorCode::MessageTooLarge => -32005,
+            ErrorCode::Unauthorized => -32006,
+            ErrorCode::NotFound => -32007,
+            ErrorCode::InvalidConfig => -32008,
+            ErrorCode::FollowingLeader => -32009,
+            ErrorCode::HookRejected => -32010,
+            ErrorCode::StateChanged => -32011,
+        };
+        Self {
+            code: rpc_code,
+            message: message.into(),
+            data: Some(json!({ "code": code.as_str() })),
+        }
+    }
+}
+
+/// 成功時の結果
+///
+/// IpcResponseのメッセージとデータを1つのオブジェクトにまとめる。
+#[derive(Debug, Clone, Serialize)]
+struct RpcResult {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    message: String,
+    #[serde(flatten)]
+    data: ResponseData,
+}
+
+/// JSON-RPCのレスポンス
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RpcResponse {
+    /// 常に "2.0"
+    pub jsonrpc: &'static str,
+    /// 成功時の結果
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// エラー時のエラー
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+    /// リクエストID（特定できない場合はnull）
+    pub id: Value,
+}
+
+impl RpcResponse {
+    /// 成功レスポンスを作成
+    pub fn success(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            result: Some(result),
+            error: None,
+            id,
+        }
+    }
+
+    /// エラーレスポンスを作成
+    pub fn error(id: Value, error: RpcError) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            result: None,
+            error: Some(error),
+            id,
+        }
+    }
+
+    /// IpcResponseから作成
+    pub fn from_ipc(id: Value, response: IpcResponse) -> Self {
+        if !response.is_success() {
+            let code = response.code.unwrap_or(ErrorCode::Internal);
+            return Self::error(id, RpcError::from_code(code, response.message));
+        }
+
+        let result = RpcResult {
+            message: response.message,
+            data: response.data.unwrap_or_default(),
+        };
+        match serde_json::to_value(result) {
+            Ok(result) => Self::success(id, result),
+            Err(e) => Self::error(id, RpcError::new(INTERNAL_ERROR, e.to_string())),
+        }
+    }
+}
+
+/// JSON-RPCの応答（単一またはバッチ）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum RpcReply {
+    /// 単一リクエストへの応答
+    Single(RpcResponse),
+    /// バッチリクエストへの応答
+    Batch(Vec<RpcResponse>),
+}
+
+/// JSON-RPCの通知（デーモン→クライアント）
+#[derive(Debug, Clone, Serialize)]
+pub struct RpcNotification<T> {
+    /// 常に "2.0"
+    pub jsonrpc: &'static str,
+    /// メソッド名
+    pub method: &'static str,
+    /// パラメータ
+    pub params: T,
+}
+
+/// ストリームメッセージをJSON-RPC通知に変換
+pub fn notification(message: StreamMessage) -> RpcNotification<Value> {
+    let (method, params) = match message {
+        StreamMessage::Event { event } => (EVENT_NOTIFICATION, serde_json::to_value(event)),
+        StreamMessage::Snapshot { data } => (SNAPSHOT_NOTIFICATION, serde_json::to_value(data)),
+    };
+    RpcNotification {
+        jsonrpc: JSONRPC_VERSION,
+        method,
+        params: params.unwrap_or(Value::Null),
+    }
+}
+
+/// メッセージの処理結果
+#[derive(Debug, Clone, PartialEq)]
+pub struct RpcOutcome {
+    /// 送信する応答（通知のみの場合はNone）
+    pub reply: Option<RpcReply>,
+    /// 購読するタイマーの名前（イベント購読に切り替えない場合はNone）
+    pub subscribe: Option<String>,
+}
+
+/// JSON-RPCのメッセージかどうかを判定
+///
+/// `jsonrpc` フィールドを持つオブジェクト、または配列（バッチ）をJSON-RPCとして扱う。
+/// ネイティブのIPCリクエストは常にオブジェクトのため、配列と衝突しない。
+pub fn is_jsonrpc(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => map.contains_key("jsonrpc"),
+        Value::Array(_) => true,
+        _ => false,
+    }
+}
+
+/// パースできなかったメッセージがJSON-RPCを意図したものかどうかを推定
+pub fn looks_like_jsonrpc(frame: &[u8]) -> bool {
+    let start = frame
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(frame.len());
+    frame[start..].starts_with(b"[")
+        || frame
+            .windows(b"\"jsonrpc\"".len())
+            .any(|w| w == b"\"jsonrpc\"")
+}
+
+/// パースエラーのレスポンスを作成
+pub fn parse_error(error: &serde_json::Error) -> RpcResponse {
+    RpcResponse::error(
+        Value::Null,
+        RpcError::new(
+            PARSE_ERROR,
+            format!("リクエストの解析に失敗しました: {}", error),
+        ),
+    )
+}
+
+/// JSON-RPCメッセージ（単一またはバッチ）を処理
+///
+/// バッチは配列の順に処理する。IDのない通知には応答しない。
+/// `timer.subscribe` は単一リクエストでのみ使用でき、成功すると
+/// `RpcOutcome::subscribe` に購読するタイマーの名前が入る。
+pub async fn handle_message(value: Value, timers: &TimerRegistry) -> RpcOutcome {
+    match value {
+        Value::Array(calls) => {
+            if calls.is_empty() {
+                return RpcOutcome {
+                    reply: Some(RpcReply::Single(RpcResponse::error(
+                        Value::Null,
+                        RpcError::new(INVALID_REQUEST, "バッチが空です"),
+                    ))),
+                    subscribe: None,
+                };
+            }
+
+            let mut responses = Vec::new();
+            for call in calls {
+                let response = match parse_call(call) {
+                    Ok(call) if call.method == "timer.subscribe" => call.id.map(|id| {
+                        RpcResponse::error(
+                            id,
+                            RpcError::new(
+                                INVALID_REQUEST,
+                                "timer.subscribeはバッチ内では使用できません",
+                            ),
+                        )
+                    }),
+                    Ok(call) => dispatch(call, timers).await,
+                    Err(response) => Some(*response),
+                };
+                responses.extend(response);
+            }
+
+            RpcOutcome {
+                reply: (!responses.is_empty()).then_some(RpcReply::Batch(responses)),
+                subscribe: None,
+            }
+        }
+        value => match parse_call(value) {
+            Ok(call) if call.method == "timer.subscribe" => subscribe(call, timers),
+            Ok(call) => RpcOutcome {
+                reply: dispatch(call, timers).await.map(RpcReply::Single),
+                subscribe: None,
+            },
+            Err(response) => RpcOutcome {
+                reply: Some(RpcReply::Single(*response)),
+                subscribe: None,
+            },
+        },
+    }
+}
+
+/// 検証済みのリクエスト
+#[derive(Debug)]
+struct RpcCall {
+    method: String,
+    params: Option<Value>,
+    /// リクエストID（Noneの場合は通知）
+    id: Option<Value>,
+}
+
+/// リクエストオブジェクトを検証
+fn parse_call(value: Value) -> Result<RpcCall, Box<RpcResponse>> {
+    let Value::Object(mut map) = value else {
+        return Err(invalid_request(
+            Value::Null,
+            "リクエストはオブジェクトである必要があります",
+        ));
+    };
+
+    let id = map.remove("id");
+    let error_id = id.clone().unwrap_or(Value::Null);
+    if !matches!(
+        id,
+        None | Some(Value::Null | Value::String(_) | Value::Number(_))
+    ) {
+        return Err(invalid_request(
+            Value::Null,
+            "idは文字列・数値・nullのいずれかです",
+        ));
+    }
+
+    if map.get("jsonrpc").and_then(Value::as_str) != Some(JSONRPC_VERSION) {
+        return Err(invalid_request(
+            error_id,
+            "jsonrpcは \"2.0\" である必要があります",
+        ));
+    }
+
+    let method = match map.remove("method") {
+        Some(Value::String(method)) => method,
+        _ => return Err(invalid_request(error_id, "methodが指定されていません")),
+    };
+
+    let params = map.remove("params");
+    if !matches!(params, None | Some(Value::Object(_) | Value::Array(_))) {
+        return Err(invalid_request(
+            error_id,
+            "paramsはオブジェクトまたは配列です",
+        ));
+    }
+
+    Ok(RpcCall { method, params, id })
+}
+
+fn invalid_request(id: Value, message: &str) -> Box<RpcResponse> {
+    Box::new(RpcResponse::error(
+        id,
+        RpcError::new(INVALID_REQUEST, message),
+    ))
+}
+
+/// `timer.subscribe` を処理（対象のタイマーが存在する場合のみ購読に切り替える）
+fn subscribe(mut call: RpcCall, timers: &TimerRegistry) -> RpcOutcome {
+    let name = match take_timer(&mut call.params) {
+        Ok(timer) => timer.unwrap_or_else(|| DEFAULT_TIMER_NAME.to_string()),
+        Err(error) => {
+            return RpcOutcome {
+                reply: call
+                    .id
+                    .map(|id| RpcReply::Single(RpcResponse::error(id, error))),
+                subscribe: None,
+            }
+        }
+    };
+    if let Err(e) = timers.find(&name) {
+        return RpcOutcome {
+            reply: call
+                .id
+                .map(|id| RpcReply::Single(RpcResponse::from_ipc(id, registry_error_response(e)))),
+            subscribe: None,
+        };
+    }
+
+    RpcOutcome {
+        reply: call
+            .id
+            .map(|id| RpcReply::Single(RpcResponse::success(id, json!({ "subscribed": true })))),
+        subscribe: Some(name),
+    }
+}
+
+/// メソッドを実行（通知の場合はNoneを返す）
+async fn dispatch(mut call: RpcCall, timers: &TimerRegistry) -> Option<RpcResponse> {
+    let request = take_timer(&mut call.params).and_then(|timer| {
+        Ok(TimerRequest {
+            timer,
+            request: to_ipc_request(&call.method, call.params)?,
+        })
+    });
+    let response = match request {
+        Ok(request) => Ok(handle_timer_request(request, timers).await),
+        Err(error) => Err(error),
+    };
+
+    let id = call.id?;
+    Some(match response {
+        Ok(response) => RpcResponse::from_ipc(id, response),
+        Err(error) => RpcResponse::error(id, error),
+    })
+}
+
+/// メソッドとパラメータをIpcRequestに変換
+fn to_ipc_request(method: &str, params: Option<Value>) -> Result<IpcRequest, RpcError> {
+    match method {
+        "timer.start" => Ok(IpcRequest::Start {
+            params: named_params(params)?,
+        }),
+        "timer.pause" => Ok(IpcRequest::Pause),
+        "timer.resume" => Ok(IpcRequest::Resume),
+        "timer.stop" => Ok(IpcRequest::Stop),
+        "timer.skip" => Ok(IpcRequest::Skip),
+        "timer.status" => Ok(IpcRequest::Status),
+        "daemon.hello" => {
+            let params: Map<String, Value> = named_params(params)?;
+            let protocol_version = match params.get("protocolVersion") {
+                None | Some(Value::Null) => None,
+                Some(value) => Some(
+                    value
+                        .as_u64()
+                        .and_then(|v| u32::try_from(v).ok())
+                        .ok_or_else(|| {
+                            RpcError::new(INVALID_PARAMS, "protocolVersionは整数です")
+                        })?,
+                ),
+            };
+            Ok(IpcRequest::Hello { protocol_version })
+        }
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("メソッドが見つかりません: {}", method),
+        )),
+    }
+}
+
+/// パラメータから対象のタイマー名（`timer`）を取り出す
+fn take_timer(params: &mut Option<Value>) -> Result<Option<String>, RpcError> {
+    let Some(Value::Object(map)) = params else {
+        return Ok(None);
+    };
+    match map.remove("timer") {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(timer)) => Ok(Some(timer)),
+        Some(_) => Err(RpcError::new(INVALID_PARAMS, "timerは文字列です")),
+    }
+}
+
+/// 名前付きパラメータ（オブジェクト）をデシリアライズ
+///
+/// パラメータの省略は空オブジェクトとして扱う。
+fn named_params<T: DeserializeOwned>(params: Option<Value>) -> Result<T, RpcError> {
+    let params = match params {
+        None => Value::Object(Map::new()),
+        Some(Value::Array(_)) => {
+            return Err(RpcError::new(
+                INVALID_PARAMS,
+                "paramsはオブジェクトで指定してください",
+            ))
+        }
+        Some(params) => params,
+    };
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::registry::NamedTimerEvent;
+    use crate::daemon::TimerEvent;
+    use crate::types::PomodoroConfig;
+    use tokio::sync::mpsc;
+
+    /// テスト用レジストリとイベントレシーバーのペア
+    struct TestTimers {
+        timers: TimerRegistry,
+        #[allow(dead_code)]
+        event_rx: mpsc::UnboundedReceiver<NamedTimerEvent>,
+    }
+
+    fn create_test_timers() -> TestTimers {
+        let (timers, rx) = TimerRegistry::new(PomodoroConfig::default(), None);
+        TestTimers {
+            timers,
+            event_rx: rx,
+        }
+    }
+
+    /// 単一リクエストを処理してレスポンスをJSONで返す
+    async fn call(timers: &TimerRegistry, request: Value) -> Value {
+        let outcome = handle_message(request, timers).await;
+        serde_json::to_value(outcome.reply.expect("reply")).unwrap()
+    }
+
+    #[test]
+    fn test_is_jsonrpc() {
+        assert!(is_jsonrpc(
+            &json!({"jsonrpc": "2.0", "method": "timer.status"})
+        ));
+        assert!(is_jsonrpc(&json!([])));
+        assert!(!is_jsonrpc(&json!({"command": "status"})));
+        assert!(!is_jsonrpc(&json!("status")));
+    }
+
+    #[test]
+    fn test_looks_like_jsonrpc() {
+        assert!(looks_like_jsonrpc(br#"{"jsonrpc": "2.0", "method": "#));
+        assert!(looks_like_jsonrpc(b"  [{"));
+        assert!(!looks_like_jsonrpc(br#"{"command": "#));
+    }
+
+    #[tokio::test]
+    async fn test_status() {
+        let test_timers = create_test_timers();
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "timer.status", "id": 1}),
+        )
+        .await;
+
+        assert_eq!(response["jsonrpc"], "2.0");
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["result"]["state"], "stopped");
+        assert!(response.get("error").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_start_with_params_and_string_id() {
+        let test_timers = create_test_timers();
+        let response = call(
+            &test_timers.timers,
+            json!({
+                "jsonrpc": "2.0",
+                "method": "timer.start",
+                "params": {"taskName": "RPC", "workMinutes": 30},
+                "id": "abc"
+            }),
+        )
+        .await;
+
+        assert_eq!(response["id"], "abc");
+        assert_eq!(response["result"]["message"], "タイマーを開始しました");
+        assert_eq!(response["result"]["taskName"], "RPC");
+        assert_eq!(response["result"]["remainingSeconds"], 30 * 60);
+    }
+
+    #[tokio::test]
+    async fn test_application_error_carries_code() {
+        let test_timers = create_test_timers();
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "timer.pause", "id": 7}),
+        )
+        .await;
+
+        assert_eq!(response["id"], 7);
+        assert_eq!(response["error"]["code"], -32002);
+        assert_eq!(response["error"]["data"]["code"], "not_running");
+        assert!(response.get("result").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_method_not_found() {
+        let test_timers = create_test_timers();
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "timer.unknown", "id": 1}),
+        )
+        .await;
+
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_params() {
+        let test_timers = create_test_timers();
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "timer.start", "params": [25], "id": 1}),
+        )
+        .await;
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "timer.start", "params": {"workMinutes": "x"}, "id": 2}),
+        )
+        .await;
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_request() {
+        let test_timers = create_test_timers();
+
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "1.0", "method": "timer.status", "id": 1}),
+        )
+        .await;
+        assert_eq!(response["error"]["code"], INVALID_REQUEST);
+        assert_eq!(response["id"], 1);
+
+        let response = call(&test_timers.timers, json!({"jsonrpc": "2.0", "id": 1})).await;
+        assert_eq!(response["error"]["code"], INVALID_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_notification_has_no_reply() {
+        let test_timers = create_test_timers();
+        let outcome = handle_message(
+            json!({"jsonrpc": "2.0", "method": "timer.start"}),
+            &test_timers.timers,
+        )
+        .await;
+
+        assert!(outcome.reply.is_none());
+        // 通知でもメソッドは実行される
+        assert!(test_timers
+            .timers
+            .default_timer()
+            .engine()
+            .lock()
+            .await
+            .get_state()
+            .is_running());
+    }
+
+    #[tokio::test]
+    async fn test_batch() {
+        let test_timers = create_test_timers();
+        let response = call(
+            &test_timers.timers,
+            json!([
+                {"jsonrpc": "2.0", "method": "timer.start", "id": 1},
+                {"jsonrpc": "2.0", "method": "timer.pause"},
+                {"jsonrpc": "2.0", "method": "timer.status", "id": 2},
+                {"foo": "bar"},
+                {"jsonrpc": "2.0", "method": "timer.subscribe", "id": 3}
+            ]),
+        )
+        .await;
+
+        let responses = response.as_array().unwrap();
+        assert_eq!(responses.len(), 4);
+        assert_eq!(responses[0]["id"], 1);
+        assert_eq!(responses[1]["id"], 2);
+        assert_eq!(responses[1]["result"]["state"], "paused");
+        assert_eq!(responses[2]["error"]["code"], INVALID_REQUEST);
+        assert_eq!(responses[3]["id"], 3);
+        assert_eq!(responses[3]["error"]["code"], INVALID_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_empty_batch_and_notification_only_batch() {
+        let test_timers = create_test_timers();
+
+        let response = call(&test_timers.timers, json!([])).await;
+        assert_eq!(response["error"]["code"], INVALID_REQUEST);
+
+        let outcome = handle_message(
+            json!([{"jsonrpc": "2.0", "method": "timer.status"}]),
+            &test_timers.timers,
+        )
+        .await;
+        assert!(outcome.reply.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe() {
+        let test_timers = create_test_timers();
+        let outcome = handle_message(
+            json!({"jsonrpc": "2.0", "method": "timer.subscribe", "id": 9}),
+            &test_timers.timers,
+        )
+        .await;
+
+        assert_eq!(outcome.subscribe.as_deref(), Some(DEFAULT_TIMER_NAME));
+        let response = serde_json::to_value(outcome.reply.unwrap()).unwrap();
+        assert_eq!(response["result"]["subscribed"], true);
+
+        let outcome = handle_message(
+            json!({"jsonrpc": "2.0", "method": "timer.subscribe", "params": {"timer": "prep"}, "id": 10}),
+            &test_timers.timers,
+        )
+        .await;
+        assert!(outcome.subscribe.is_none());
+        let response = serde_json::to_value(outcome.reply.unwrap()).unwrap();
+        assert_eq!(response["error"]["data"]["code"], "not_found");
+    }
+
+    #[tokio::test]
+    async fn test_timer_param_selects_named_timer() {
+        let test_timers = create_test_timers();
+        let response = call(
+            &test_timers.timers,
+            json!({
+                "jsonrpc": "2.0",
+                "method": "timer.start",
+                "params": {"timer": "prep", "taskName": "準備"},
+                "id": 1
+            }),
+        )
+        .await;
+        assert_eq!(response["result"]["taskName"], "準備");
+
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "timer.status", "params": {"timer": "prep"}, "id": 2}),
+        )
+        .await;
+        assert_eq!(response["result"]["state"], "working");
+
+        // デフォルトタイマーは操作されない
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "timer.status", "id": 3}),
+        )
+        .await;
+        assert_eq!(response["result"]["state"], "stopped");
+
+        let outcome = handle_message(
+            json!({"jsonrpc": "2.0", "method": "timer.subscribe", "params": {"timer": "prep"}}),
+            &test_timers.timers,
+        )
+        .await;
+        assert_eq!(outcome.subscribe.as_deref(), Some("prep"));
+
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "timer.pause", "params": {"timer": "other"}, "id": 4}),
+        )
+        .await;
+        assert_eq!(response["error"]["data"]["code"], "not_found");
+
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "timer.stop", "params": {"timer": 1}, "id": 5}),
+        )
+        .await;
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_hello() {
+        let test_timers = create_test_timers();
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "daemon.hello", "params": {"protocolVersion": 1}, "id": 1}),
+        )
+        .await;
+        assert_eq!(response["result"]["protocolVersion"], 1);
+
+        let response = call(
+            &test_timers.timers,
+            json!({"jsonrpc": "2.0", "method": "daemon.hello", "params": {"protocolVersion": 99}, "id": 2}),
+        )
+        .await;
+        assert_eq!(
+            response["error"]["data"]["code"],
+            "unsupported_protocol_version"
+        );
+    }
+
+    #[test]
+    fn test_notification_from_stream_message() {
+        let message = StreamMessage::Event {
+            event: TimerEvent::Tick {
+                remaining_seconds: 10,
+            },
+        };
+        let json = serde_json::to_value(notification(message)).unwrap();
+
+        assert_eq!(json["jsonrpc"], "2.0");
+        assert_eq!(json["method"], EVENT_NOTIFICATION);
+        assert_eq!(json["params"]["remainingSeconds"], 10);
+        assert!(json.get("id").is_none());
+    }
+
+    #[test]
+    fn test_parse_error_response() {
+        let error = serde_json::from_str::<Value>("{").unwrap_err();
+        let json = serde_json::to_value(parse_error(&error)).unwrap();
+
+        assert_eq!(json["error"]["code"], PARSE_ERROR);
+        assert_eq!(json["id"], Value::Null);
+    }
+}
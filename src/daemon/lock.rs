@@ -0,0 +1,232 @@
+//! デーモンの多重起動防止
+//!
+//! ソケットと同じディレクトリのロックファイル（`daemon.lock`）に排他ロック（flock）を掛け、
+//! PIDファイル（`daemon.pid`）に自身のPIDを書き込む。
+//! flockはプロセス終了時にOSが解放するため、異常終了したデーモンのロックは
+//! 次の起動時にそのまま取得し直せる（古いPIDファイルとソケットは上書きされる）。
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::daemon::ipc::create_private_dir;
+
+/// ロックファイル名
+const LOCK_FILE_NAME: &str = "daemon.lock";
+
+/// PIDファイル名
+const PID_FILE_NAME: &str = "daemon.pid";
+
+/// 多重起動防止のエラー型
+#[derive(Debug, Error)]
+pub enum DaemonLockError {
+    /// 別のデーモンが起動している
+    #[error("デーモンは既に起動しています{}", format_pid(*.pid))]
+    AlreadyRunning {
+        /// 起動中のデーモンのPID（PIDファイルから読み取れた場合）
+        pid: Option<u32>,
+    },
+
+    /// IOエラー
+    #[error("ロックファイルの操作に失敗しました: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+fn format_pid(pid: Option<u32>) -> String {
+    pid.map(|pid| format!("（PID: {}）", pid))
+        .unwrap_or_default()
+}
+
+/// デーモンのロック
+///
+/// 保持している間は同じディレクトリで別のデーモンを起動できない。
+/// ドロップ時にPIDファイルを削除し、ロックを解放する。
+#[derive(Debug)]
+pub struct DaemonLock {
+    /// ロックを保持しているファイル（クローズでロックが解放される）
+    _file: File,
+    /// PIDファイルのパス
+    pid_path: PathBuf,
+}
+
+impl DaemonLock {
+    /// ロックを取得
+    ///
+    /// 次の順に確認する。
+    /// 1. ソケットに接続できる場合は、起動中のデーモンがあるとみなす
+    /// 2. ロックファイルの排他ロックを取得できない場合は、起動中のデーモンがあるとみなす
+    /// 3. ロックを取得できた場合は、PIDファイルに自身のPIDを書き込む
+    ///
+    /// # Arguments
+    ///
+    /// * `socket_path` - デーモンのソケットパス（ロック・PIDファイルは同じディレクトリに作成）
+    ///
+    /// # Errors
+    ///
+    /// - 別のデーモンが起動している場合（`DaemonLockError::AlreadyRunning`）
+    /// - ロックファイル・PIDファイルの操作に失敗した場合
+    pub fn acquire(socket_path: &Path) -> Result<Self, DaemonLockError> {
+        let dir = socket_path.parent().unwrap_or_else(|| Path::new("."));
+        let lock_path = dir.join(LOCK_FILE_NAME);
+        let pid_path = dir.join(PID_FILE_NAME);
+
+        // ロックを取得する前に、応答中のデーモンがないか確認する
+        if UnixStream::connect(socket_path).is_ok() {
+            return Err(DaemonLockError::AlreadyRunning {
+                pid: read_pid(&pid_path),
+            });
+        }
+
+        if !dir.exists() {
+            create_private_dir(dir)?;
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)?;
+
+        // SAFETY: 有効なファイルディスクリプタに対するflock呼び出し
+        if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } != 0 {
+            let error = std::io::Error::last_os_error();
+            return if error.kind() == std::io::ErrorKind::WouldBlock {
+                Err(DaemonLockError::AlreadyRunning {
+                    pid: read_pid(&pid_path),
+                })
+            } else {
+                Err(error.into())
+            };
+        }
+
+        // ロックを取得できたので、残っているPIDファイルは古いものとして上書きする
+        let mut pid_file = File::create(&pid_path)?;
+        writeln!(pid_file, "{}", std::process::id())?;
+
+        Ok(Self {
+            _file: file,
+            pid_path,
+        })
+    }
+
+    /// PIDファイルのパスを取得
+    pub fn pid_path(&self) -> &Path {
+        &self.pid_path
+    }
+}
+
+impl Drop for DaemonLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.pid_path);
+    }
+}
+
+/// PIDファイルからPIDを読み取る
+fn read_pid(path: &Path) -> Option<u32> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_acquire_writes_pid_file() {
+        let dir = tempdir().unwrap();
+        let socket_path = dir.path().join("test.sock");
+
+        let lock = DaemonLock::acquire(&socket_path).unwrap();
+
+        assert_eq!(lock.pid_path(), dir.path().join(PID_FILE_NAME));
+        assert_eq!(read_pid(lock.pid_path()), Some(std::process::id()));
+        assert!(dir.path().join(LOCK_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn test_second_acquire_fails_while_locked() {
+        let dir = tempdir().unwrap();
+        let socket_path = dir.path().join("test.sock");
+
+        let _lock = DaemonLock::acquire(&socket_path).unwrap();
+        let result = DaemonLock::acquire(&socket_path);
+
+        match result {
+            Err(DaemonLockError::AlreadyRunning { pid }) => {
+                assert_eq!(pid, Some(std::process::id()));
+            }
+            other => panic!("expected AlreadyRunning, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_acquire_after_release() {
+        let dir = tempdir().unwrap();
+        let socket_path = dir.path().join("test.sock");
+
+        let lock = DaemonLock::acquire(&socket_path).unwrap();
+        let pid_path = lock.pid_path().to_path_buf();
+        drop(lock);
+
+        assert!(!pid_path.exists());
+        assert!(DaemonLock::acquire(&socket_path).is_ok());
+    }
+
+    #[test]
+    fn test_acquire_recovers_stale_files() {
+        let dir = tempdir().unwrap();
+        let socket_path = dir.path().join("test.sock");
+
+        // 異常終了したデーモンが残したファイル
+        std::fs::write(dir.path().join(LOCK_FILE_NAME), "").unwrap();
+        std::fs::write(dir.path().join(PID_FILE_NAME), "999999\n").unwrap();
+        std::fs::write(&socket_path, "").unwrap();
+
+        let lock = DaemonLock::acquire(&socket_path).unwrap();
+        assert_eq!(read_pid(lock.pid_path()), Some(std::process::id()));
+    }
+
+    #[test]
+    fn test_acquire_fails_when_socket_is_live() {
+        let dir = tempdir().unwrap();
+        let socket_path = dir.path().join("test.sock");
+        std::fs::write(dir.path().join(PID_FILE_NAME), "4242\n").unwrap();
+
+        let _listener = std::os::unix::net::UnixListener::bind(&socket_path).unwrap();
+        let result = DaemonLock::acquire(&socket_path);
+
+        assert!(matches!(
+            result,
+            Err(DaemonLockError::AlreadyRunning { pid: Some(4242) })
+        ));
+    }
+
+    #[test]
+    fn test_acquire_creates_directory() {
+        let dir = tempdir().unwrap();
+        let socket_path = dir.path().join("nested").join("test.sock");
+
+        let _lock = DaemonLock::acquire(&socket_path).unwrap();
+        assert!(dir.path().join("nested").join(PID_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn test_error_message() {
+        let error = DaemonLockError::AlreadyRunning { pid: Some(123) };
+        assert_eq!(
+            error.to_string(),
+            "デーモンは既に起動しています（PID: 123）"
+        );
+
+        let error = DaemonLockError::AlreadyRunning { pid: None };
+        assert_eq!(error.to_string(), "デーモンは既に起動しています");
+    }
+}
@@ -0,0 +1,298 @@
+//! Prometheusメトリクスのエンドポイント
+//!
+//! `127.0.0.1` 上で `GET /metrics` にPrometheusのテキスト形式でメトリクスを返す。
+//! 値は読み取り専用で、タイマーの状態は取得のたびに各タイマーから読み取る。
+//!
+//! 設定ファイル (`~/.pomodoro/metrics.json`) で有効化しない限り起動しない。
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tokio::io::BufReader;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::{timeout, Duration};
+
+use super::http::{
+    error_response, read_request, write_response, HttpError, HttpResponse, REQUEST_TIMEOUT_SECS,
+};
+use crate::daemon::TimerRegistry;
+use crate::metrics::{Metrics, TimerGauge};
+use crate::types::ErrorCode;
+
+/// デフォルトのポート番号
+pub const DEFAULT_METRICS_PORT: u16 = 7375;
+
+/// Prometheusのテキスト形式のContent-Type
+const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+// ============================================================================
+// Config
+// ============================================================================
+
+/// メトリクスエンドポイント設定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    /// エンドポイントを有効にするかどうか（デフォルト: 無効）
+    #[serde(default)]
+    pub enabled: bool,
+    /// 待ち受けるポート番号（`127.0.0.1` のみ）
+    #[serde(default = "default_port")]
+    pub port: u16,
+}
+
+fn default_port() -> u16 {
+    DEFAULT_METRICS_PORT
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_METRICS_PORT,
+        }
+    }
+}
+
+impl MetricsConfig {
+    /// デフォルトの設定ファイルパス（データディレクトリの `metrics.json`）を取得
+    fn get_config_path() -> Option<PathBuf> {
+        crate::paths::data_file("metrics.json")
+    }
+
+    /// 設定ファイルから読み込む
+    /// ファイルが存在しない場合はデフォルト値（無効）を返す
+    pub fn load() -> Result<Self> {
+        match Self::get_config_path() {
+            Some(path) => Self::load_from_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// 指定されたパスから読み込む
+    pub fn load_from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).context("Failed to read metrics config")?;
+        serde_json::from_str(&content).context("Failed to parse metrics config")
+    }
+
+    /// 指定されたパスに保存する
+    pub fn save_to_file(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Server
+// ============================================================================
+
+/// メトリクスエンドポイントのサーバー
+pub struct MetricsServer {
+    /// TCPリスナー（`127.0.0.1` のみ）
+    listener: TcpListener,
+}
+
+impl MetricsServer {
+    /// 指定されたポートで待ち受けるサーバーを作成
+    ///
+    /// ポートに0を指定した場合は空いているポートを使用する。
+    pub async fn bind(port: u16) -> Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+            .await
+            .with_context(|| format!("Failed to bind metrics endpoint to 127.0.0.1:{}", port))?;
+        Ok(Self { listener })
+    }
+
+    /// 待ち受けアドレスを取得
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        self.listener
+            .local_addr()
+            .context("Failed to get metrics endpoint address")
+    }
+
+    /// リクエストの受け付けを開始
+    ///
+    /// 接続ごとにタスクを起動して処理する。リスナーのエラーが発生するまで戻らない。
+    pub async fn run(self, timers: Arc<TimerRegistry>, metrics: Arc<Metrics>) -> Result<()> {
+        loop {
+            let (stream, _) = self
+                .listener
+                .accept()
+                .await
+                .context("Failed to accept metrics connection")?;
+
+            let timers = timers.clone();
+            let metrics = metrics.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream, &timers, &metrics).await {
+                    eprintln!("Metrics connection error: {}", e);
+                }
+            });
+        }
+    }
+}
+
+/// 1接続（1リクエスト）を処理
+async fn handle_connection(
+    stream: TcpStream,
+    timers: &TimerRegistry,
+    metrics: &Metrics,
+) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+
+    let response = match timeout(
+        Duration::from_secs(REQUEST_TIMEOUT_SECS),
+        read_request(&mut reader),
+    )
+    .await
+    {
+        Err(_) => return Ok(()),
+        Ok(Err(HttpError::Io(e))) => return Err(e).context("Failed to read HTTP request"),
+        Ok(Err(e)) => error_response(e.status(), e.code(), e.to_string()),
+        Ok(Ok(request)) => match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/metrics") => {
+                let body = metrics.render(&timer_gauges(timers).await);
+                HttpResponse::new(200, METRICS_CONTENT_TYPE, body.into_bytes())
+            }
+            (_, "/metrics") => error_response(
+                405,
+                ErrorCode::InvalidRequest,
+                format!("{} は使用できません", request.method),
+            ),
+            _ => error_response(
+                404,
+                ErrorCode::NotFound,
+                format!("エンドポイントが見つかりません: {}", request.path),
+            ),
+        },
+    };
+
+    write_response(&mut writer, &response)
+        .await
+        .context("Failed to write HTTP response")
+}
+
+/// 各タイマーの現在の状態を取得
+async fn timer_gauges(timers: &TimerRegistry) -> Vec<TimerGauge> {
+    let mut gauges = Vec::new();
+    for (name, timer) in timers.timers() {
+        let engine = timer.engine().lock().await;
+        let state = engine.get_state();
+        gauges.push(TimerGauge {
+            timer: name,
+            phase: state.phase,
+            remaining_seconds: state.remaining_seconds,
+        });
+    }
+    gauges
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PomodoroConfig, StartParams, TimerEvent};
+    use tempfile::tempdir;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn test_config_default_is_disabled() {
+        let config = MetricsConfig::default();
+        assert!(!config.enabled);
+        assert_eq!(config.port, DEFAULT_METRICS_PORT);
+    }
+
+    #[test]
+    fn test_config_save_and_load() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("metrics.json");
+        assert_eq!(
+            MetricsConfig::load_from_file(&path).unwrap(),
+            MetricsConfig::default()
+        );
+
+        let config = MetricsConfig {
+            enabled: true,
+            port: 9100,
+        };
+        config.save_to_file(&path).unwrap();
+        assert_eq!(MetricsConfig::load_from_file(&path).unwrap(), config);
+    }
+
+    #[test]
+    fn test_config_port_defaults_when_missing() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("metrics.json");
+        std::fs::write(&path, r#"{"enabled": true}"#).unwrap();
+
+        let config = MetricsConfig::load_from_file(&path).unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.port, DEFAULT_METRICS_PORT);
+    }
+
+    /// サーバーを起動し、待ち受けアドレスを返す
+    async fn start_server(timers: Arc<TimerRegistry>, metrics: Arc<Metrics>) -> SocketAddr {
+        let server = MetricsServer::bind(0).await.unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(server.run(timers, metrics));
+        addr
+    }
+
+    /// リクエストを送信し、レスポンス全体を返す
+    async fn send(addr: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint() {
+        let (timers, _rx) = TimerRegistry::new(PomodoroConfig::default(), None);
+        let timers = Arc::new(timers);
+        timers
+            .default_timer()
+            .engine()
+            .lock()
+            .await
+            .start(&StartParams::default())
+            .unwrap();
+        let metrics = Arc::new(Metrics::new());
+        metrics.record_event("default", &TimerEvent::Paused);
+        let addr = start_server(timers, metrics).await;
+
+        let response = send(addr, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").await;
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: text/plain; version=0.0.4"));
+        assert!(response.contains("pomodoro_timer_phase{timer=\"default\",phase=\"working\"} 1\n"));
+        assert!(response.contains("pomodoro_timer_remaining_seconds{timer=\"default\"} 1500\n"));
+        assert!(response.contains("pomodoro_pauses_total{timer=\"default\"} 1\n"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_route_and_method() {
+        let (timers, _rx) = TimerRegistry::new(PomodoroConfig::default(), None);
+        let addr = start_server(Arc::new(timers), Arc::new(Metrics::new())).await;
+
+        let response = send(addr, "GET /v1/timer/status HTTP/1.1\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 404 "));
+
+        let response = send(addr, "POST /metrics HTTP/1.1\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 405 "));
+    }
+}
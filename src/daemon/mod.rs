@@ -2,8 +2,23 @@
 //!
 //! ポモドーロタイマーのバックグラウンドデーモン機能を提供する。
 
+pub mod directive;
+pub mod http;
 pub mod ipc;
+pub mod jsonrpc;
+pub mod lock;
+pub mod metrics;
+pub mod registry;
+pub mod reload;
+pub mod team;
 pub mod timer;
 
-pub use ipc::{handle_request, IpcServer};
-pub use timer::{TimerEngine, TimerEvent};
+pub use directive::{DirectiveApplier, DirectiveOutcome};
+pub use http::{HttpApiConfig, HttpApiServer};
+pub use ipc::{handle_request, IpcConnection, IpcServer};
+pub use lock::{DaemonLock, DaemonLockError};
+pub use metrics::{MetricsConfig, MetricsServer};
+pub use registry::{TimerHandle, TimerRegistry, TimerRegistryError};
+pub use reload::{ConfigReloader, ReloadError, ReloadSummary};
+pub use team::{TeamConfig, TeamFollower, TeamLeader, TeamMode};
+pub use timer::{PreHookCheck, TimerEngine, TimerEvent};
@@ -0,0 +1,449 @@
+//! 名前付きタイマーの管理
+//!
+//! 1つのデーモンで独立した複数の `TimerEngine` を名前で管理する。
+//! デフォルトタイマー（`DEFAULT_TIMER_NAME`）は常に存在し、タイマー名を省略したリクエストの対象になる。
+//! 名前付きタイマーは `start` で作成され、`stop` で削除される。
+//!
+//! 各タイマーのイベントは、そのタイマーの購読者（broadcast）と、
+//! デーモン全体の処理（通知・履歴）用のチャネルの両方に転送する。
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use tokio::sync::{broadcast, mpsc, Mutex};
+
+use crate::daemon::{TimerEngine, TimerEvent};
+use crate::hooks::HookExecutor;
+use crate::types::{PomodoroConfig, DEFAULT_TIMER_NAME};
+
+/// タイマーごとのイベント配信チャネルのバッファサイズ
+const EVENT_BUFFER_SIZE: usize = 256;
+
+/// 同時に存在できるタイマーの最大数（デフォルトタイマーを含む）
+pub const MAX_TIMERS: usize = 16;
+
+/// タイマー名の最大長
+const MAX_TIMER_NAME_LEN: usize = 32;
+
+/// タイマー名付きのイベント
+pub type NamedTimerEvent = (String, TimerEvent);
+
+/// タイマー
+///
+/// タイマーエンジンと、そのタイマーのイベント配信チャネルの組。
+#[derive(Clone)]
+pub struct TimerHandle {
+    engine: Arc<Mutex<TimerEngine>>,
+    events: broadcast::Sender<TimerEvent>,
+}
+
+impl TimerHandle {
+    /// 新しいTimerHandleを作成
+    pub fn new(engine: Arc<Mutex<TimerEngine>>, events: broadcast::Sender<TimerEvent>) -> Self {
+        Self { engine, events }
+    }
+
+    /// タイマーエンジンを取得
+    pub fn engine(&self) -> &Arc<Mutex<TimerEngine>> {
+        &self.engine
+    }
+
+    /// イベント配信チャネルを取得
+    pub fn events(&self) -> &broadcast::Sender<TimerEvent> {
+        &self.events
+    }
+}
+
+/// 名前付きタイマーの作成に必要な設定
+struct TimerFactory {
+    config: PomodoroConfig,
+    hook_executor: Option<Arc<HookExecutor>>,
+    events: mpsc::UnboundedSender<NamedTimerEvent>,
+}
+
+impl TimerFactory {
+    /// タイマーを作成し、イベントの転送を開始する
+    fn create(&self, name: &str) -> TimerHandle {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let engine = match &self.hook_executor {
+            Some(executor) => {
+                TimerEngine::new_with_hook_executor(self.config.clone(), tx, executor.clone())
+            }
+            None => TimerEngine::new(self.config.clone(), tx),
+        };
+        let (events, _) = broadcast::channel(EVENT_BUFFER_SIZE);
+        let handle = TimerHandle::new(Arc::new(Mutex::new(engine)), events.clone());
+
+        // エンジンが破棄されるとチャネルが閉じ、転送タスクも終了する
+        let name = name.to_string();
+        let all_events = self.events.clone();
+        tokio::spawn(async move {
+            while let Some(event) = rx.recv().await {
+                // 購読者がいない場合の送信エラーは無視する
+                let _ = events.send(event.clone());
+                let _ = all_events.send((name.clone(), event));
+            }
+        });
+
+        handle
+    }
+}
+
+/// タイマーの取得・作成のエラー
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TimerRegistryError {
+    /// タイマー名が不正
+    #[error("タイマー名が不正です: {0}（英数字・'-'・'_' の{max}文字以内で指定してください）", max = MAX_TIMER_NAME_LEN)]
+    InvalidName(String),
+
+    /// タイマーが存在しない
+    #[error("タイマー '{0}' は存在しません")]
+    NotFound(String),
+
+    /// タイマーの数が上限に達している
+    #[error("タイマーの数が上限（{max}）に達しています", max = MAX_TIMERS)]
+    TooManyTimers,
+
+    /// 名前付きタイマーを作成できない（単一タイマーのレジストリ）
+    #[error("このデーモンでは名前付きタイマーを作成できません")]
+    Unsupported,
+}
+
+/// 名前付きタイマーのレジストリ
+pub struct TimerRegistry {
+    /// タイマー名とタイマー（名前順）
+    timers: std::sync::Mutex<BTreeMap<String, TimerHandle>>,
+    /// 名前付きタイマーの作成（Noneの場合はデフォルトタイマーのみ）
+    factory: Option<TimerFactory>,
+}
+
+impl TimerRegistry {
+    /// 新しいTimerRegistryを作成
+    ///
+    /// デフォルトタイマーを作成し、すべてのタイマーのイベントを受信するレシーバーを返す。
+    /// イベントの転送にタスクを使用するため、tokioランタイム上で呼び出す必要がある。
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - 各タイマーの初期設定
+    /// * `hook_executor` - すべてのタイマーで共有するフック実行機能
+    pub fn new(
+        config: PomodoroConfig,
+        hook_executor: Option<Arc<HookExecutor>>,
+    ) -> (Self, mpsc::UnboundedReceiver<NamedTimerEvent>) {
+        let (events, rx) = mpsc::unbounded_channel();
+        let factory = TimerFactory {
+            config,
+            hook_executor,
+            events,
+        };
+        let default = factory.create(DEFAULT_TIMER_NAME);
+
+        let registry = Self {
+            timers: std::sync::Mutex::new(BTreeMap::from([(
+                DEFAULT_TIMER_NAME.to_string(),
+                default,
+            )])),
+            factory: Some(factory),
+        };
+        (registry, rx)
+    }
+
+    /// デフォルトタイマーのみのレジストリを作成
+    ///
+    /// イベントの転送は呼び出し側で行う。名前付きタイマーは作成できない。
+    pub fn single(handle: TimerHandle) -> Self {
+        Self {
+            timers: std::sync::Mutex::new(BTreeMap::from([(
+                DEFAULT_TIMER_NAME.to_string(),
+                handle,
+            )])),
+            factory: None,
+        }
+    }
+
+    /// デフォルトタイマーを取得
+    pub fn default_timer(&self) -> TimerHandle {
+        self.get(DEFAULT_TIMER_NAME)
+            .expect("default timer is never removed")
+    }
+
+    /// タイマーを取得
+    pub fn get(&self, name: &str) -> Option<TimerHandle> {
+        self.lock().get(name).cloned()
+    }
+
+    /// タイマーを取得（存在しない場合はエラー）
+    pub fn find(&self, name: &str) -> Result<TimerHandle, TimerRegistryError> {
+        self.get(name)
+            .ok_or_else(|| TimerRegistryError::NotFound(name.to_string()))
+    }
+
+    /// タイマーを取得し、存在しない場合は作成する
+    pub fn get_or_create(&self, name: &str) -> Result<TimerHandle, TimerRegistryError> {
+        validate_timer_name(name)?;
+
+        let mut timers = self.lock();
+        if let Some(handle) = timers.get(name) {
+            return Ok(handle.clone());
+        }
+
+        let factory = self
+            .factory
+            .as_ref()
+            .ok_or(TimerRegistryError::Unsupported)?;
+        if timers.len() >= MAX_TIMERS {
+            return Err(TimerRegistryError::TooManyTimers);
+        }
+
+        let handle = factory.create(name);
+        timers.insert(name.to_string(), handle.clone());
+        Ok(handle)
+    }
+
+    /// 名前付きタイマーを削除する（デフォルトタイマーは削除しない）
+    ///
+    /// # Returns
+    ///
+    /// 削除した場合はtrue
+    pub fn remove(&self, name: &str) -> bool {
+        if name == DEFAULT_TIMER_NAME {
+            return false;
+        }
+        self.lock().remove(name).is_some()
+    }
+
+    /// 停止中の名前付きタイマーを削除する
+    ///
+    /// 開始に失敗したタイマーや、自動サイクルなしで終了したタイマーが上限を占有しないようにする。
+    /// 状態の確認中に別のタイマーに置き換わった場合は削除しない。
+    ///
+    /// # Returns
+    ///
+    /// 削除した場合はtrue
+    pub async fn remove_if_stopped(&self, name: &str) -> bool {
+        if name == DEFAULT_TIMER_NAME {
+            return false;
+        }
+        let Some(handle) = self.get(name) else {
+            return false;
+        };
+        let engine = handle.engine().lock().await;
+        let state = engine.get_state();
+        if state.is_running() || state.is_paused() {
+            return false;
+        }
+
+        let mut timers = self.lock();
+        match timers.get(name) {
+            Some(current) if Arc::ptr_eq(current.engine(), handle.engine()) => {
+                timers.remove(name);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// すべてのタイマー（名前順）
+    pub fn timers(&self) -> Vec<(String, TimerHandle)> {
+        self.lock()
+            .iter()
+            .map(|(name, handle)| (name.clone(), handle.clone()))
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, TimerHandle>> {
+        self.timers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// タイマー名を検証
+fn validate_timer_name(name: &str) -> Result<(), TimerRegistryError> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_TIMER_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(TimerRegistryError::InvalidName(name.to_string()))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::StartParams;
+
+    fn create_registry() -> (TimerRegistry, mpsc::UnboundedReceiver<NamedTimerEvent>) {
+        TimerRegistry::new(
+            PomodoroConfig::default(),
+            Some(Arc::new(HookExecutor::with_config(Default::default()))),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_new_creates_default_timer() {
+        let (registry, _rx) = create_registry();
+
+        let timers = registry.timers();
+        assert_eq!(timers.len(), 1);
+        assert_eq!(timers[0].0, DEFAULT_TIMER_NAME);
+        assert!(registry.get(DEFAULT_TIMER_NAME).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_or_create_named_timer() {
+        let (registry, _rx) = create_registry();
+
+        let prep = registry.get_or_create("prep").unwrap();
+        let again = registry.get_or_create("prep").unwrap();
+
+        assert!(Arc::ptr_eq(prep.engine(), again.engine()));
+        assert!(!Arc::ptr_eq(
+            prep.engine(),
+            registry.default_timer().engine()
+        ));
+        let names: Vec<_> = registry.timers().into_iter().map(|(n, _)| n).collect();
+        assert_eq!(names, vec!["default", "prep"]);
+    }
+
+    #[tokio::test]
+    async fn test_timers_are_independent() {
+        let (registry, _rx) = create_registry();
+        let prep = registry.get_or_create("prep").unwrap();
+
+        prep.engine()
+            .lock()
+            .await
+            .start(&StartParams::default())
+            .unwrap();
+
+        assert!(prep.engine().lock().await.get_state().is_running());
+        assert!(!registry
+            .default_timer()
+            .engine()
+            .lock()
+            .await
+            .get_state()
+            .is_running());
+    }
+
+    #[tokio::test]
+    async fn test_events_are_forwarded_with_name() {
+        let (registry, mut rx) = create_registry();
+        let prep = registry.get_or_create("prep").unwrap();
+        let mut subscriber = prep.events().subscribe();
+
+        prep.engine()
+            .lock()
+            .await
+            .start(&StartParams::default())
+            .unwrap();
+
+        let (name, event) = rx.recv().await.unwrap();
+        assert_eq!(name, "prep");
+        assert!(matches!(event, TimerEvent::WorkStarted { .. }));
+        assert!(matches!(
+            subscriber.recv().await.unwrap(),
+            TimerEvent::WorkStarted { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_remove_named_timer() {
+        let (registry, _rx) = create_registry();
+        registry.get_or_create("prep").unwrap();
+
+        assert!(registry.remove("prep"));
+        assert!(registry.get("prep").is_none());
+        assert!(!registry.remove("prep"));
+
+        // デフォルトタイマーは削除できない
+        assert!(!registry.remove(DEFAULT_TIMER_NAME));
+        assert!(registry.get(DEFAULT_TIMER_NAME).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_remove_if_stopped() {
+        let (registry, _rx) = create_registry();
+        let prep = registry.get_or_create("prep").unwrap();
+        prep.engine()
+            .lock()
+            .await
+            .start(&StartParams::default())
+            .unwrap();
+
+        // 実行中・一時停止中のタイマーは削除しない
+        assert!(!registry.remove_if_stopped("prep").await);
+        prep.engine().lock().await.pause().unwrap();
+        assert!(!registry.remove_if_stopped("prep").await);
+
+        prep.engine().lock().await.stop().unwrap();
+        assert!(registry.remove_if_stopped("prep").await);
+        assert!(registry.get("prep").is_none());
+        assert!(!registry.remove_if_stopped("prep").await);
+        assert!(!registry.remove_if_stopped(DEFAULT_TIMER_NAME).await);
+    }
+
+    #[tokio::test]
+    async fn test_find_missing_timer() {
+        let (registry, _rx) = create_registry();
+
+        assert!(matches!(
+            registry.find("prep"),
+            Err(TimerRegistryError::NotFound(name)) if name == "prep"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_or_create_rejects_invalid_names() {
+        let (registry, _rx) = create_registry();
+
+        for name in ["", "has space", "日本語", &"a".repeat(33)] {
+            assert!(matches!(
+                registry.get_or_create(name),
+                Err(TimerRegistryError::InvalidName(_))
+            ));
+        }
+        assert!(registry.get_or_create("meeting-prep_2").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_or_create_limit() {
+        let (registry, _rx) = create_registry();
+
+        for i in 1..MAX_TIMERS {
+            registry.get_or_create(&format!("timer{}", i)).unwrap();
+        }
+
+        assert!(matches!(
+            registry.get_or_create("one-more"),
+            Err(TimerRegistryError::TooManyTimers)
+        ));
+        // 既存のタイマーは取得できる
+        assert!(registry.get_or_create("timer1").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_single_registry_cannot_create_named_timers() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let engine = Arc::new(Mutex::new(TimerEngine::new_without_hooks(
+            PomodoroConfig::default(),
+            tx,
+        )));
+        let (events, _) = broadcast::channel(16);
+        let registry = TimerRegistry::single(TimerHandle::new(engine.clone(), events));
+
+        assert!(Arc::ptr_eq(registry.default_timer().engine(), &engine));
+        assert!(matches!(
+            registry.get_or_create("prep"),
+            Err(TimerRegistryError::Unsupported)
+        ));
+    }
+}
@@ -0,0 +1,301 @@
+//! 設定の再読み込み
+//!
+//! フック設定（`hooks.json`）とサウンド設定（`sound-config.json`）を、
+//! デーモンを再起動せずに読み込み直す。SIGHUP・`IpcRequest::Reload`・設定ファイルの監視から呼ばれる。
+//! 新しい設定をすべて検証してから差し替え、いずれかが不正な場合は現在の設定をそのまま使い続ける。
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+
+use thiserror::Error;
+
+use crate::hooks::{HookConfig, HookConfigError, HookExecutor};
+use crate::sound::SoundConfig;
+
+/// 設定ファイル監視のデフォルト間隔
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 再読み込みのエラー型
+#[derive(Debug, Error)]
+pub enum ReloadError {
+    /// フック設定が不正
+    #[error("フック設定の再読み込みに失敗しました: {0}")]
+    Hooks(#[from] HookConfigError),
+
+    /// サウンド設定が不正
+    #[error("サウンド設定の再読み込みに失敗しました: {0}")]
+    Sound(String),
+}
+
+/// 再読み込みの結果
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReloadSummary {
+    /// 新しいフック設定に登録されたフックの数
+    pub hook_count: usize,
+    /// 新しいサウンド設定
+    pub sound_config: SoundConfig,
+}
+
+/// 設定の再読み込み機能
+#[derive(Debug)]
+pub struct ConfigReloader {
+    /// 設定を差し替えるフック実行機能（フック無効のエンジンではNone）
+    hook_executor: Option<Arc<HookExecutor>>,
+    /// 現在のサウンド設定
+    sound_config: RwLock<SoundConfig>,
+    /// フック設定ファイルのパス
+    hooks_path: Option<PathBuf>,
+    /// サウンド設定ファイルのパス
+    sound_path: Option<PathBuf>,
+}
+
+impl ConfigReloader {
+    /// 新しいConfigReloaderを作成
+    ///
+    /// 設定ファイルはデフォルトパス（`~/.pomodoro/hooks.json`、`~/.pomodoro/sound-config.json`）を使用する。
+    ///
+    /// # Arguments
+    ///
+    /// * `hook_executor` - 設定を差し替えるフック実行機能
+    /// * `sound_config` - 起動時に読み込んだサウンド設定
+    pub fn new(hook_executor: Option<Arc<HookExecutor>>, sound_config: SoundConfig) -> Self {
+        Self {
+            hook_executor,
+            sound_config: RwLock::new(sound_config),
+            hooks_path: HookConfig::default_config_path().ok(),
+            sound_path: SoundConfig::get_config_path(),
+        }
+    }
+
+    /// 設定ファイルのパスを指定
+    pub fn with_paths(mut self, hooks_path: PathBuf, sound_path: PathBuf) -> Self {
+        self.hooks_path = Some(hooks_path);
+        self.sound_path = Some(sound_path);
+        self
+    }
+
+    /// 現在のサウンド設定を取得
+    pub fn sound_config(&self) -> SoundConfig {
+        self.sound_config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 設定を再読み込み
+    ///
+    /// フック設定とサウンド設定の両方を読み込んで検証し、両方とも成功した場合のみ差し替える。
+    /// 設定ファイルが存在しない場合はデフォルト（フックなし・デフォルトのサウンド）に戻す。
+    ///
+    /// # Errors
+    ///
+    /// いずれかの設定の読み込み・検証に失敗した場合（現在の設定は変更されない）
+    pub fn reload(&self) -> Result<ReloadSummary, ReloadError> {
+        let hook_config = match (&self.hook_executor, &self.hooks_path) {
+            (Some(_), Some(path)) => Some(HookExecutor::load_for_reload(path)?),
+            _ => None,
+        };
+        let sound_config = match &self.sound_path {
+            Some(path) => {
+                SoundConfig::load_from_file(path).map_err(|e| ReloadError::Sound(e.to_string()))?
+            }
+            None => SoundConfig::default(),
+        };
+
+        // 検証がすべて成功してから差し替える
+        let hook_count = match (&self.hook_executor, hook_config) {
+            (Some(executor), Some(config)) => {
+                let count = config.hooks.len();
+                executor.replace_config(config);
+                count
+            }
+            (Some(executor), None) => executor.hook_count(),
+            (None, _) => 0,
+        };
+        *self.sound_config.write().unwrap_or_else(|e| e.into_inner()) = sound_config.clone();
+
+        Ok(ReloadSummary {
+            hook_count,
+            sound_config,
+        })
+    }
+
+    /// 設定ファイルを監視し、変更されたら再読み込みする
+    ///
+    /// ファイルの更新日時を `interval` ごとに確認する（作成・削除も変更として扱う）。
+    /// 再読み込みに失敗した場合は現在の設定を維持し、次の変更を待つ。戻らない。
+    pub async fn watch(self: Arc<Self>, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        let mut last = self.fingerprint();
+
+        loop {
+            ticker.tick().await;
+            let current = self.fingerprint();
+            if current == last {
+                continue;
+            }
+            last = current;
+
+            match self.reload() {
+                Ok(summary) => println!(
+                    "Configuration reloaded after file change ({} hooks)",
+                    summary.hook_count
+                ),
+                Err(e) => eprintln!("{} (keeping current configuration)", e),
+            }
+        }
+    }
+
+    /// 監視対象ファイルの更新日時
+    fn fingerprint(&self) -> Vec<Option<SystemTime>> {
+        [&self.hooks_path, &self.sound_path]
+            .into_iter()
+            .map(|path| path.as_deref().and_then(modified_time))
+            .collect()
+    }
+}
+
+/// ファイルの更新日時（存在しない場合はNone）
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::{tempdir, TempDir};
+
+    const HOOKS_JSON: &str = r#"{
+        "version": "1.0",
+        "hooks": [
+            {
+                "name": "notify",
+                "event": "work_start",
+                "script": "/bin/true"
+            }
+        ]
+    }"#;
+
+    fn create_reloader(dir: &TempDir) -> (ConfigReloader, Arc<HookExecutor>) {
+        let executor = Arc::new(HookExecutor::with_config(HookConfig::default()));
+        let reloader = ConfigReloader::new(Some(executor.clone()), SoundConfig::default())
+            .with_paths(
+                dir.path().join("hooks.json"),
+                dir.path().join("sound-config.json"),
+            );
+        (reloader, executor)
+    }
+
+    fn write_sound_config(dir: &TempDir, work: &str) {
+        let config = SoundConfig {
+            work_end_sound: work.to_string(),
+            break_end_sound: "Glass".to_string(),
+        };
+        config
+            .save_to_file(&dir.path().join("sound-config.json"))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_reload_applies_new_config() {
+        let dir = tempdir().unwrap();
+        let (reloader, executor) = create_reloader(&dir);
+        fs::write(dir.path().join("hooks.json"), HOOKS_JSON).unwrap();
+        write_sound_config(&dir, "Pop");
+
+        let summary = reloader.reload().unwrap();
+
+        assert_eq!(summary.hook_count, 1);
+        assert_eq!(executor.hook_count(), 1);
+        assert_eq!(reloader.sound_config().work_end_sound, "Pop");
+    }
+
+    #[test]
+    fn test_reload_keeps_config_when_hooks_invalid() {
+        let dir = tempdir().unwrap();
+        let (reloader, executor) = create_reloader(&dir);
+        fs::write(dir.path().join("hooks.json"), HOOKS_JSON).unwrap();
+        reloader.reload().unwrap();
+
+        // サウンド設定は正しくても、フック設定が不正なら何も差し替えない
+        fs::write(dir.path().join("hooks.json"), "{ invalid").unwrap();
+        write_sound_config(&dir, "Pop");
+        let result = reloader.reload();
+
+        assert!(matches!(result, Err(ReloadError::Hooks(_))));
+        assert_eq!(executor.hook_count(), 1);
+        assert_eq!(reloader.sound_config(), SoundConfig::default());
+    }
+
+    #[test]
+    fn test_reload_keeps_config_when_sound_invalid() {
+        let dir = tempdir().unwrap();
+        let (reloader, executor) = create_reloader(&dir);
+        fs::write(dir.path().join("hooks.json"), HOOKS_JSON).unwrap();
+        fs::write(dir.path().join("sound-config.json"), "not json").unwrap();
+
+        let result = reloader.reload();
+
+        assert!(matches!(result, Err(ReloadError::Sound(_))));
+        assert_eq!(executor.hook_count(), 0);
+    }
+
+    #[test]
+    fn test_reload_missing_files_uses_defaults() {
+        let dir = tempdir().unwrap();
+        let executor = Arc::new(HookExecutor::with_config(
+            HookConfig::parse_and_validate(HOOKS_JSON).unwrap(),
+        ));
+        let sound = SoundConfig {
+            work_end_sound: "Pop".to_string(),
+            break_end_sound: "Pop".to_string(),
+        };
+        let reloader = ConfigReloader::new(Some(executor.clone()), sound).with_paths(
+            dir.path().join("hooks.json"),
+            dir.path().join("sound-config.json"),
+        );
+
+        let summary = reloader.reload().unwrap();
+
+        assert_eq!(summary.hook_count, 0);
+        assert_eq!(reloader.sound_config(), SoundConfig::default());
+    }
+
+    #[test]
+    fn test_reload_without_hook_executor() {
+        let dir = tempdir().unwrap();
+        let reloader = ConfigReloader::new(None, SoundConfig::default()).with_paths(
+            dir.path().join("hooks.json"),
+            dir.path().join("sound-config.json"),
+        );
+        // フックを使わないエンジンではフック設定を読まない
+        fs::write(dir.path().join("hooks.json"), "{ invalid").unwrap();
+
+        assert_eq!(reloader.reload().unwrap().hook_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_watch_reloads_on_change() {
+        let dir = tempdir().unwrap();
+        let (reloader, executor) = create_reloader(&dir);
+        let reloader = Arc::new(reloader);
+        let handle = tokio::spawn(reloader.clone().watch(Duration::from_millis(20)));
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        fs::write(dir.path().join("hooks.json"), HOOKS_JSON).unwrap();
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+        while executor.hook_count() == 0 && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        handle.abort();
+
+        assert_eq!(executor.hook_count(), 1);
+    }
+}
@@ -0,0 +1,473 @@
+//! チームタイマー
+//!
+//! モブ・ペアプログラミング向けに、複数のデーモンでフェーズを揃える。
+//! リーダーのデーモンはTCPで待ち受け、デフォルトタイマーの状態を接続中のフォロワーに送り続ける。
+//! フォロワーのデーモンは受け取った状態を自分のデフォルトタイマーに反映し、
+//! 通知・サウンド・フックをリーダーと同じタイミングで発火する。
+//! リーダーとの接続が切れた場合、フォロワーはその時点の状態から単独でカウントダウンを続け、
+//! 一定間隔で再接続を試みる。
+//!
+//! メッセージは1行1JSON（`types::framing`）で、認証は行わないため
+//! 信頼できるネットワーク（`127.0.0.1` やLAN内）でのみ使用すること。
+//!
+//! 設定ファイル (`~/.pomodoro/team.json`) または `daemon --lead` / `--follow` で有効化しない限り起動しない。
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tokio::io::BufReader;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, Mutex};
+use tokio::task::JoinSet;
+use tokio::time::{timeout, Duration};
+
+use crate::daemon::{TimerEngine, TimerHandle};
+use crate::types::framing::{read_frame, write_frame, DEFAULT_MAX_MESSAGE_SIZE};
+use crate::types::TimerState;
+
+/// デフォルトのポート番号
+pub const DEFAULT_TEAM_PORT: u16 = 7374;
+
+/// リーダーが状態を送る最大間隔（変化がなくても送るハートビート）
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// リーダーへの接続のタイムアウト
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// この時間リーダーから何も届かなければ接続が切れたとみなす
+const DEFAULT_LEADER_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// リーダーへの再接続の間隔
+const DEFAULT_RECONNECT_INTERVAL: Duration = Duration::from_secs(5);
+
+// ============================================================================
+// Config
+// ============================================================================
+
+/// チームタイマーでの役割
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TeamMode {
+    /// チームタイマーを使用しない
+    #[default]
+    Off,
+    /// フェーズを配信するリーダー
+    Leader,
+    /// リーダーのフェーズに追従するフォロワー
+    Follower,
+}
+
+/// チームタイマー設定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamConfig {
+    /// 役割（デフォルト: 無効）
+    #[serde(default)]
+    pub mode: TeamMode,
+    /// リーダーとして待ち受けるアドレス
+    #[serde(default = "default_listen")]
+    pub listen: SocketAddr,
+    /// フォロワーとして追従するリーダーのアドレス（`host:port`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leader: Option<String>,
+}
+
+fn default_listen() -> SocketAddr {
+    SocketAddr::from((Ipv4Addr::LOCALHOST, DEFAULT_TEAM_PORT))
+}
+
+impl Default for TeamConfig {
+    fn default() -> Self {
+        Self {
+            mode: TeamMode::Off,
+            listen: default_listen(),
+            leader: None,
+        }
+    }
+}
+
+impl TeamConfig {
+    /// デフォルトの設定ファイルパス（データディレクトリの `team.json`）を取得
+    fn get_config_path() -> Option<PathBuf> {
+        crate::paths::data_file("team.json")
+    }
+
+    /// 設定ファイルから読み込む
+    /// ファイルが存在しない場合はデフォルト値（無効）を返す
+    pub fn load() -> Result<Self> {
+        match Self::get_config_path() {
+            Some(path) => Self::load_from_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// 指定されたパスから読み込む
+    pub fn load_from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).context("Failed to read team config")?;
+        serde_json::from_str(&content).context("Failed to parse team config")
+    }
+
+    /// 指定されたパスに保存する
+    pub fn save_to_file(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// リーダーとして起動するように上書き（`daemon --lead`）
+    pub fn lead(mut self, listen: SocketAddr) -> Self {
+        self.mode = TeamMode::Leader;
+        self.listen = listen;
+        self
+    }
+
+    /// フォロワーとして起動するように上書き（`daemon --follow`）
+    pub fn follow(mut self, leader: impl Into<String>) -> Self {
+        self.mode = TeamMode::Follower;
+        self.leader = Some(leader.into());
+        self
+    }
+}
+
+// ============================================================================
+// Protocol
+// ============================================================================
+
+/// リーダーからフォロワーへのメッセージ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TeamMessage {
+    /// リーダーのデフォルトタイマーの現在状態
+    State { state: TimerState },
+}
+
+/// メッセージを1フレームとして送信
+async fn send_message(stream: &mut TcpStream, message: &TeamMessage) -> Result<()> {
+    let payload = serde_json::to_vec(message).context("Failed to serialize team message")?;
+    write_frame(stream, &payload, DEFAULT_MAX_MESSAGE_SIZE)
+        .await
+        .context("Failed to send team message")
+}
+
+// ============================================================================
+// Leader
+// ============================================================================
+
+/// チームタイマーのリーダー
+pub struct TeamLeader {
+    listener: TcpListener,
+}
+
+impl TeamLeader {
+    /// 指定アドレスで待ち受けを開始
+    pub async fn bind(addr: SocketAddr) -> Result<Self> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .with_context(|| format!("Failed to bind team leader to {}", addr))?;
+        Ok(Self { listener })
+    }
+
+    /// 待ち受けているアドレスを取得
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// フォロワーの接続を受け付け、タイマーの状態を配信する（戻らない）
+    ///
+    /// フォロワーごとに、接続直後・タイマーのイベントごと・ハートビート間隔ごとに状態を送る。
+    /// このタスクを中断するとフォロワーとの接続もすべて閉じる。
+    pub async fn run(self, timer: TimerHandle) -> Result<()> {
+        let mut connections = JoinSet::new();
+        loop {
+            tokio::select! {
+                accepted = self.listener.accept() => {
+                    let (stream, peer) = accepted?;
+                    let timer = timer.clone();
+                    connections.spawn(async move {
+                        if let Err(e) = serve_follower(stream, timer).await {
+                            eprintln!("Team follower {} disconnected: {}", peer, e);
+                        }
+                    });
+                }
+                // 終了した接続を回収する
+                Some(_) = connections.join_next() => {}
+            }
+        }
+    }
+}
+
+/// 1台のフォロワーに状態を送り続ける
+///
+/// フォロワーが切断した場合（送信に失敗した場合）に戻る。
+async fn serve_follower(mut stream: TcpStream, timer: TimerHandle) -> Result<()> {
+    // 状態を読む前に購読し、取りこぼしを防ぐ
+    let mut events = timer.events().subscribe();
+    let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+
+    loop {
+        let state = timer.engine().lock().await.get_state().clone();
+        send_message(&mut stream, &TeamMessage::State { state }).await?;
+
+        tokio::select! {
+            result = events.recv() => match result {
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+            _ = heartbeat.tick() => {}
+        }
+    }
+}
+
+// ============================================================================
+// Follower
+// ============================================================================
+
+/// チームタイマーのフォロワー
+#[derive(Debug, Clone)]
+pub struct TeamFollower {
+    /// リーダーのアドレス
+    leader: String,
+    /// 再接続の間隔
+    reconnect_interval: Duration,
+    /// 接続が切れたとみなすまでの時間
+    leader_timeout: Duration,
+}
+
+impl TeamFollower {
+    /// 新しいTeamFollowerを作成
+    pub fn new(leader: impl Into<String>) -> Self {
+        Self {
+            leader: leader.into(),
+            reconnect_interval: DEFAULT_RECONNECT_INTERVAL,
+            leader_timeout: DEFAULT_LEADER_TIMEOUT,
+        }
+    }
+
+    /// 再接続の間隔と、接続が切れたとみなすまでの時間を指定
+    pub fn with_intervals(
+        mut self,
+        reconnect_interval: Duration,
+        leader_timeout: Duration,
+    ) -> Self {
+        self.reconnect_interval = reconnect_interval;
+        self.leader_timeout = leader_timeout;
+        self
+    }
+
+    /// リーダーに接続してタイマーを追従させる（戻らない）
+    ///
+    /// 接続が切れた場合は追従をやめてタイマーを単独で動かし、再接続を試み続ける。
+    pub async fn run(self, engine: Arc<Mutex<TimerEngine>>) {
+        // 接続できない状態が続く間は、最初の1回だけ報告する
+        let mut unavailable_reported = false;
+        loop {
+            match self.follow_once(&engine).await {
+                Ok(()) => {
+                    eprintln!("Lost team leader at {}; continuing standalone", self.leader);
+                    unavailable_reported = false;
+                }
+                Err(e) if !unavailable_reported => {
+                    eprintln!("Team leader at {} unavailable: {}", self.leader, e);
+                    unavailable_reported = true;
+                }
+                Err(_) => {}
+            }
+            engine.lock().await.set_following(false);
+            tokio::time::sleep(self.reconnect_interval).await;
+        }
+    }
+
+    /// 1回分の接続でリーダーに追従する
+    ///
+    /// 状態を受け取っている間は戻らず、接続が閉じられたかタイムアウトした場合に `Ok` を返す。
+    async fn follow_once(&self, engine: &Arc<Mutex<TimerEngine>>) -> Result<()> {
+        let stream = timeout(CONNECT_TIMEOUT, TcpStream::connect(&self.leader))
+            .await
+            .context("Connection timed out")?
+            .context("Failed to connect")?;
+        let mut reader = BufReader::new(stream);
+        let mut following = false;
+
+        loop {
+            let frame = match timeout(
+                self.leader_timeout,
+                read_frame(&mut reader, DEFAULT_MAX_MESSAGE_SIZE),
+            )
+            .await
+            {
+                Ok(Ok(Some(frame))) => frame,
+                Ok(Ok(None)) | Err(_) => return Ok(()),
+                Ok(Err(e)) => return Err(e.into()),
+            };
+            let TeamMessage::State { state } =
+                serde_json::from_slice(&frame).context("Invalid team message")?;
+
+            let mut engine = engine.lock().await;
+            if !following {
+                engine.set_following(true);
+                following = true;
+                println!("Following team leader at {}", self.leader);
+            }
+            engine.sync_to(&state)?;
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PomodoroConfig, StartParams, TimerEvent, TimerPhase};
+    use tempfile::tempdir;
+    use tokio::sync::mpsc;
+
+    fn create_timer() -> (TimerHandle, mpsc::UnboundedReceiver<TimerEvent>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let engine = TimerEngine::new_without_hooks(PomodoroConfig::default(), tx);
+        let (events, _) = broadcast::channel(16);
+        (TimerHandle::new(Arc::new(Mutex::new(engine)), events), rx)
+    }
+
+    async fn wait_for(engine: &Arc<Mutex<TimerEngine>>, f: impl Fn(&TimerEngine) -> bool) {
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+        while !f(&*engine.lock().await) {
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "condition not met in time"
+            );
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[test]
+    fn test_team_config_default() {
+        let config = TeamConfig::default();
+        assert_eq!(config.mode, TeamMode::Off);
+        assert_eq!(config.listen.to_string(), "127.0.0.1:7374");
+        assert!(config.leader.is_none());
+    }
+
+    #[test]
+    fn test_team_config_load_missing_file() {
+        let dir = tempdir().unwrap();
+        let config = TeamConfig::load_from_file(&dir.path().join("team.json")).unwrap();
+        assert_eq!(config, TeamConfig::default());
+    }
+
+    #[test]
+    fn test_team_config_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("team.json");
+        let config = TeamConfig::default().follow("192.168.1.10:7374");
+
+        config.save_to_file(&path).unwrap();
+
+        assert_eq!(TeamConfig::load_from_file(&path).unwrap(), config);
+    }
+
+    #[test]
+    fn test_team_config_parse() {
+        let config: TeamConfig = serde_json::from_str(r#"{"mode": "leader"}"#).unwrap();
+        assert_eq!(config.mode, TeamMode::Leader);
+        assert_eq!(config.listen, default_listen());
+
+        assert!(serde_json::from_str::<TeamConfig>(r#"{"mode": "captain"}"#).is_err());
+    }
+
+    #[test]
+    fn test_team_config_overrides() {
+        let addr: SocketAddr = "0.0.0.0:9000".parse().unwrap();
+        let config = TeamConfig::default().lead(addr);
+        assert_eq!(config.mode, TeamMode::Leader);
+        assert_eq!(config.listen, addr);
+    }
+
+    #[test]
+    fn test_team_message_format() {
+        let state = TimerState::new(PomodoroConfig::default());
+        let json = serde_json::to_value(TeamMessage::State { state }).unwrap();
+        assert_eq!(json["type"], "state");
+        assert_eq!(json["state"]["phase"], "stopped");
+    }
+
+    #[tokio::test]
+    async fn test_follower_mirrors_leader() {
+        let (leader_timer, _leader_rx) = create_timer();
+        let leader = TeamLeader::bind("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let addr = leader.local_addr().unwrap();
+        let leader_task = tokio::spawn(leader.run(leader_timer.clone()));
+
+        let (follower_timer, _follower_rx) = create_timer();
+        let follower_engine = follower_timer.engine().clone();
+        let follower_task =
+            tokio::spawn(TeamFollower::new(addr.to_string()).run(follower_engine.clone()));
+
+        wait_for(&follower_engine, |e| e.is_following()).await;
+
+        leader_timer
+            .engine()
+            .lock()
+            .await
+            .start(&StartParams::default())
+            .unwrap();
+        let _ = leader_timer
+            .events()
+            .send(TimerEvent::WorkStarted { task_name: None });
+
+        wait_for(&follower_engine, |e| {
+            e.get_state().phase == TimerPhase::Working
+        })
+        .await;
+
+        leader_task.abort();
+        follower_task.abort();
+    }
+
+    #[tokio::test]
+    async fn test_follower_continues_standalone_when_leader_disappears() {
+        let (leader_timer, _leader_rx) = create_timer();
+        leader_timer
+            .engine()
+            .lock()
+            .await
+            .start(&StartParams::default())
+            .unwrap();
+        let leader = TeamLeader::bind("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let addr = leader.local_addr().unwrap();
+        let leader_task = tokio::spawn(leader.run(leader_timer.clone()));
+
+        let (follower_timer, _follower_rx) = create_timer();
+        let follower_engine = follower_timer.engine().clone();
+        let follower = TeamFollower::new(addr.to_string())
+            .with_intervals(Duration::from_secs(60), Duration::from_millis(200));
+        let follower_task = tokio::spawn(follower.run(follower_engine.clone()));
+
+        wait_for(&follower_engine, |e| e.is_following()).await;
+
+        // リーダーが応答しなくなったら単独で動作を続ける
+        leader_task.abort();
+        wait_for(&follower_engine, |e| !e.is_following()).await;
+
+        let mut engine = follower_engine.lock().await;
+        assert_eq!(engine.get_state().phase, TimerPhase::Working);
+        assert!(engine.process_tick().unwrap());
+        drop(engine);
+
+        follower_task.abort();
+    }
+}
@@ -7,39 +7,18 @@ use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use chrono::Utc;
-use tokio::sync::mpsc;
-use tokio::time::{interval, Duration, Interval, MissedTickBehavior};
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::{interval, Duration, Instant, Interval, MissedTickBehavior};
 use uuid::Uuid;
 
-use crate::hooks::{HookContext, HookExecutor};
-use crate::types::{HookEvent, PomodoroConfig, StartParams, TimerPhase, TimerState};
+use crate::hooks::executor::MAX_PRE_HOOK_TOTAL_SECS;
+use crate::hooks::{HookContext, HookDirectiveRequest, HookExecutor};
+use crate::types::{ErrorCode, HookEvent, PomodoroConfig, StartParams, TimerPhase, TimerState};
 
-/// タイマーイベント
-///
-/// タイマーエンジンが発火するイベント。
-/// 通知システム、サウンド再生、メニューバーUI更新などに使用。
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum TimerEvent {
-    /// 作業開始
-    WorkStarted { task_name: Option<String> },
-    /// 作業完了
-    WorkCompleted {
-        pomodoro_count: u32,
-        task_name: Option<String>,
-    },
-    /// 休憩開始
-    BreakStarted { is_long_break: bool },
-    /// 休憩完了
-    BreakCompleted { is_long_break: bool },
-    /// 一時停止
-    Paused,
-    /// 再開
-    Resumed,
-    /// 停止
-    Stopped,
-    /// ティック（1秒経過）
-    Tick { remaining_seconds: u32 },
-}
+pub use crate::types::TimerEvent;
+
+/// 事前フックがフェーズの完了を拒否した後、再び実行するまでの秒数
+pub const PRE_HOOK_RETRY_SECS: u32 = 30;
 
 /// タイマーエンジン
 ///
@@ -74,6 +53,57 @@ pub struct TimerEngine {
     hook_executor: Option<Arc<HookExecutor>>,
     /// セッションID（フック実行時に使用）
     session_id: Uuid,
+    /// チームタイマーのリーダーに追従中か（追従中はローカルの操作とティックを無効にする）
+    following: bool,
+    /// 状態の世代（フェーズや一時停止が変わるたびに増やす）
+    generation: u64,
+    /// 事前フックの結果を待っているフェーズの完了
+    pending_completion: Option<PendingCompletion>,
+}
+
+/// ティックによるフェーズの完了の事前フックの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PendingCompletion {
+    /// 事前フックの実行待ち（`take_completion_check` で取り出す）
+    Ready,
+    /// 事前フックを実行中
+    Running,
+    /// 事前フックが拒否した（残りのティック数が0になったら再び実行する）
+    Rejected { retry_in: u32 },
+}
+
+/// ロックの外で実行する事前フック
+///
+/// エンジンのロック中に作成し、ロックを解放してから `run` で事前フックを実行する。
+/// 作成時の状態の世代を保持しており、遷移を適用する前に状態が変わっていないかを確認する。
+pub struct PreHookCheck {
+    hook_executor: Option<Arc<HookExecutor>>,
+    contexts: Vec<HookContext>,
+    generation: u64,
+}
+
+impl PreHookCheck {
+    /// 事前フックを順に実行し、フックの指示を返す（拒否された場合は `HookRejected` エラー）
+    ///
+    /// 実行時間はすべてのイベントの事前フックの合計で `MAX_PRE_HOOK_TOTAL_SECS` までに制限する。
+    /// 返した指示は、遷移を適用した後に同じロックの中で `apply_pre_hook_directives` に渡す。
+    pub async fn run(&self) -> Result<Vec<HookDirectiveRequest>> {
+        let mut requests = Vec::new();
+        if let Some(ref executor) = self.hook_executor {
+            let deadline = Instant::now() + Duration::from_secs(MAX_PRE_HOOK_TOTAL_SECS);
+            for context in &self.contexts {
+                let directives =
+                    executor
+                        .run_pre_hooks(context, deadline)
+                        .await
+                        .map_err(|message| {
+                            anyhow::Error::from(ErrorCode::HookRejected).context(message)
+                        })?;
+                requests.extend(directives);
+            }
+        }
+        Ok(requests)
+    }
 }
 
 impl TimerEngine {
@@ -84,6 +114,9 @@ impl TimerEngine {
             event_tx,
             hook_executor: Some(Arc::new(HookExecutor::new())),
             session_id: Uuid::new_v4(),
+            following: false,
+            generation: 0,
+            pending_completion: None,
         }
     }
 
@@ -97,6 +130,9 @@ impl TimerEngine {
             event_tx,
             hook_executor: None,
             session_id: Uuid::new_v4(),
+            following: false,
+            generation: 0,
+            pending_completion: None,
         }
     }
 
@@ -110,6 +146,9 @@ impl TimerEngine {
             event_tx,
             hook_executor: Some(hook_executor),
             session_id: Uuid::new_v4(),
+            following: false,
+            generation: 0,
+            pending_completion: None,
         }
     }
     /// タイマー用のIntervalを作成
@@ -144,17 +183,50 @@ impl TimerEngine {
             total_cycles: 4,
             timestamp: Utc::now(),
             session_id: self.session_id,
+            config: self.state.config.clone(),
+        }
+    }
+
+    /// チームタイマーのリーダーへの追従を設定
+    ///
+    /// 追従中は `start`・`pause` などのローカル操作を `FollowingLeader` エラーで拒否し、
+    /// ティックによるカウントダウンも行わない（状態は `sync_to` で受け取る）。
+    /// 追従をやめると、その時点の状態からローカルでカウントダウンを続ける。
+    pub fn set_following(&mut self, following: bool) {
+        self.following = following;
+    }
+
+    /// 状態の世代を進める（事前フックの実行中の状態変化を検出するため）
+    ///
+    /// 事前フックの結果を待っているフェーズの完了も取り消す。
+    fn mark_changed(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        self.pending_completion = None;
+    }
+
+    /// チームタイマーのリーダーに追従中か
+    pub fn is_following(&self) -> bool {
+        self.following
+    }
+
+    /// ローカル操作が可能か確認（追従中はエラー）
+    fn ensure_not_following(&self) -> Result<()> {
+        if self.following {
+            return Err(ErrorCode::FollowingLeader.into());
         }
+        Ok(())
     }
 
     /// タイマーを開始
     pub fn start(&mut self, params: &StartParams) -> Result<()> {
+        self.ensure_not_following()?;
         if self.state.is_running() {
-            anyhow::bail!("タイマーは既に実行中です");
+            return Err(ErrorCode::AlreadyRunning.into());
         }
 
         self.state.config.update_from_params(params);
         self.state.start_working(params.task_name.clone());
+        self.mark_changed();
 
         self.event_tx
             .send(TimerEvent::WorkStarted {
@@ -169,11 +241,13 @@ impl TimerEngine {
 
     /// タイマーを一時停止
     pub fn pause(&mut self) -> Result<()> {
+        self.ensure_not_following()?;
         if !self.state.is_running() {
-            anyhow::bail!("タイマーは実行されていません");
+            return Err(ErrorCode::NotRunning.into());
         }
 
         self.state.pause();
+        self.mark_changed();
 
         self.event_tx
             .send(TimerEvent::Paused)
@@ -186,11 +260,13 @@ impl TimerEngine {
 
     /// タイマーを再開
     pub fn resume(&mut self) -> Result<()> {
+        self.ensure_not_following()?;
         if !self.state.is_paused() {
-            anyhow::bail!("タイマーは一時停止していません");
+            return Err(ErrorCode::NotPaused.into());
         }
 
         self.state.resume();
+        self.mark_changed();
 
         self.event_tx
             .send(TimerEvent::Resumed)
@@ -203,13 +279,15 @@ impl TimerEngine {
 
     /// タイマーを停止
     pub fn stop(&mut self) -> Result<()> {
+        self.ensure_not_following()?;
         if !self.state.is_running() && !self.state.is_paused() {
-            anyhow::bail!("タイマーは実行されていません");
+            return Err(ErrorCode::NotRunning.into());
         }
 
         self.fire_hook(HookEvent::Stop);
 
         self.state.stop();
+        self.mark_changed();
 
         self.event_tx
             .send(TimerEvent::Stopped)
@@ -218,6 +296,211 @@ impl TimerEngine {
         Ok(())
     }
 
+    /// 現在のフェーズをスキップ
+    ///
+    /// 作業中の場合はポモドーロを完了扱いにせず休憩へ、休憩中の場合は作業へ移る。
+    /// 休憩のスキップは自動サイクルの設定に関わらず作業を開始する。
+    pub fn skip(&mut self) -> Result<()> {
+        self.ensure_not_following()?;
+        if self.state.is_running() {
+            self.mark_changed();
+        }
+        match self.state.phase {
+            TimerPhase::Working => {
+                self.state.start_breaking();
+
+                let is_long_break = self.state.phase == TimerPhase::LongBreaking;
+                let break_start_event = if is_long_break {
+                    HookEvent::LongBreakStart
+                } else {
+                    HookEvent::BreakStart
+                };
+                self.fire_hook(break_start_event);
+
+                self.event_tx
+                    .send(TimerEvent::BreakStarted { is_long_break })
+                    .context("Failed to send break started event")?;
+            }
+            TimerPhase::Breaking | TimerPhase::LongBreaking => {
+                let is_long_break = self.state.phase == TimerPhase::LongBreaking;
+                let break_end_event = if is_long_break {
+                    HookEvent::LongBreakEnd
+                } else {
+                    HookEvent::BreakEnd
+                };
+                self.fire_hook(break_end_event);
+
+                self.event_tx
+                    .send(TimerEvent::BreakCompleted { is_long_break })
+                    .context("Failed to send break completed event")?;
+
+                let task_name = self.state.task_name.clone();
+                self.state.start_working(task_name.clone());
+
+                self.fire_hook(HookEvent::WorkStart);
+
+                self.event_tx
+                    .send(TimerEvent::WorkStarted { task_name })
+                    .context("Failed to send work started event")?;
+            }
+            _ => return Err(ErrorCode::NotRunning.into()),
+        }
+
+        Ok(())
+    }
+
+    /// タスク名を変更する（フックの指示で使用）
+    pub fn set_task_name(&mut self, task_name: Option<String>) -> Result<()> {
+        self.ensure_not_following()?;
+        self.state.task_name = task_name;
+        Ok(())
+    }
+
+    /// 現在のフェーズの残り時間を延長する（フックの指示で使用）
+    ///
+    /// 一時停止中の場合は再開後のフェーズの残り時間を延長する。
+    pub fn extend(&mut self, secs: u32) -> Result<()> {
+        self.ensure_not_following()?;
+        if !self.state.is_running() && !self.state.is_paused() {
+            return Err(ErrorCode::NotRunning.into());
+        }
+        self.state.extend(secs);
+        self.mark_changed();
+        Ok(())
+    }
+
+    /// `start` の事前フックを準備
+    ///
+    /// 開始できる状態かを確認し、`before_work_start` フックのコンテキストを作成する。
+    pub fn prepare_start(&self, params: &StartParams) -> Result<PreHookCheck> {
+        self.ensure_not_following()?;
+        if self.state.is_running() {
+            return Err(ErrorCode::AlreadyRunning.into());
+        }
+
+        let mut context = self.build_hook_context(HookEvent::BeforeWorkStart);
+        context.task_name = params.task_name.clone();
+        Ok(self.pre_hook_check(vec![context]))
+    }
+
+    /// `skip` の事前フックを準備
+    ///
+    /// 作業中は `before_break_start`（または `before_long_break_start`）、
+    /// 休憩中は `before_break_end`（または `before_long_break_end`）と `before_work_start` のコンテキストを作成する。
+    pub fn prepare_skip(&self) -> Result<PreHookCheck> {
+        self.ensure_not_following()?;
+        let events: &[HookEvent] = match self.state.phase {
+            TimerPhase::Working if self.state.next_break_is_long() => {
+                &[HookEvent::BeforeLongBreakStart]
+            }
+            TimerPhase::Working => &[HookEvent::BeforeBreakStart],
+            TimerPhase::Breaking => &[HookEvent::BeforeBreakEnd, HookEvent::BeforeWorkStart],
+            TimerPhase::LongBreaking => {
+                &[HookEvent::BeforeLongBreakEnd, HookEvent::BeforeWorkStart]
+            }
+            _ => return Err(ErrorCode::NotRunning.into()),
+        };
+        let contexts = events
+            .iter()
+            .map(|event| self.build_hook_context(*event))
+            .collect();
+        Ok(self.pre_hook_check(contexts))
+    }
+
+    fn pre_hook_check(&self, contexts: Vec<HookContext>) -> PreHookCheck {
+        PreHookCheck {
+            hook_executor: self.hook_executor.clone(),
+            contexts,
+            generation: self.generation,
+        }
+    }
+
+    /// 事前フックの準備後に状態が変わっていないか確認（変わった場合は `StateChanged` エラー）
+    pub fn ensure_unchanged(&self, check: &PreHookCheck) -> Result<()> {
+        if self.generation != check.generation {
+            return Err(ErrorCode::StateChanged.into());
+        }
+        Ok(())
+    }
+
+    /// 事前フックを実行してからタイマーを開始
+    ///
+    /// 事前フックはエンジンのロックを解放して実行するため、実行中もティックやほかの操作は止まらない。
+    /// `before_work_start` フックが拒否した場合は開始せず、`HookRejected` エラーを返す。
+    /// フックの実行中にタイマーが開始された場合は `AlreadyRunning` エラーを返す。
+    pub async fn start_with_pre_hooks(engine: &Mutex<Self>, params: &StartParams) -> Result<()> {
+        let check = engine.lock().await.prepare_start(params)?;
+        let directives = check.run().await?;
+        let mut engine = engine.lock().await;
+        engine.start(params)?;
+        engine.apply_pre_hook_directives(directives);
+        Ok(())
+    }
+
+    /// 事前フックを実行してから現在のフェーズをスキップ
+    ///
+    /// 事前フックはエンジンのロックを解放して実行する。いずれかが拒否した場合はスキップせず、
+    /// `HookRejected` エラーを返す。フックの実行中にフェーズの完了や一時停止などで状態が
+    /// 変わった場合は、フックが確認した遷移と異なるためスキップせず、`StateChanged` エラーを返す。
+    pub async fn skip_with_pre_hooks(engine: &Mutex<Self>) -> Result<()> {
+        let check = engine.lock().await.prepare_skip()?;
+        let directives = check.run().await?;
+        let mut engine = engine.lock().await;
+        engine.ensure_unchanged(&check)?;
+        engine.skip()?;
+        engine.apply_pre_hook_directives(directives);
+        Ok(())
+    }
+
+    /// 事前フックの指示のタスク名・タグを適用する
+    ///
+    /// 遷移を適用した直後に同じロックの中で呼び出すため、`start` で指定したタスク名より
+    /// フックの指示が優先される。通知などの残りの指示は、ほかのフックの指示と同じくデーモンに送る。
+    fn apply_pre_hook_directives(&mut self, requests: Vec<HookDirectiveRequest>) {
+        for mut request in requests {
+            let directive = &mut request.directive;
+            if let Some(task_name) = directive.task_name_after(self.state.task_name.as_deref()) {
+                // 遷移を適用できた時点で追従中ではないため、set_task_name を経由しない
+                self.state.task_name = Some(task_name);
+            }
+            directive.task_name = None;
+            directive.add_tags.clear();
+            if directive.notify.is_some() || directive.changes_timer() {
+                if let Some(ref executor) = self.hook_executor {
+                    executor.send_directive(request);
+                }
+            }
+        }
+    }
+
+    /// デーモン終了時の後処理
+    ///
+    /// 実行中または一時停止中のタイマーを停止し、stopフックを発火する。
+    /// 停止中の場合は何もしない。
+    ///
+    /// # Returns
+    ///
+    /// タイマーを停止した場合はtrue
+    pub fn shutdown(&mut self) -> Result<bool> {
+        // 終了時はリーダーへの追従をやめてローカルで停止する
+        self.following = false;
+        if !self.state.is_running() && !self.state.is_paused() {
+            return Ok(false);
+        }
+        self.stop()?;
+        Ok(true)
+    }
+
+    /// フック実行機能を取得（終了時に実行中のフックを待つために使用）
+    pub fn hook_executor(&self) -> Option<Arc<HookExecutor>> {
+        self.hook_executor.clone()
+    }
+
+    /// セッションID（フックのコンテキストに渡すもの。エンジンごとに一意）
+    pub fn session_id(&self) -> Uuid {
+        self.session_id
+    }
+
     /// 現在の状態を取得
     pub fn get_state(&self) -> &TimerState {
         &self.state
@@ -226,18 +509,34 @@ impl TimerEngine {
     /// 1ティック（1秒）を処理
     ///
     /// タイマーが実行中の場合、残り時間を1秒減らし、Tickイベントを送信する。
-    /// タイマーが完了した場合、フェーズ遷移を行う。
+    /// タイマーが完了した場合、フェーズ遷移を行う。ただし遷移先の事前フック
+    /// （`before_break_start` など）が登録されている場合は残り時間0のままフェーズを保持し、
+    /// `take_completion_check` で取り出した事前フックの結果を待つ。
     ///
     /// # 戻り値
     ///
     /// - `Ok(true)`: タイマーが実行中でティックを処理した
-    /// - `Ok(false)`: タイマーが実行中ではない（停止中または一時停止中）
+    /// - `Ok(false)`: タイマーが実行中ではない（停止中・一時停止中・リーダーに追従中）
     /// - `Err(...)`: イベント送信に失敗
     pub fn process_tick(&mut self) -> Result<bool> {
-        if !self.state.is_running() {
+        if self.following || !self.state.is_running() {
             return Ok(false);
         }
 
+        // 事前フックの結果待ちの間は残り時間0のまま保持し、拒否された場合は一定時間後に再実行する
+        if let Some(pending) = self.pending_completion {
+            if let PendingCompletion::Rejected { retry_in } = pending {
+                self.pending_completion = Some(if retry_in <= 1 {
+                    PendingCompletion::Ready
+                } else {
+                    PendingCompletion::Rejected {
+                        retry_in: retry_in - 1,
+                    }
+                });
+            }
+            return Ok(true);
+        }
+
         let completed = self.state.tick();
 
         // Tickイベントを送信
@@ -248,14 +547,210 @@ impl TimerEngine {
             .context("Failed to send tick event")?;
 
         if completed {
-            self.handle_timer_complete()?;
+            if self.completion_pre_hook_events().is_empty() {
+                self.handle_timer_complete()?;
+            } else {
+                self.pending_completion = Some(PendingCompletion::Ready);
+            }
         }
 
         Ok(true)
     }
 
+    /// フェーズの完了時に実行する事前フックのイベント（フックが登録されているもののみ）
+    fn completion_pre_hook_events(&self) -> Vec<HookEvent> {
+        let Some(ref executor) = self.hook_executor else {
+            return Vec::new();
+        };
+        let auto_cycle = self.state.config.auto_cycle;
+        let events: &[HookEvent] = match self.state.phase {
+            // 完了したポモドーロを数えた後の休憩が長い休憩になるか
+            TimerPhase::Working if (self.state.pomodoro_count + 1) % 4 == 0 => {
+                &[HookEvent::BeforeLongBreakStart]
+            }
+            TimerPhase::Working => &[HookEvent::BeforeBreakStart],
+            TimerPhase::Breaking if auto_cycle => {
+                &[HookEvent::BeforeBreakEnd, HookEvent::BeforeWorkStart]
+            }
+            TimerPhase::Breaking => &[HookEvent::BeforeBreakEnd],
+            TimerPhase::LongBreaking if auto_cycle => {
+                &[HookEvent::BeforeLongBreakEnd, HookEvent::BeforeWorkStart]
+            }
+            TimerPhase::LongBreaking => &[HookEvent::BeforeLongBreakEnd],
+            _ => &[],
+        };
+        events
+            .iter()
+            .copied()
+            .filter(|event| executor.has_hooks_for(event))
+            .collect()
+    }
+
+    /// 事前フックの結果を待っているフェーズの完了を取り出す
+    ///
+    /// `process_tick` でフェーズを保持した場合に、実行する事前フックを返す。
+    /// 呼び出し側はロックを解放してから `complete_with_pre_hooks` に渡す。
+    pub fn take_completion_check(&mut self) -> Option<PreHookCheck> {
+        if self.pending_completion != Some(PendingCompletion::Ready) {
+            return None;
+        }
+        self.pending_completion = Some(PendingCompletion::Running);
+        let contexts = self
+            .completion_pre_hook_events()
+            .into_iter()
+            .map(|event| self.build_hook_context(event))
+            .collect();
+        Some(self.pre_hook_check(contexts))
+    }
+
+    /// 事前フックを実行してから、保持していたフェーズを完了する
+    ///
+    /// 事前フックはエンジンのロックを解放して実行する。フックが拒否した場合は
+    /// 残り時間0のままフェーズを保持し、`PRE_HOOK_RETRY_SECS` 秒後に再び実行する
+    /// （拒否の理由を `HookRejected` エラーとして返す）。
+    /// フックの実行中に停止・スキップ・延長などで状態が変わった場合は何もしない。
+    pub async fn complete_with_pre_hooks(engine: &Mutex<Self>, check: PreHookCheck) -> Result<()> {
+        let result = check.run().await;
+        let mut engine = engine.lock().await;
+        if engine.generation != check.generation
+            || engine.pending_completion != Some(PendingCompletion::Running)
+        {
+            return Ok(());
+        }
+        match result {
+            Ok(directives) => {
+                engine.handle_timer_complete()?;
+                engine.apply_pre_hook_directives(directives);
+                Ok(())
+            }
+            Err(e) => {
+                engine.pending_completion = Some(PendingCompletion::Rejected {
+                    retry_in: PRE_HOOK_RETRY_SECS,
+                });
+                Err(e)
+            }
+        }
+    }
+
+    /// リーダーのタイマー状態に同期する
+    ///
+    /// チームタイマーの追従側で、リーダーから受け取った状態を反映する。
+    /// フェーズや一時停止の変化はローカルの操作と同じイベント・フックとして発火するため、
+    /// 通知・サウンド・フックはリーダーと同じタイミングで動作する。
+    /// フェーズが変わらない場合は残り時間だけを合わせ、Tickイベントを送信する。
+    pub fn sync_to(&mut self, remote: &TimerState) -> Result<()> {
+        self.mark_changed();
+        let local_phase = self.state.active_phase();
+        let remote_phase = remote.active_phase();
+
+        if remote_phase == TimerPhase::Stopped {
+            if local_phase != TimerPhase::Stopped {
+                self.fire_hook(HookEvent::Stop);
+                self.state = remote.clone();
+                self.event_tx
+                    .send(TimerEvent::Stopped)
+                    .context("Failed to send stopped event")?;
+            } else {
+                self.state = remote.clone();
+            }
+            return Ok(());
+        }
+
+        let was_paused = self.state.is_paused();
+        let phase_changed =
+            local_phase != remote_phase || self.state.pomodoro_count != remote.pomodoro_count;
+
+        if phase_changed {
+            match local_phase {
+                TimerPhase::Working if remote.pomodoro_count > self.state.pomodoro_count => {
+                    self.state.pomodoro_count = remote.pomodoro_count;
+                    self.fire_hook(HookEvent::WorkEnd);
+                    self.event_tx
+                        .send(TimerEvent::WorkCompleted {
+                            pomodoro_count: remote.pomodoro_count,
+                            task_name: self.state.task_name.clone(),
+                        })
+                        .context("Failed to send work completed event")?;
+                }
+                TimerPhase::Breaking | TimerPhase::LongBreaking => {
+                    let is_long_break = local_phase == TimerPhase::LongBreaking;
+                    self.fire_hook(if is_long_break {
+                        HookEvent::LongBreakEnd
+                    } else {
+                        HookEvent::BreakEnd
+                    });
+                    self.event_tx
+                        .send(TimerEvent::BreakCompleted { is_long_break })
+                        .context("Failed to send break completed event")?;
+                }
+                _ => {}
+            }
+
+            self.state = remote.clone();
+            match remote_phase {
+                TimerPhase::Working => {
+                    self.fire_hook(HookEvent::WorkStart);
+                    self.event_tx
+                        .send(TimerEvent::WorkStarted {
+                            task_name: remote.task_name.clone(),
+                        })
+                        .context("Failed to send work started event")?;
+                }
+                TimerPhase::Breaking | TimerPhase::LongBreaking => {
+                    let is_long_break = remote_phase == TimerPhase::LongBreaking;
+                    self.fire_hook(if is_long_break {
+                        HookEvent::LongBreakStart
+                    } else {
+                        HookEvent::BreakStart
+                    });
+                    self.event_tx
+                        .send(TimerEvent::BreakStarted { is_long_break })
+                        .context("Failed to send break started event")?;
+                }
+                _ => {}
+            }
+
+            if remote.is_paused() {
+                self.fire_hook(HookEvent::Pause);
+                self.event_tx
+                    .send(TimerEvent::Paused)
+                    .context("Failed to send paused event")?;
+            }
+            return Ok(());
+        }
+
+        let remaining_changed = self.state.remaining_seconds != remote.remaining_seconds;
+        self.state = remote.clone();
+
+        match (was_paused, remote.is_paused()) {
+            (false, true) => {
+                self.event_tx
+                    .send(TimerEvent::Paused)
+                    .context("Failed to send paused event")?;
+                self.fire_hook(HookEvent::Pause);
+            }
+            (true, false) => {
+                self.event_tx
+                    .send(TimerEvent::Resumed)
+                    .context("Failed to send resumed event")?;
+                self.fire_hook(HookEvent::Resume);
+            }
+            _ if remaining_changed && remote.is_running() => {
+                self.event_tx
+                    .send(TimerEvent::Tick {
+                        remaining_seconds: remote.remaining_seconds,
+                    })
+                    .context("Failed to send tick event")?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
     /// タイマー完了時の処理
     fn handle_timer_complete(&mut self) -> Result<()> {
+        self.mark_changed();
         match self.state.phase {
             TimerPhase::Working => {
                 self.state.pomodoro_count += 1;
@@ -598,6 +1093,93 @@ mod tests {
         assert_eq!(event, TimerEvent::Stopped);
     }
 
+    #[test]
+    fn test_timer_engine_skip_work() {
+        let (mut engine, mut rx) = create_test_engine();
+
+        engine.start(&StartParams::default()).unwrap();
+        rx.try_recv().unwrap(); // consume WorkStarted
+
+        engine.skip().unwrap();
+
+        let state = engine.get_state();
+        assert_eq!(state.phase, TimerPhase::Breaking);
+        // スキップした作業はポモドーロとして数えない
+        assert_eq!(state.pomodoro_count, 0);
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            TimerEvent::BreakStarted {
+                is_long_break: false
+            }
+        );
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_timer_engine_skip_break_starts_work() {
+        let (mut engine, mut rx) = create_test_engine();
+
+        let params = StartParams {
+            task_name: Some("タスク".to_string()),
+            ..Default::default()
+        };
+        engine.start(&params).unwrap();
+        engine.skip().unwrap();
+        while rx.try_recv().is_ok() {}
+
+        engine.skip().unwrap();
+
+        let state = engine.get_state();
+        assert_eq!(state.phase, TimerPhase::Working);
+        assert_eq!(state.task_name, Some("タスク".to_string()));
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            TimerEvent::BreakCompleted {
+                is_long_break: false
+            }
+        );
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            TimerEvent::WorkStarted {
+                task_name: Some("タスク".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_timer_engine_skip_when_not_running() {
+        let (mut engine, _rx) = create_test_engine();
+
+        let result = engine.skip();
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().downcast_ref::<ErrorCode>(),
+            Some(&ErrorCode::NotRunning)
+        );
+    }
+
+    #[test]
+    fn test_timer_engine_shutdown_stops_running_timer() {
+        let (mut engine, mut rx) = create_test_engine();
+
+        engine.start(&StartParams::default()).unwrap();
+        rx.try_recv().unwrap(); // consume WorkStarted
+
+        assert!(engine.shutdown().unwrap());
+        assert_eq!(engine.get_state().phase, TimerPhase::Stopped);
+        assert_eq!(rx.try_recv().unwrap(), TimerEvent::Stopped);
+    }
+
+    #[test]
+    fn test_timer_engine_shutdown_when_stopped() {
+        let (mut engine, mut rx) = create_test_engine();
+
+        assert!(!engine.shutdown().unwrap());
+        assert!(rx.try_recv().is_err());
+        assert!(engine.hook_executor().is_none());
+    }
+
     #[test]
     fn test_timer_engine_stop_when_paused() {
         let (mut engine, mut rx) = create_test_engine();
@@ -1035,4 +1617,633 @@ mod tests {
         // Process tick while stopped - should return false
         assert!(!engine.process_tick().unwrap());
     }
+
+    // ------------------------------------------------------------------------
+    // Team Following Tests
+    // ------------------------------------------------------------------------
+
+    fn create_follower() -> (TimerEngine, mpsc::UnboundedReceiver<TimerEvent>) {
+        let (mut engine, rx) = create_test_engine();
+        engine.set_following(true);
+        (engine, rx)
+    }
+
+    fn drain(rx: &mut mpsc::UnboundedReceiver<TimerEvent>) -> Vec<TimerEvent> {
+        let mut events = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            events.push(event);
+        }
+        events
+    }
+
+    #[test]
+    fn test_following_rejects_local_commands() {
+        let (mut engine, _rx) = create_follower();
+
+        let err = engine.start(&StartParams::default()).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ErrorCode>(),
+            Some(&ErrorCode::FollowingLeader)
+        );
+        assert!(engine.pause().is_err());
+        assert!(engine.resume().is_err());
+        assert!(engine.stop().is_err());
+        assert!(engine.skip().is_err());
+    }
+
+    #[test]
+    fn test_following_does_not_tick() {
+        let (mut leader, _leader_rx) = create_test_engine();
+        leader.start(&StartParams::default()).unwrap();
+        let (mut follower, _rx) = create_follower();
+        follower.sync_to(leader.get_state()).unwrap();
+
+        assert!(!follower.process_tick().unwrap());
+        assert_eq!(follower.get_state().remaining_seconds, 25 * 60);
+    }
+
+    #[test]
+    fn test_sync_to_work_started() {
+        let (mut leader, _leader_rx) = create_test_engine();
+        leader
+            .start(&StartParams {
+                task_name: Some("モブ".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        let (mut follower, mut rx) = create_follower();
+
+        follower.sync_to(leader.get_state()).unwrap();
+
+        assert_eq!(follower.get_state().phase, TimerPhase::Working);
+        assert_eq!(
+            drain(&mut rx),
+            vec![TimerEvent::WorkStarted {
+                task_name: Some("モブ".to_string())
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sync_to_tick_updates_remaining() {
+        let (mut leader, _leader_rx) = create_test_engine();
+        leader.start(&StartParams::default()).unwrap();
+        let (mut follower, mut rx) = create_follower();
+        follower.sync_to(leader.get_state()).unwrap();
+        drain(&mut rx);
+
+        leader.process_tick().unwrap();
+        follower.sync_to(leader.get_state()).unwrap();
+        // 同じ状態を再度受け取ってもイベントは発生しない
+        follower.sync_to(leader.get_state()).unwrap();
+
+        assert_eq!(follower.get_state().remaining_seconds, 25 * 60 - 1);
+        assert_eq!(
+            drain(&mut rx),
+            vec![TimerEvent::Tick {
+                remaining_seconds: 25 * 60 - 1
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sync_to_work_completed_starts_break() {
+        let config = PomodoroConfig {
+            work_minutes: 1,
+            ..Default::default()
+        };
+        let (mut leader, _leader_rx) = create_test_engine_with_config(config);
+        leader.start(&StartParams::default()).unwrap();
+        let (mut follower, mut rx) = create_follower();
+        follower.sync_to(leader.get_state()).unwrap();
+        drain(&mut rx);
+
+        for _ in 0..60 {
+            leader.process_tick().unwrap();
+        }
+        follower.sync_to(leader.get_state()).unwrap();
+
+        assert_eq!(follower.get_state().phase, TimerPhase::Breaking);
+        assert_eq!(follower.get_state().pomodoro_count, 1);
+        assert_eq!(
+            drain(&mut rx),
+            vec![
+                TimerEvent::WorkCompleted {
+                    pomodoro_count: 1,
+                    task_name: None
+                },
+                TimerEvent::BreakStarted {
+                    is_long_break: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sync_to_break_completed_starts_work() {
+        let (mut leader, _leader_rx) = create_test_engine();
+        leader.start(&StartParams::default()).unwrap();
+        leader.skip().unwrap();
+        let (mut follower, mut rx) = create_follower();
+        follower.sync_to(leader.get_state()).unwrap();
+        drain(&mut rx);
+
+        leader.skip().unwrap();
+        follower.sync_to(leader.get_state()).unwrap();
+
+        assert_eq!(follower.get_state().phase, TimerPhase::Working);
+        assert_eq!(
+            drain(&mut rx),
+            vec![
+                TimerEvent::BreakCompleted {
+                    is_long_break: false
+                },
+                TimerEvent::WorkStarted { task_name: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sync_to_pause_resume_and_stop() {
+        let (mut leader, _leader_rx) = create_test_engine();
+        leader.start(&StartParams::default()).unwrap();
+        let (mut follower, mut rx) = create_follower();
+        follower.sync_to(leader.get_state()).unwrap();
+        drain(&mut rx);
+
+        leader.pause().unwrap();
+        follower.sync_to(leader.get_state()).unwrap();
+        assert!(follower.get_state().is_paused());
+
+        leader.resume().unwrap();
+        follower.sync_to(leader.get_state()).unwrap();
+        assert_eq!(follower.get_state().phase, TimerPhase::Working);
+
+        leader.stop().unwrap();
+        follower.sync_to(leader.get_state()).unwrap();
+        assert_eq!(follower.get_state().phase, TimerPhase::Stopped);
+
+        assert_eq!(
+            drain(&mut rx),
+            vec![TimerEvent::Paused, TimerEvent::Resumed, TimerEvent::Stopped]
+        );
+    }
+
+    #[test]
+    fn test_sync_to_joins_paused_timer() {
+        let (mut leader, _leader_rx) = create_test_engine();
+        leader.start(&StartParams::default()).unwrap();
+        leader.pause().unwrap();
+        let (mut follower, mut rx) = create_follower();
+
+        follower.sync_to(leader.get_state()).unwrap();
+
+        assert!(follower.get_state().is_paused());
+        assert_eq!(
+            drain(&mut rx),
+            vec![
+                TimerEvent::WorkStarted { task_name: None },
+                TimerEvent::Paused
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stop_following_continues_standalone() {
+        let (mut leader, _leader_rx) = create_test_engine();
+        leader.start(&StartParams::default()).unwrap();
+        let (mut follower, _rx) = create_follower();
+        follower.sync_to(leader.get_state()).unwrap();
+
+        follower.set_following(false);
+
+        assert!(follower.process_tick().unwrap());
+        assert_eq!(follower.get_state().remaining_seconds, 25 * 60 - 1);
+        assert!(follower.pause().is_ok());
+    }
+
+    #[test]
+    fn test_shutdown_while_following_stops_timer() {
+        let (mut leader, _leader_rx) = create_test_engine();
+        leader.start(&StartParams::default()).unwrap();
+        let (mut follower, _rx) = create_follower();
+        follower.sync_to(leader.get_state()).unwrap();
+
+        assert!(follower.shutdown().unwrap());
+        assert!(!follower.is_following());
+        assert_eq!(follower.get_state().phase, TimerPhase::Stopped);
+    }
+
+    // ------------------------------------------------------------------------
+    // Pre-transition Hook Tests
+    // ------------------------------------------------------------------------
+
+    /// 指定したイベントに事前フック（`body` を実行するスクリプト）を登録したエンジンを作成
+    #[cfg(unix)]
+    fn create_engine_with_pre_hook(
+        dir: &std::path::Path,
+        event: &str,
+        body: &str,
+    ) -> (TimerEngine, mpsc::UnboundedReceiver<TimerEvent>) {
+        use crate::hooks::HookConfig;
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = dir.join(format!("{}.sh", event));
+        std::fs::write(&script, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let config = HookConfig::parse_and_validate(&format!(
+            r#"{{"version": "1.0", "hooks": [{{"name": "guard", "event": "{}", "script": "{}"}}]}}"#,
+            event,
+            script.display()
+        ))
+        .unwrap();
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let engine = TimerEngine::new_with_hook_executor(
+            PomodoroConfig::default(),
+            tx,
+            Arc::new(HookExecutor::with_config(config)),
+        );
+        (engine, rx)
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_start_with_pre_hooks_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let (engine, mut rx) = create_engine_with_pre_hook(
+            dir.path(),
+            "before_work_start",
+            "echo \"Slack が起動していません\" >&2\nexit 1",
+        );
+        let engine = Mutex::new(engine);
+
+        let err = TimerEngine::start_with_pre_hooks(&engine, &StartParams::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<ErrorCode>(),
+            Some(&ErrorCode::HookRejected)
+        );
+        assert_eq!(err.to_string(), "Slack が起動していません");
+        assert_eq!(engine.lock().await.get_state().phase, TimerPhase::Stopped);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_start_with_pre_hooks_allowed_receives_task_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let (engine, mut rx) = create_engine_with_pre_hook(
+            dir.path(),
+            "before_work_start",
+            "[ \"$POMODORO_TASK_NAME\" = \"設計\" ]",
+        );
+        let engine = Mutex::new(engine);
+
+        TimerEngine::start_with_pre_hooks(
+            &engine,
+            &StartParams {
+                task_name: Some("設計".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(engine.lock().await.get_state().phase, TimerPhase::Working);
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            TimerEvent::WorkStarted { .. }
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_start_with_pre_hooks_applies_directive_task_name() {
+        use crate::hooks::HookConfig;
+
+        let config = HookConfig::parse_and_validate(
+            r#"{"version": "1.0", "hooks": [{
+                "name": "tracker",
+                "event": "before_work_start",
+                "shell": "echo '{\"pomodoro\": {\"task_name\": \"PROJ-9\", \"add_tags\": [\"review\"], \"notify\": {\"message\": \"取得しました\"}}}'"
+            }]}"#,
+        )
+        .unwrap();
+        let (directive_tx, mut directive_rx) = mpsc::unbounded_channel();
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let engine = Mutex::new(TimerEngine::new_with_hook_executor(
+            PomodoroConfig::default(),
+            tx,
+            Arc::new(HookExecutor::with_config(config).with_directive_sender(directive_tx)),
+        ));
+
+        TimerEngine::start_with_pre_hooks(
+            &engine,
+            &StartParams {
+                task_name: Some("設計".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        // 開始と同じロックの中で適用し、start のタスク名より優先する
+        assert_eq!(
+            engine.lock().await.get_state().task_name.as_deref(),
+            Some("PROJ-9 #review")
+        );
+        // 通知だけをデーモンに送る
+        let request = directive_rx.try_recv().unwrap();
+        assert_eq!(request.event, HookEvent::BeforeWorkStart);
+        assert_eq!(request.directive.task_name, None);
+        assert!(request.directive.add_tags.is_empty());
+        assert_eq!(request.directive.notify.unwrap().message, "取得しました");
+        assert!(directive_rx.try_recv().is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_start_with_pre_hooks_checks_state_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("ran");
+        let (mut engine, _rx) = create_engine_with_pre_hook(
+            dir.path(),
+            "before_work_start",
+            &format!("touch {}", marker.display()),
+        );
+        engine.start(&StartParams::default()).unwrap();
+        let engine = Mutex::new(engine);
+
+        let err = TimerEngine::start_with_pre_hooks(&engine, &StartParams::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<ErrorCode>(),
+            Some(&ErrorCode::AlreadyRunning)
+        );
+        assert!(!marker.exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_skip_with_pre_hooks_rejects_break_start() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut engine, _rx) = create_engine_with_pre_hook(
+            dir.path(),
+            "before_break_start",
+            "echo 'コミットされていない変更があります' >&2\nexit 1",
+        );
+        engine.start(&StartParams::default()).unwrap();
+        let engine = Mutex::new(engine);
+
+        let err = TimerEngine::skip_with_pre_hooks(&engine).await.unwrap_err();
+
+        assert_eq!(err.to_string(), "コミットされていない変更があります");
+        assert_eq!(engine.lock().await.get_state().phase, TimerPhase::Working);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_skip_with_pre_hooks_rejects_break_end() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut engine, _rx) =
+            create_engine_with_pre_hook(dir.path(), "before_break_end", "exit 3");
+        engine.start(&StartParams::default()).unwrap();
+        engine.skip().unwrap();
+        let engine = Mutex::new(engine);
+
+        let err = TimerEngine::skip_with_pre_hooks(&engine).await.unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<ErrorCode>(),
+            Some(&ErrorCode::HookRejected)
+        );
+        assert!(err.to_string().contains("フック 'guard' が失敗しました"));
+        assert_eq!(engine.lock().await.get_state().phase, TimerPhase::Breaking);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_skip_with_pre_hooks_limits_total_time_across_events() {
+        use crate::hooks::HookConfig;
+
+        // 休憩中の skip は before_break_end と before_work_start の両方を実行する
+        let config = HookConfig::parse_and_validate(
+            r#"{"version": "1.0", "hooks": [
+                {"name": "break-end", "event": "before_break_end", "shell": "sleep 3"},
+                {"name": "work-start", "event": "before_work_start", "shell": "sleep 3"}
+            ]}"#,
+        )
+        .unwrap();
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut engine = TimerEngine::new_with_hook_executor(
+            PomodoroConfig::default(),
+            tx,
+            Arc::new(HookExecutor::with_config(config)),
+        );
+        engine.start(&StartParams::default()).unwrap();
+        engine.skip().unwrap();
+        let engine = Mutex::new(engine);
+
+        let started = Instant::now();
+        let err = TimerEngine::skip_with_pre_hooks(&engine).await.unwrap_err();
+
+        assert!(started.elapsed() < Duration::from_secs(MAX_PRE_HOOK_TOTAL_SECS + 1));
+        assert_eq!(
+            err.downcast_ref::<ErrorCode>(),
+            Some(&ErrorCode::HookRejected)
+        );
+        assert!(err
+            .to_string()
+            .contains("フック 'work-start' が失敗しました"));
+        assert_eq!(engine.lock().await.get_state().phase, TimerPhase::Breaking);
+    }
+
+    #[tokio::test]
+    async fn test_skip_with_pre_hooks_without_hooks() {
+        let (engine, _rx) = create_test_engine();
+        let engine = Mutex::new(engine);
+        assert!(TimerEngine::skip_with_pre_hooks(&engine).await.is_err());
+
+        engine.lock().await.start(&StartParams::default()).unwrap();
+        TimerEngine::skip_with_pre_hooks(&engine).await.unwrap();
+
+        assert_eq!(engine.lock().await.get_state().phase, TimerPhase::Breaking);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_pre_hooks_run_without_holding_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut engine, _rx) =
+            create_engine_with_pre_hook(dir.path(), "before_break_start", "sleep 1");
+        engine.start(&StartParams::default()).unwrap();
+        let engine = Arc::new(Mutex::new(engine));
+
+        let skip = tokio::spawn({
+            let engine = engine.clone();
+            async move { TimerEngine::skip_with_pre_hooks(&engine).await }
+        });
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // フックの実行中もエンジンを操作できる
+        let locked = tokio::time::timeout(Duration::from_millis(200), engine.lock()).await;
+        locked
+            .expect("engine is locked during pre-hooks")
+            .pause()
+            .unwrap();
+
+        // フックの確認後に状態が変わったため、スキップは適用しない
+        let err = skip.await.unwrap().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ErrorCode>(),
+            Some(&ErrorCode::StateChanged)
+        );
+        assert_eq!(engine.lock().await.get_state().phase, TimerPhase::Paused);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_tick_completion_rejected_by_pre_hook_keeps_working() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut engine, mut rx) = create_engine_with_pre_hook(
+            dir.path(),
+            "before_break_start",
+            "echo 'コミットされていない変更があります' >&2\nexit 1",
+        );
+        engine.start(&StartParams::default()).unwrap();
+        engine.state.remaining_seconds = 1;
+
+        // 残り時間が0になってもフェーズを保持する
+        engine.process_tick().unwrap();
+        assert_eq!(engine.get_state().phase, TimerPhase::Working);
+        assert_eq!(engine.get_state().remaining_seconds, 0);
+        let check = engine.take_completion_check().unwrap();
+        assert!(engine.take_completion_check().is_none());
+
+        let engine = Mutex::new(engine);
+        let err = TimerEngine::complete_with_pre_hooks(&engine, check)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ErrorCode>(),
+            Some(&ErrorCode::HookRejected)
+        );
+        assert_eq!(err.to_string(), "コミットされていない変更があります");
+
+        let mut engine = engine.into_inner();
+        assert_eq!(engine.get_state().phase, TimerPhase::Working);
+        assert_eq!(engine.get_state().pomodoro_count, 0);
+        while let Ok(event) = rx.try_recv() {
+            assert!(!matches!(event, TimerEvent::WorkCompleted { .. }));
+        }
+
+        // 拒否された場合は一定時間後に再実行する
+        for _ in 1..PRE_HOOK_RETRY_SECS {
+            engine.process_tick().unwrap();
+            assert!(engine.take_completion_check().is_none());
+        }
+        engine.process_tick().unwrap();
+        assert!(engine.take_completion_check().is_some());
+        assert_eq!(engine.get_state().phase, TimerPhase::Working);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_tick_completion_allowed_by_pre_hook() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut engine, _rx) = create_engine_with_pre_hook(
+            dir.path(),
+            "before_break_start",
+            "[ \"$POMODORO_REMAINING_SECS\" = \"0\" ]",
+        );
+        engine.start(&StartParams::default()).unwrap();
+        engine.state.remaining_seconds = 1;
+        engine.process_tick().unwrap();
+        let check = engine.take_completion_check().unwrap();
+
+        let engine = Mutex::new(engine);
+        TimerEngine::complete_with_pre_hooks(&engine, check)
+            .await
+            .unwrap();
+
+        let engine = engine.lock().await;
+        assert_eq!(engine.get_state().phase, TimerPhase::Breaking);
+        assert_eq!(engine.get_state().pomodoro_count, 1);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_tick_completion_cancelled_when_state_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut engine, _rx) =
+            create_engine_with_pre_hook(dir.path(), "before_break_start", "exit 0");
+        engine.start(&StartParams::default()).unwrap();
+        engine.state.remaining_seconds = 1;
+        engine.process_tick().unwrap();
+        let check = engine.take_completion_check().unwrap();
+
+        // フックの実行中に延長された場合は完了しない
+        engine.extend(60).unwrap();
+        let engine = Mutex::new(engine);
+        TimerEngine::complete_with_pre_hooks(&engine, check)
+            .await
+            .unwrap();
+
+        let mut engine = engine.into_inner();
+        assert_eq!(engine.get_state().phase, TimerPhase::Working);
+        assert_eq!(engine.get_state().remaining_seconds, 60);
+        engine.process_tick().unwrap();
+        assert_eq!(engine.get_state().remaining_seconds, 59);
+    }
+
+    #[test]
+    fn test_tick_completion_without_pre_hooks_is_immediate() {
+        let (mut engine, _rx) = create_test_engine();
+        engine.start(&StartParams::default()).unwrap();
+        engine.state.remaining_seconds = 1;
+
+        engine.process_tick().unwrap();
+
+        assert!(engine.take_completion_check().is_none());
+        assert_eq!(engine.get_state().phase, TimerPhase::Breaking);
+    }
+
+    #[test]
+    fn test_extend_adds_remaining_time() {
+        let (mut engine, _rx) = create_test_engine();
+        assert_eq!(
+            engine.extend(60).unwrap_err().downcast_ref::<ErrorCode>(),
+            Some(&ErrorCode::NotRunning)
+        );
+
+        engine.start(&StartParams::default()).unwrap();
+        engine.extend(300).unwrap();
+        assert_eq!(engine.get_state().remaining_seconds, 25 * 60 + 300);
+
+        engine.pause().unwrap();
+        engine.extend(60).unwrap();
+        assert_eq!(engine.get_state().remaining_seconds, 25 * 60 + 360);
+        assert_eq!(engine.get_state().current_duration(), 25 * 60 + 360);
+
+        // 次のフェーズでは延長した時間をリセットする
+        engine.resume().unwrap();
+        engine.skip().unwrap();
+        assert_eq!(engine.get_state().current_duration(), 5 * 60);
+    }
+
+    #[test]
+    fn test_set_task_name_rejected_while_following() {
+        let (mut engine, _rx) = create_test_engine();
+        engine.set_task_name(Some("PROJ-1".to_string())).unwrap();
+        assert_eq!(engine.get_state().task_name.as_deref(), Some("PROJ-1"));
+
+        engine.set_following(true);
+        assert!(engine.set_task_name(None).is_err());
+        assert_eq!(engine.get_state().task_name.as_deref(), Some("PROJ-1"));
+    }
 }
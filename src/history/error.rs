@@ -0,0 +1,37 @@
+//! 履歴エラー型定義
+//!
+//! 作業履歴の読み書き・編集で発生するエラーを定義する。
+
+use thiserror::Error;
+
+/// 作業履歴のエラー型
+#[derive(Debug, Error)]
+pub enum HistoryError {
+    /// 指定IDのエントリが存在しない
+    #[error("履歴エントリが見つかりません: #{0}")]
+    NotFound(u32),
+
+    /// 既存エントリと時間帯が重複している
+    #[error("既存の履歴エントリ #{0} と時間帯が重複しています")]
+    Overlap(u32),
+
+    /// 作業時間が範囲外
+    #[error("作業時間は1-120分の範囲で指定してください")]
+    InvalidDuration,
+
+    /// 終了時刻が未来
+    #[error("未来の時刻で終わるエントリは登録できません")]
+    FutureEntry,
+
+    /// ホームディレクトリの取得に失敗
+    #[error("ホームディレクトリが見つかりません")]
+    HomeDirectoryNotFound,
+
+    /// 履歴ファイルの解析に失敗
+    #[error("履歴ファイルの解析に失敗しました: {0}")]
+    ParseError(String),
+
+    /// IOエラー
+    #[error("IOエラー: {0}")]
+    IoError(#[from] std::io::Error),
+}
@@ -0,0 +1,9 @@
+//! 作業履歴モジュール
+//!
+//! 完了したポモドーロの記録と、手動・事後登録による履歴の修正機能を提供する。
+
+mod error;
+mod store;
+
+pub use error::HistoryError;
+pub use store::{HistoryEntry, HistoryStore, HistorySummary, HistoryUpdate};
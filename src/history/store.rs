@@ -0,0 +1,695 @@
+//! 作業履歴ストア
+//!
+//! 完了したポモドーロの記録 (`~/.pomodoro/history.json`) の読み書きと、
+//! 手動エントリの追加・編集・削除を担当する。
+//!
+//! CLIとデーモンが同じファイルを更新するため、読み込み・変更・保存は
+//! ロックファイル（`history.json.lock`）の排他ロック（flock）の下で行い（`HistoryStore::update`）、
+//! 保存は一時ファイルへの書き込みとリネームで置き換える。
+
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::os::unix::io::AsRawFd;
+use std::path::{Path, PathBuf};
+
+use super::HistoryError;
+use crate::types::DEFAULT_TIMER_NAME;
+
+/// 履歴ファイルのフォーマットバージョン
+const HISTORY_VERSION: u32 = 1;
+
+/// 作業時間の最小値（秒）
+const MIN_DURATION_SECS: u64 = 60;
+
+/// 作業時間の最大値（秒）
+const MAX_DURATION_SECS: u64 = 120 * 60;
+
+/// 履歴エントリ
+///
+/// 1回分の作業セッションを表す。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// エントリID（ストア内で一意）
+    pub id: u32,
+    /// タスク名
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_name: Option<String>,
+    /// 作業開始時刻
+    pub started_at: DateTime<Utc>,
+    /// 作業時間（秒）
+    pub duration_secs: u64,
+    /// 手動で登録・編集されたエントリかどうか
+    #[serde(default)]
+    pub manual: bool,
+    /// 計測した名前付きタイマー（デフォルトタイマーの場合はNone）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timer: Option<String>,
+}
+
+impl HistoryEntry {
+    /// 作業終了時刻を取得
+    pub fn ended_at(&self) -> DateTime<Utc> {
+        self.started_at + Duration::seconds(self.duration_secs as i64)
+    }
+
+    /// 指定された時間帯と重複しているかどうか
+    pub fn overlaps(&self, started_at: DateTime<Utc>, ended_at: DateTime<Utc>) -> bool {
+        self.started_at < ended_at && started_at < self.ended_at()
+    }
+}
+
+/// 手動編集の内容
+///
+/// 指定されたフィールドのみを更新する（Noneのフィールドは更新しない）。
+#[derive(Debug, Clone, Default)]
+pub struct HistoryUpdate {
+    pub task_name: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub duration_secs: Option<u64>,
+}
+
+/// 履歴の集計結果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct HistorySummary {
+    /// エントリ数
+    pub count: usize,
+    /// うち手動エントリ数
+    pub manual_count: usize,
+    /// 合計作業時間（秒）
+    pub total_secs: u64,
+}
+
+/// 作業履歴ストア
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryStore {
+    /// ファイルフォーマットのバージョン
+    #[serde(default = "default_version")]
+    version: u32,
+    /// 次に払い出すエントリID
+    #[serde(default = "default_next_id")]
+    next_id: u32,
+    /// エントリ一覧（開始時刻順）
+    #[serde(default)]
+    entries: Vec<HistoryEntry>,
+}
+
+fn default_version() -> u32 {
+    HISTORY_VERSION
+}
+
+fn default_next_id() -> u32 {
+    1
+}
+
+impl Default for HistoryStore {
+    fn default() -> Self {
+        Self {
+            version: default_version(),
+            next_id: default_next_id(),
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl HistoryStore {
+    /// デフォルトの履歴ファイルパス（データディレクトリの `history.json`）を取得
+    pub fn default_path() -> Result<PathBuf, HistoryError> {
+        crate::paths::data_file("history.json").ok_or(HistoryError::HomeDirectoryNotFound)
+    }
+
+    /// デフォルトパスから読み込む
+    ///
+    /// ファイルが存在しない場合は空のストアを返す。
+    pub fn load() -> Result<Self, HistoryError> {
+        Self::load_from_file(&Self::default_path()?)
+    }
+
+    /// デフォルトパスに保存する
+    pub fn save(&self) -> Result<(), HistoryError> {
+        self.save_to_file(&Self::default_path()?)
+    }
+
+    /// デフォルトパスの履歴を排他ロックの下で読み込み、変更して保存する
+    ///
+    /// 変更後のストアと `f` の戻り値を返す。`f` がエラーを返した場合は保存しない。
+    pub fn update<T>(
+        f: impl FnOnce(&mut Self) -> Result<T, HistoryError>,
+    ) -> Result<(Self, T), HistoryError> {
+        Self::update_file(&Self::default_path()?, f)
+    }
+
+    /// 指定されたパスの履歴を排他ロックの下で読み込み、変更して保存する
+    ///
+    /// 他のプロセス（CLIとデーモン）の更新と重なって、エントリが失われないようにする。
+    pub fn update_file<T>(
+        path: &Path,
+        f: impl FnOnce(&mut Self) -> Result<T, HistoryError>,
+    ) -> Result<(Self, T), HistoryError> {
+        let _lock = lock_exclusive(path)?;
+        let mut store = Self::load_from_file(path)?;
+        let value = f(&mut store)?;
+        store.save_to_file(path)?;
+        Ok((store, value))
+    }
+
+    /// 指定されたパスから読み込む
+    pub fn load_from_file(path: &Path) -> Result<Self, HistoryError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        let mut store: Self =
+            serde_json::from_str(&content).map_err(|e| HistoryError::ParseError(e.to_string()))?;
+
+        // 手で書き換えられたファイルでもID採番が衝突しないようにする
+        let max_id = store.entries.iter().map(|e| e.id).max().unwrap_or(0);
+        store.next_id = store.next_id.max(max_id + 1);
+        store.sort();
+
+        Ok(store)
+    }
+
+    /// 指定されたパスに保存する
+    ///
+    /// 同じディレクトリの一時ファイルに書き込んでからリネームするため、
+    /// 読み込み側が書き込み途中のファイルを見ることはない。
+    pub fn save_to_file(&self, path: &Path) -> Result<(), HistoryError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| HistoryError::ParseError(e.to_string()))?;
+
+        let temp_path = sibling_path(path, &format!(".tmp.{}", std::process::id()));
+        let result = File::create(&temp_path)
+            .and_then(|mut file| {
+                file.write_all(content.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&temp_path, path));
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        Ok(result?)
+    }
+
+    /// エントリ一覧を取得（開始時刻順）
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// 指定した日（ローカル時刻）に開始したエントリを取得
+    pub fn entries_on(&self, date: NaiveDate) -> Vec<&HistoryEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.started_at.with_timezone(&Local).date_naive() == date)
+            .collect()
+    }
+
+    /// IDでエントリを取得
+    pub fn get(&self, id: u32) -> Option<&HistoryEntry> {
+        self.entries.iter().find(|e| e.id == id)
+    }
+
+    /// デーモンが計測した作業完了を記録する
+    ///
+    /// 実測値のため重複チェックは行わない。
+    pub fn record_completed(
+        &mut self,
+        task_name: Option<String>,
+        ended_at: DateTime<Utc>,
+        duration_secs: u64,
+    ) -> u32 {
+        let started_at = ended_at - Duration::seconds(duration_secs as i64);
+        self.push(task_name, started_at, duration_secs, false, None)
+    }
+
+    /// 指定したタイマーで計測した作業完了を記録する
+    ///
+    /// 名前付きタイマーの場合はエントリにタイマー名を付ける。
+    pub fn record_timer_completed(
+        &mut self,
+        timer: &str,
+        task_name: Option<String>,
+        ended_at: DateTime<Utc>,
+        duration_secs: u64,
+    ) -> u32 {
+        let started_at = ended_at - Duration::seconds(duration_secs as i64);
+        let timer = (timer != DEFAULT_TIMER_NAME).then(|| timer.to_string());
+        self.push(task_name, started_at, duration_secs, false, timer)
+    }
+
+    /// 手動エントリを追加する
+    ///
+    /// 作業時間の範囲、未来時刻、既存エントリとの重複を検証する。
+    pub fn add_manual(
+        &mut self,
+        task_name: Option<String>,
+        started_at: DateTime<Utc>,
+        duration_secs: u64,
+        now: DateTime<Utc>,
+    ) -> Result<u32, HistoryError> {
+        self.validate_span(None, started_at, duration_secs, now)?;
+        Ok(self.push(task_name, started_at, duration_secs, true, None))
+    }
+
+    /// エントリを編集する
+    ///
+    /// 編集されたエントリは手動エントリとして扱う。
+    pub fn edit(
+        &mut self,
+        id: u32,
+        update: HistoryUpdate,
+        now: DateTime<Utc>,
+    ) -> Result<(), HistoryError> {
+        let current = self.get(id).ok_or(HistoryError::NotFound(id))?;
+        let started_at = update.started_at.unwrap_or(current.started_at);
+        let duration_secs = update.duration_secs.unwrap_or(current.duration_secs);
+
+        self.validate_span(Some(id), started_at, duration_secs, now)?;
+
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.id == id) {
+            if update.task_name.is_some() {
+                entry.task_name = update.task_name;
+            }
+            entry.started_at = started_at;
+            entry.duration_secs = duration_secs;
+            entry.manual = true;
+        }
+        self.sort();
+
+        Ok(())
+    }
+
+    /// エントリを削除する
+    pub fn delete(&mut self, id: u32) -> Result<HistoryEntry, HistoryError> {
+        let index = self
+            .entries
+            .iter()
+            .position(|e| e.id == id)
+            .ok_or(HistoryError::NotFound(id))?;
+        Ok(self.entries.remove(index))
+    }
+
+    /// 全エントリを集計する
+    pub fn summary(&self) -> HistorySummary {
+        Self::summarize(self.entries.iter())
+    }
+
+    /// 指定されたエントリを集計する
+    pub fn summarize<'a>(entries: impl Iterator<Item = &'a HistoryEntry>) -> HistorySummary {
+        entries.fold(HistorySummary::default(), |mut acc, entry| {
+            acc.count += 1;
+            if entry.manual {
+                acc.manual_count += 1;
+            }
+            acc.total_secs += entry.duration_secs;
+            acc
+        })
+    }
+
+    /// 時間帯を検証する
+    fn validate_span(
+        &self,
+        exclude_id: Option<u32>,
+        started_at: DateTime<Utc>,
+        duration_secs: u64,
+        now: DateTime<Utc>,
+    ) -> Result<(), HistoryError> {
+        if !(MIN_DURATION_SECS..=MAX_DURATION_SECS).contains(&duration_secs) {
+            return Err(HistoryError::InvalidDuration);
+        }
+
+        let ended_at = started_at + Duration::seconds(duration_secs as i64);
+        if ended_at > now {
+            return Err(HistoryError::FutureEntry);
+        }
+
+        if let Some(conflict) = self
+            .entries
+            .iter()
+            .filter(|e| Some(e.id) != exclude_id)
+            .find(|e| e.overlaps(started_at, ended_at))
+        {
+            return Err(HistoryError::Overlap(conflict.id));
+        }
+
+        Ok(())
+    }
+
+    /// エントリを追加してIDを返す
+    fn push(
+        &mut self,
+        task_name: Option<String>,
+        started_at: DateTime<Utc>,
+        duration_secs: u64,
+        manual: bool,
+        timer: Option<String>,
+    ) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.push(HistoryEntry {
+            id,
+            task_name,
+            started_at,
+            duration_secs,
+            manual,
+            timer,
+        });
+        self.sort();
+        id
+    }
+
+    /// 開始時刻順に並べ替える
+    fn sort(&mut self) {
+        self.entries.sort_by_key(|e| (e.started_at, e.id));
+    }
+}
+
+/// 同じディレクトリにあるファイル名に接尾辞を付けたパス
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// 履歴ファイルのロックファイルに排他ロックを掛ける（ファイルを閉じると解放される）
+fn lock_exclusive(path: &Path) -> Result<File, HistoryError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(sibling_path(path, ".lock"))?;
+
+    // SAFETY: 有効なファイルディスクリプタに対するflock呼び出し
+    if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::tempdir;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 10, hour, minute, 0).unwrap()
+    }
+
+    fn now() -> DateTime<Utc> {
+        at(23, 0)
+    }
+
+    #[test]
+    fn test_entry_overlaps() {
+        let entry = HistoryEntry {
+            id: 1,
+            task_name: None,
+            started_at: at(14, 0),
+            duration_secs: 25 * 60,
+            manual: false,
+            timer: None,
+        };
+
+        assert_eq!(entry.ended_at(), at(14, 25));
+        assert!(entry.overlaps(at(14, 10), at(14, 35)));
+        assert!(entry.overlaps(at(13, 50), at(14, 5)));
+        // 境界が接しているだけなら重複しない
+        assert!(!entry.overlaps(at(14, 25), at(14, 50)));
+        assert!(!entry.overlaps(at(13, 35), at(14, 0)));
+    }
+
+    #[test]
+    fn test_record_completed_is_not_manual() {
+        let mut store = HistoryStore::default();
+        let id = store.record_completed(Some("開発".to_string()), at(14, 25), 25 * 60);
+
+        let entry = store.get(id).unwrap();
+        assert_eq!(entry.started_at, at(14, 0));
+        assert!(!entry.manual);
+        assert!(entry.timer.is_none());
+    }
+
+    #[test]
+    fn test_record_timer_completed_tags_named_timer() {
+        let mut store = HistoryStore::default();
+        let default = store.record_timer_completed(DEFAULT_TIMER_NAME, None, at(14, 25), 25 * 60);
+        let named = store.record_timer_completed("prep", None, at(14, 25), 25 * 60);
+
+        assert!(store.get(default).unwrap().timer.is_none());
+        assert_eq!(store.get(named).unwrap().timer.as_deref(), Some("prep"));
+
+        // デフォルトタイマーのエントリは従来と同じ形式で保存する
+        let json = serde_json::to_value(store.get(default).unwrap()).unwrap();
+        assert!(json.get("timer").is_none());
+        let json = serde_json::to_value(store.get(named).unwrap()).unwrap();
+        assert_eq!(json["timer"], "prep");
+    }
+
+    #[test]
+    fn test_add_manual() {
+        let mut store = HistoryStore::default();
+        let id = store
+            .add_manual(Some("会議".to_string()), at(10, 0), 25 * 60, now())
+            .unwrap();
+
+        let entry = store.get(id).unwrap();
+        assert!(entry.manual);
+        assert_eq!(entry.task_name, Some("会議".to_string()));
+    }
+
+    #[test]
+    fn test_add_manual_rejects_overlap() {
+        let mut store = HistoryStore::default();
+        let existing = store.record_completed(None, at(14, 25), 25 * 60);
+
+        let result = store.add_manual(None, at(14, 10), 25 * 60, now());
+        assert!(matches!(result, Err(HistoryError::Overlap(id)) if id == existing));
+        assert_eq!(store.entries().len(), 1);
+    }
+
+    #[test]
+    fn test_add_manual_rejects_invalid_duration() {
+        let mut store = HistoryStore::default();
+        assert!(matches!(
+            store.add_manual(None, at(10, 0), 30, now()),
+            Err(HistoryError::InvalidDuration)
+        ));
+        assert!(matches!(
+            store.add_manual(None, at(10, 0), 121 * 60, now()),
+            Err(HistoryError::InvalidDuration)
+        ));
+    }
+
+    #[test]
+    fn test_add_manual_rejects_future_entry() {
+        let mut store = HistoryStore::default();
+        let result = store.add_manual(None, at(22, 50), 25 * 60, now());
+        assert!(matches!(result, Err(HistoryError::FutureEntry)));
+    }
+
+    #[test]
+    fn test_edit_marks_manual_and_ignores_self_overlap() {
+        let mut store = HistoryStore::default();
+        let id = store.record_completed(None, at(14, 25), 25 * 60);
+
+        let update = HistoryUpdate {
+            task_name: Some("レビュー".to_string()),
+            started_at: Some(at(14, 5)),
+            ..Default::default()
+        };
+        store.edit(id, update, now()).unwrap();
+
+        let entry = store.get(id).unwrap();
+        assert!(entry.manual);
+        assert_eq!(entry.started_at, at(14, 5));
+        assert_eq!(entry.duration_secs, 25 * 60);
+        assert_eq!(entry.task_name, Some("レビュー".to_string()));
+    }
+
+    #[test]
+    fn test_edit_rejects_overlap_with_other_entry() {
+        let mut store = HistoryStore::default();
+        let first = store.record_completed(None, at(14, 25), 25 * 60);
+        let second = store.record_completed(None, at(15, 0), 25 * 60);
+
+        let update = HistoryUpdate {
+            duration_secs: Some(50 * 60),
+            ..Default::default()
+        };
+        let result = store.edit(first, update, now());
+        assert!(matches!(result, Err(HistoryError::Overlap(id)) if id == second));
+        assert_eq!(store.get(first).unwrap().duration_secs, 25 * 60);
+    }
+
+    #[test]
+    fn test_edit_not_found() {
+        let mut store = HistoryStore::default();
+        let result = store.edit(42, HistoryUpdate::default(), now());
+        assert!(matches!(result, Err(HistoryError::NotFound(42))));
+    }
+
+    #[test]
+    fn test_delete() {
+        let mut store = HistoryStore::default();
+        let id = store.record_completed(None, at(14, 25), 25 * 60);
+
+        let removed = store.delete(id).unwrap();
+        assert_eq!(removed.id, id);
+        assert!(store.entries().is_empty());
+        assert!(matches!(store.delete(id), Err(HistoryError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_entries_sorted_by_start_time() {
+        let mut store = HistoryStore::default();
+        store.record_completed(None, at(16, 25), 25 * 60);
+        store.add_manual(None, at(9, 0), 25 * 60, now()).unwrap();
+
+        let starts: Vec<_> = store.entries().iter().map(|e| e.started_at).collect();
+        assert_eq!(starts, vec![at(9, 0), at(16, 0)]);
+    }
+
+    #[test]
+    fn test_summary_includes_manual_entries() {
+        let mut store = HistoryStore::default();
+        store.record_completed(None, at(14, 25), 25 * 60);
+        store.add_manual(None, at(10, 0), 50 * 60, now()).unwrap();
+
+        let summary = store.summary();
+        assert_eq!(summary.count, 2);
+        assert_eq!(summary.manual_count, 1);
+        assert_eq!(summary.total_secs, 75 * 60);
+    }
+
+    #[test]
+    fn test_entries_on_filters_by_local_date() {
+        let mut store = HistoryStore::default();
+        store.record_completed(None, at(14, 25), 25 * 60);
+        let date = at(14, 0).with_timezone(&Local).date_naive();
+
+        assert_eq!(store.entries_on(date).len(), 1);
+        assert!(store.entries_on(date.succ_opt().unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("history.json");
+
+        let mut store = HistoryStore::default();
+        store.record_completed(Some("開発".to_string()), at(14, 25), 25 * 60);
+        store.add_manual(None, at(10, 0), 25 * 60, now()).unwrap();
+        store.save_to_file(&path).unwrap();
+
+        let loaded = HistoryStore::load_from_file(&path).unwrap();
+        assert_eq!(loaded, store);
+    }
+
+    #[test]
+    fn test_save_replaces_file_without_leftovers() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("history.json");
+
+        let mut store = HistoryStore::default();
+        store.save_to_file(&path).unwrap();
+        store.record_completed(None, at(14, 25), 25 * 60);
+        store.save_to_file(&path).unwrap();
+
+        assert_eq!(HistoryStore::load_from_file(&path).unwrap(), store);
+        let names: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(names, vec![std::ffi::OsString::from("history.json")]);
+    }
+
+    #[test]
+    fn test_update_file_serializes_concurrent_writers() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("history.json");
+
+        let writers: Vec<_> = (0..8)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for j in 0..5 {
+                        HistoryStore::update_file(&path, |store| {
+                            Ok(store.record_completed(None, at(i, j * 10 + 5), 5 * 60))
+                        })
+                        .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let store = HistoryStore::load_from_file(&path).unwrap();
+        assert_eq!(store.entries().len(), 40);
+    }
+
+    #[test]
+    fn test_update_file_does_not_save_on_error() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("history.json");
+        HistoryStore::update_file(&path, |store| {
+            Ok(store.record_completed(None, at(14, 25), 25 * 60))
+        })
+        .unwrap();
+
+        let result = HistoryStore::update_file(&path, |store| {
+            store.record_completed(None, at(16, 25), 25 * 60);
+            store.delete(99)
+        });
+
+        assert!(matches!(result, Err(HistoryError::NotFound(99))));
+        assert_eq!(
+            HistoryStore::load_from_file(&path).unwrap().entries().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_load_non_existent() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("history.json");
+
+        let loaded = HistoryStore::load_from_file(&path).unwrap();
+        assert!(loaded.entries().is_empty());
+    }
+
+    #[test]
+    fn test_load_repairs_next_id() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("history.json");
+        fs::write(
+            &path,
+            r#"{"entries":[{"id":7,"started_at":"2026-01-10T14:00:00Z","duration_secs":1500}]}"#,
+        )
+        .unwrap();
+
+        let mut store = HistoryStore::load_from_file(&path).unwrap();
+        assert!(!store.get(7).unwrap().manual);
+        let id = store.record_completed(None, at(16, 25), 25 * 60);
+        assert_eq!(id, 8);
+    }
+
+    #[test]
+    fn test_load_corrupted() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("history.json");
+        fs::write(&path, "{ invalid json }").unwrap();
+
+        let result = HistoryStore::load_from_file(&path);
+        assert!(matches!(result, Err(HistoryError::ParseError(_))));
+    }
+}
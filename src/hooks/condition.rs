@@ -0,0 +1,334 @@
+//! フックの実行条件
+//!
+//! `HookDefinition` の `when` ブロックで指定する条件を表す。
+//! すべての条件を満たす場合のみフックを実行する（指定していない条件は常に満たす）。
+//!
+//! プロジェクトとタグはタスク名から取り出す（`@プロジェクト`、`#タグ` 形式）。
+
+use crate::hooks::HookContext;
+use chrono::{Datelike, Local, NaiveDateTime, NaiveTime, Weekday};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// 時刻の書式
+const TIME_FORMAT: &str = "%H:%M";
+
+/// フックの実行条件
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookCondition {
+    /// タスク名にマッチする正規表現（タスク名がない場合は満たさない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_name: Option<String>,
+
+    /// タスク名に含まれる `@プロジェクト`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    /// タスク名に含まれる `#タグ`（いずれか1つを含めば満たす）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// 実行する時間帯（ローカル時刻）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<TimeWindow>,
+
+    /// 実行する曜日（`mon`〜`sun`）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub weekdays: Vec<String>,
+
+    /// N回目ごとのポモドーロのみ実行（サイクル数がNの倍数のとき）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every_nth: Option<u32>,
+}
+
+/// 時間帯（`"HH:MM"` 形式、`start` を含み `end` を含まない）
+///
+/// `start` が `end` より後の場合は日をまたぐ時間帯（例: 22:00〜06:00）として扱う。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    /// 開始時刻
+    pub start: String,
+    /// 終了時刻
+    pub end: String,
+}
+
+impl TimeWindow {
+    /// 開始・終了時刻を解析
+    fn parse(&self) -> Result<(NaiveTime, NaiveTime), String> {
+        let parse = |value: &str| {
+            NaiveTime::parse_from_str(value, TIME_FORMAT)
+                .map_err(|_| format!("時刻は HH:MM 形式で指定してください: '{}'", value))
+        };
+        let (start, end) = (parse(&self.start)?, parse(&self.end)?);
+        if start == end {
+            return Err(format!(
+                "hours の start と end が同じです: '{}'",
+                self.start
+            ));
+        }
+        Ok((start, end))
+    }
+
+    /// 指定された時刻が時間帯に含まれるか
+    fn contains(&self, time: NaiveTime) -> Result<bool, String> {
+        let (start, end) = self.parse()?;
+        Ok(if start < end {
+            start <= time && time < end
+        } else {
+            time >= start || time < end
+        })
+    }
+}
+
+impl HookCondition {
+    /// 条件を検証する
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(pattern) = &self.task_name {
+            Regex::new(pattern).map_err(|e| format!("task_name の正規表現が不正です: {}", e))?;
+        }
+        if let Some(project) = &self.project {
+            if project.trim().is_empty() {
+                return Err("project が空です".to_string());
+            }
+        }
+        if self.tags.iter().any(|tag| tag.trim().is_empty()) {
+            return Err("tags に空の値があります".to_string());
+        }
+        if let Some(hours) = &self.hours {
+            hours.parse()?;
+        }
+        for weekday in &self.weekdays {
+            parse_weekday(weekday)?;
+        }
+        if self.every_nth == Some(0) {
+            return Err("every_nth は1以上を指定してください".to_string());
+        }
+        Ok(())
+    }
+
+    /// コンテキストが条件を満たすか（時間帯・曜日はイベント発生時刻のローカル時刻で判定）
+    pub fn matches(&self, context: &HookContext) -> bool {
+        self.matches_at(
+            context,
+            context.timestamp.with_timezone(&Local).naive_local(),
+        )
+    }
+
+    /// 指定されたローカル時刻で条件を判定
+    ///
+    /// 検証済みの条件が解析できない場合は満たさないものとして扱う。
+    pub fn matches_at(&self, context: &HookContext, now: NaiveDateTime) -> bool {
+        match self.evaluate(context, now) {
+            Ok(matched) => matched,
+            Err(e) => {
+                warn!("フックの実行条件を評価できません: {}", e);
+                false
+            }
+        }
+    }
+
+    fn evaluate(&self, context: &HookContext, now: NaiveDateTime) -> Result<bool, String> {
+        let task_name = context.task_name.as_deref().unwrap_or("");
+
+        if let Some(pattern) = &self.task_name {
+            let regex = Regex::new(pattern).map_err(|e| e.to_string())?;
+            if context.task_name.is_none() || !regex.is_match(task_name) {
+                return Ok(false);
+            }
+        }
+        if let Some(project) = &self.project {
+            let project = project.trim_start_matches('@');
+            if !task_words(task_name, '@').any(|word| word == project) {
+                return Ok(false);
+            }
+        }
+        if !self.tags.is_empty() {
+            let matched = task_words(task_name, '#').any(|word| {
+                self.tags
+                    .iter()
+                    .any(|tag| tag.trim_start_matches('#') == word)
+            });
+            if !matched {
+                return Ok(false);
+            }
+        }
+        if let Some(hours) = &self.hours {
+            if !hours.contains(now.time())? {
+                return Ok(false);
+            }
+        }
+        if !self.weekdays.is_empty() {
+            let today = now.weekday();
+            let mut weekdays = self.weekdays.iter().map(|day| parse_weekday(day));
+            if !weekdays.any(|day| matches!(day, Ok(day) if day == today)) {
+                return Ok(false);
+            }
+        }
+        if let Some(n) = self.every_nth {
+            if n == 0 || context.cycle == 0 || context.cycle % n != 0 {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// 曜日名（`mon`、`Monday` など）を解析
+fn parse_weekday(value: &str) -> Result<Weekday, String> {
+    value
+        .parse::<Weekday>()
+        .map_err(|_| format!("無効な曜日: '{}' (mon〜sun で指定してください)", value))
+}
+
+/// タスク名から `prefix` で始まる単語を取り出す（`prefix` は除く）
+fn task_words(task_name: &str, prefix: char) -> impl Iterator<Item = &str> {
+    task_name
+        .split_whitespace()
+        .filter_map(move |word| word.strip_prefix(prefix))
+        .filter(|word| !word.is_empty())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{HookEvent, PomodoroConfig};
+    use chrono::{NaiveDate, Utc};
+    use uuid::Uuid;
+
+    fn create_test_context(task_name: Option<&str>, cycle: u32) -> HookContext {
+        HookContext {
+            event: HookEvent::WorkEnd,
+            task_name: task_name.map(str::to_string),
+            phase: "working".to_string(),
+            duration_secs: 1500,
+            elapsed_secs: 1500,
+            remaining_secs: 0,
+            cycle,
+            total_cycles: 4,
+            timestamp: Utc::now(),
+            session_id: Uuid::new_v4(),
+            config: PomodoroConfig::default(),
+        }
+    }
+
+    /// 2026-01-05（月曜日）の指定時刻
+    fn monday_at(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 1, 5)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn parse(json: &str) -> HookCondition {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_empty_condition_always_matches() {
+        let condition = HookCondition::default();
+        assert!(condition.validate().is_ok());
+        assert!(condition.matches_at(&create_test_context(None, 0), monday_at(3, 0)));
+    }
+
+    #[test]
+    fn test_task_name_regex() {
+        let condition = parse(r#"{"task_name": "^(設計|レビュー)"}"#);
+        let now = monday_at(10, 0);
+
+        assert!(condition.matches_at(&create_test_context(Some("設計書の作成"), 1), now));
+        assert!(!condition.matches_at(&create_test_context(Some("メール返信"), 1), now));
+        assert!(!condition.matches_at(&create_test_context(None, 1), now));
+    }
+
+    #[test]
+    fn test_project_and_tags() {
+        let now = monday_at(10, 0);
+        let context = create_test_context(Some("ログイン修正 @backend #bug #urgent"), 1);
+
+        assert!(parse(r#"{"project": "backend"}"#).matches_at(&context, now));
+        assert!(parse(r#"{"project": "@backend"}"#).matches_at(&context, now));
+        assert!(!parse(r#"{"project": "frontend"}"#).matches_at(&context, now));
+
+        assert!(parse(r##"{"tags": ["docs", "#bug"]}"##).matches_at(&context, now));
+        assert!(!parse(r#"{"tags": ["docs"]}"#).matches_at(&context, now));
+        assert!(!parse(r#"{"tags": ["bug"]}"#).matches_at(&create_test_context(None, 1), now));
+    }
+
+    #[test]
+    fn test_hours_window() {
+        let condition = parse(r#"{"hours": {"start": "09:00", "end": "18:00"}}"#);
+        let context = create_test_context(None, 1);
+
+        assert!(condition.matches_at(&context, monday_at(9, 0)));
+        assert!(condition.matches_at(&context, monday_at(17, 59)));
+        assert!(!condition.matches_at(&context, monday_at(18, 0)));
+        assert!(!condition.matches_at(&context, monday_at(8, 59)));
+    }
+
+    #[test]
+    fn test_hours_window_across_midnight() {
+        let condition = parse(r#"{"hours": {"start": "22:00", "end": "06:00"}}"#);
+        let context = create_test_context(None, 1);
+
+        assert!(condition.matches_at(&context, monday_at(23, 30)));
+        assert!(condition.matches_at(&context, monday_at(5, 59)));
+        assert!(!condition.matches_at(&context, monday_at(12, 0)));
+    }
+
+    #[test]
+    fn test_weekdays() {
+        let context = create_test_context(None, 1);
+
+        assert!(parse(r#"{"weekdays": ["mon", "tue"]}"#).matches_at(&context, monday_at(10, 0)));
+        assert!(parse(r#"{"weekdays": ["Monday"]}"#).matches_at(&context, monday_at(10, 0)));
+        assert!(!parse(r#"{"weekdays": ["sat", "sun"]}"#).matches_at(&context, monday_at(10, 0)));
+    }
+
+    #[test]
+    fn test_every_nth() {
+        let condition = parse(r#"{"every_nth": 4}"#);
+        let now = monday_at(10, 0);
+
+        assert!(!condition.matches_at(&create_test_context(None, 0), now));
+        assert!(!condition.matches_at(&create_test_context(None, 3), now));
+        assert!(condition.matches_at(&create_test_context(None, 4), now));
+        assert!(condition.matches_at(&create_test_context(None, 8), now));
+    }
+
+    #[test]
+    fn test_all_conditions_must_hold() {
+        let condition = parse(
+            r#"{"project": "backend", "weekdays": ["mon"], "hours": {"start": "09:00", "end": "12:00"}}"#,
+        );
+        let context = create_test_context(Some("API @backend"), 1);
+
+        assert!(condition.matches_at(&context, monday_at(10, 0)));
+        assert!(!condition.matches_at(&context, monday_at(13, 0)));
+        assert!(!condition.matches_at(&create_test_context(Some("API"), 1), monday_at(10, 0)));
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_conditions() {
+        let cases = [
+            (r#"{"task_name": "("}"#, "正規表現が不正です"),
+            (r#"{"project": " "}"#, "project が空です"),
+            (r#"{"tags": [""]}"#, "空の値"),
+            (r#"{"hours": {"start": "9時", "end": "18:00"}}"#, "HH:MM"),
+            (
+                r#"{"hours": {"start": "09:00", "end": "09:00"}}"#,
+                "同じです",
+            ),
+            (r#"{"weekdays": ["holiday"]}"#, "無効な曜日"),
+            (r#"{"every_nth": 0}"#, "1以上"),
+        ];
+        for (json, expected) in cases {
+            let err = parse(json).validate().unwrap_err();
+            assert!(err.contains(expected), "{}: {}", json, err);
+        }
+    }
+}
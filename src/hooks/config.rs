@@ -2,11 +2,13 @@
 //!
 //! フック設定ファイル (`~/.pomodoro/hooks.json`) の読み込み・検証を担当する。
 
+use crate::hooks::{template, HookCondition};
 use crate::types::HookEvent;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use thiserror::Error;
 
 /// 許可されるイベント名
@@ -20,6 +22,11 @@ const VALID_EVENTS: &[&str] = &[
     "pause",
     "resume",
     "stop",
+    "before_work_start",
+    "before_break_start",
+    "before_long_break_start",
+    "before_break_end",
+    "before_long_break_end",
 ];
 
 /// 1イベントあたりの最大フック数
@@ -31,6 +38,24 @@ const MIN_TIMEOUT_SECS: u64 = 1;
 /// タイムアウトの最大値（秒）
 const MAX_TIMEOUT_SECS: u64 = 300;
 
+/// 失敗したフックを再実行する回数の最大値（最初の実行と合わせて最大6回）
+pub const MAX_RETRIES: u32 = 5;
+
+/// 再実行までの待ち時間の初期値の最大値（秒）
+const MAX_RETRY_BACKOFF_SECS: u64 = 60;
+
+/// 再実行までの待ち時間の上限（秒、倍にしていってもこれを超えない）
+const MAX_RETRY_DELAY_SECS: u64 = 300;
+
+/// 同時に実行できるフック数の上限のデフォルト値
+const DEFAULT_MAX_CONCURRENT: usize = 4;
+
+/// 同時に実行できるフック数の上限の最大値
+const MAX_CONCURRENT_LIMIT: usize = 32;
+
+/// HTTPフックで使用できるメソッド
+const VALID_HTTP_METHODS: &[&str] = &["GET", "POST", "PUT", "PATCH", "DELETE"];
+
 /// フック設定エラー
 #[derive(Debug, Error)]
 pub enum HookConfigError {
@@ -66,6 +91,10 @@ pub struct HookConfig {
     #[serde(default)]
     pub defaults: HookDefaults,
 
+    /// イベントごとの実行オプション（イベント名 -> オプション）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, EventOptions>,
+
     /// 内部用: イベント名 -> フック定義リストのマップ（検証・正規化後に構築）
     #[serde(skip)]
     hooks_by_event: HashMap<String, Vec<HookDefinition>>,
@@ -84,9 +113,42 @@ pub struct HookDefinition {
     /// トリガーするイベント名
     pub event: String,
 
-    /// 実行するスクリプトのパス
+    /// フックの種類（デフォルト: script）
+    #[serde(rename = "type", default)]
+    pub kind: HookKind,
+
+    /// 実行するスクリプトのパス（scriptフックのみ）
+    #[serde(default, skip_serializing_if = "is_empty_path")]
     pub script: PathBuf,
 
+    /// 実行するコマンド（scriptフックのみ。コマンド名はPATHから検索、パスは絶対パスまたは~/形式）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// `/bin/sh -c` で実行するコマンド文字列（scriptフックのみ。テンプレートは展開しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+
+    /// script または command に渡す引数（テンプレート可）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+
+    /// 作業ディレクトリ（絶対パスまたは~/形式）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<PathBuf>,
+
+    /// 追加の環境変数（値はテンプレート可。`POMODORO_*` は上書きできない）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+
+    /// 送信するHTTPリクエスト（httpフックのみ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<HttpHook>,
+
+    /// 実行条件（省略時は常に実行）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<HookCondition>,
+
     /// タイムアウト（秒）
     #[serde(default = "default_timeout")]
     pub timeout_secs: u64,
@@ -94,6 +156,41 @@ pub struct HookDefinition {
     /// 有効かどうか
     #[serde(default = "default_enabled")]
     pub enabled: bool,
+
+    /// 実行順の優先度（大きいほど先に実行、同じ場合は定義順）
+    #[serde(default)]
+    pub priority: i32,
+
+    /// 失敗したときの動作（デフォルト: continue）
+    #[serde(default)]
+    pub on_error: OnError,
+
+    /// 失敗したときに再実行する回数（デフォルト: 0）
+    #[serde(default)]
+    pub retries: u32,
+
+    /// 最初の再実行までの待ち時間（秒、再実行のたびに倍にする）
+    #[serde(default = "default_retry_backoff")]
+    pub retry_backoff_secs: u64,
+}
+
+/// フックが失敗したときの動作
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnError {
+    /// 後続のフックを続けて実行する
+    #[default]
+    Continue,
+    /// 同じイベントの後続のフックを実行しない（順次実行時のみ）
+    Stop,
+}
+
+/// イベントごとの実行オプション
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventOptions {
+    /// フックを並列に実行するかどうか（デフォルト: 優先度順に1つずつ実行）
+    #[serde(default)]
+    pub parallel: bool,
 }
 
 impl HookDefinition {
@@ -129,30 +226,280 @@ impl HookDefinition {
             )));
         }
 
+        // 再実行の検証
+        if self.retries > MAX_RETRIES {
+            return Err(HookConfigError::ValidationError(format!(
+                "フック '{}' の retries {} が範囲外です (許可: 0-{})",
+                self.name, self.retries, MAX_RETRIES
+            )));
+        }
+        if !(1..=MAX_RETRY_BACKOFF_SECS).contains(&self.retry_backoff_secs) {
+            return Err(HookConfigError::ValidationError(format!(
+                "フック '{}' の retry_backoff_secs {} が範囲外です (許可: 1-{}秒)",
+                self.name, self.retry_backoff_secs, MAX_RETRY_BACKOFF_SECS
+            )));
+        }
+        // 事前フックは操作を待たせるため再実行しない
+        if self.retries > 0 && HookEvent::parse(&self.event).is_some_and(|e| e.is_pre_transition())
+        {
+            return Err(HookConfigError::ValidationError(format!(
+                "フック '{}': 事前フック（{}）では retries を指定できません",
+                self.name, self.event
+            )));
+        }
+
+        // 実行条件の検証
+        if let Some(when) = &self.when {
+            when.validate().map_err(|e| {
+                HookConfigError::ValidationError(format!("フック '{}' の when: {}", self.name, e))
+            })?;
+        }
+
+        // 種類ごとの設定の検証
+        match self.kind {
+            HookKind::Script => self.validate_process(),
+            HookKind::Http => self.validate_http(),
+        }
+        .map_err(|e| HookConfigError::ValidationError(format!("フック '{}': {}", self.name, e)))
+    }
+
+    /// 最大の実行回数（最初の実行と再実行の合計）
+    pub fn max_attempts(&self) -> u32 {
+        self.retries.min(MAX_RETRIES) + 1
+    }
+
+    /// `attempt` 回目の実行が失敗した後、次の実行までの待ち時間
+    ///
+    /// `retry_backoff_secs` から再実行のたびに倍にし、`MAX_RETRY_DELAY_SECS` で打ち切る。
+    pub fn retry_delay(&self, attempt: u32) -> Duration {
+        let factor = 1u64 << attempt.saturating_sub(1).min(16);
+        Duration::from_secs(
+            self.retry_backoff_secs
+                .saturating_mul(factor)
+                .min(MAX_RETRY_DELAY_SECS),
+        )
+    }
+
+    /// scriptフック（script・command・shell）の設定を検証する
+    fn validate_process(&self) -> Result<(), String> {
+        if self.http.is_some() {
+            return Err("http は type が \"http\" の場合のみ指定できます".to_string());
+        }
+        let specified = [
+            !self.script.as_os_str().is_empty(),
+            self.command.is_some(),
+            self.shell.is_some(),
+        ]
+        .iter()
+        .filter(|specified| **specified)
+        .count();
+        if specified != 1 {
+            return Err("script・command・shell のいずれか1つを指定してください".to_string());
+        }
+        if let Some(command) = &self.command {
+            if command.trim().is_empty() {
+                return Err("command が空です".to_string());
+            }
+        }
+        if let Some(shell) = &self.shell {
+            if shell.trim().is_empty() {
+                return Err("shell が空です".to_string());
+            }
+            if !self.args.is_empty() {
+                return Err("shell と args は同時に指定できません".to_string());
+            }
+        }
+        for arg in &self.args {
+            template::validate(arg).map_err(|e| format!("args: {}", e))?;
+        }
+        for (name, value) in &self.env {
+            if name.is_empty() || name.contains('=') || name.contains('\0') {
+                return Err(format!("無効な環境変数名: '{}'", name));
+            }
+            template::validate(value).map_err(|e| format!("env '{}': {}", name, e))?;
+        }
         Ok(())
     }
+
+    /// httpフックの設定を検証する
+    fn validate_http(&self) -> Result<(), String> {
+        let Some(http) = &self.http else {
+            return Err("http は必須です".to_string());
+        };
+        if !self.script.as_os_str().is_empty()
+            || self.command.is_some()
+            || self.shell.is_some()
+            || !self.args.is_empty()
+            || self.working_dir.is_some()
+            || !self.env.is_empty()
+        {
+            return Err(
+                "type が \"http\" のため script・command・shell・args・working_dir・env は指定できません"
+                    .to_string(),
+            );
+        }
+        http.validate()
+    }
+}
+
+fn is_empty_path(path: &Path) -> bool {
+    path.as_os_str().is_empty()
+}
+
+/// フックの種類
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HookKind {
+    /// 外部スクリプトを実行する
+    #[default]
+    Script,
+    /// HTTPリクエストを送信する（Webhook）
+    Http,
+}
+
+/// HTTPフックの定義
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpHook {
+    /// 送信先URL（http:// または https://）
+    pub url: String,
+
+    /// HTTPメソッド（デフォルト: POST）
+    #[serde(default = "default_http_method")]
+    pub method: String,
+
+    /// リクエストヘッダー
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+
+    /// 値を環境変数またはファイルから読み込むヘッダー（トークンなど）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub secret_headers: BTreeMap<String, SecretValue>,
+
+    /// リクエストボディのテンプレート（省略時はコンテキスト全体のJSON）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+fn default_http_method() -> String {
+    "POST".to_string()
+}
+
+impl HttpHook {
+    /// HTTPフックの定義を検証する
+    fn validate(&self) -> Result<(), String> {
+        if !(self.url.starts_with("http://") || self.url.starts_with("https://")) {
+            return Err(format!(
+                "URLは http:// または https:// で始まる必要があります: {}",
+                self.url
+            ));
+        }
+        if !VALID_HTTP_METHODS.contains(&self.method.as_str()) {
+            return Err(format!(
+                "無効なHTTPメソッド: '{}'. 許可されるメソッド: {:?}",
+                self.method, VALID_HTTP_METHODS
+            ));
+        }
+        for name in self.headers.keys().chain(self.secret_headers.keys()) {
+            reqwest::header::HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| format!("無効なヘッダー名: '{}'", name))?;
+        }
+        for (name, value) in &self.headers {
+            reqwest::header::HeaderValue::from_str(value)
+                .map_err(|_| format!("ヘッダー '{}' の値が不正です", name))?;
+        }
+        for (name, secret) in &self.secret_headers {
+            secret
+                .validate()
+                .map_err(|e| format!("ヘッダー '{}': {}", name, e))?;
+        }
+        if let Some(body) = &self.body {
+            template::validate(body).map_err(|e| format!("body: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+/// 設定ファイルに直接書かない値（環境変数またはファイルから読み込む）
+///
+/// 値はフックの実行時に読み込むため、トークンの更新はデーモンの再起動なしに反映される。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretValue {
+    /// 値を読み込む環境変数名
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<String>,
+
+    /// 値を読み込むファイルのパス（前後の空白は除去する）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+
+    /// 値の前に付ける文字列（例: `"Bearer "`）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub prefix: String,
+}
+
+impl SecretValue {
+    /// env と file のどちらか一方だけが指定されているか検証する
+    fn validate(&self) -> Result<(), String> {
+        match (&self.env, &self.file) {
+            (Some(env), None) if env.trim().is_empty() => Err("env が空です".to_string()),
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err("env と file のどちらか一方を指定してください".to_string()),
+        }
+    }
+
+    /// 値を読み込む
+    ///
+    /// # Errors
+    ///
+    /// 環境変数が設定されていない場合、またはファイルを読み込めない場合
+    pub fn resolve(&self) -> Result<String, String> {
+        let value = match (&self.env, &self.file) {
+            (Some(env), _) => {
+                std::env::var(env).map_err(|_| format!("環境変数 {} が設定されていません", env))?
+            }
+            (None, Some(file)) => fs::read_to_string(file)
+                .map_err(|e| format!("ファイル {:?} を読み込めません: {}", file, e))?
+                .trim()
+                .to_string(),
+            (None, None) => return Err("env と file のどちらか一方を指定してください".to_string()),
+        };
+        Ok(format!("{}{}", self.prefix, value))
+    }
 }
 
 fn default_timeout() -> u64 {
     30
 }
 
+fn default_retry_backoff() -> u64 {
+    2
+}
+
 fn default_enabled() -> bool {
     true
 }
 
+fn default_max_concurrent() -> usize {
+    DEFAULT_MAX_CONCURRENT
+}
+
 /// デフォルト設定
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HookDefaults {
     /// デフォルトタイムアウト（秒）
     #[serde(default = "default_timeout")]
     pub timeout_secs: u64,
+
+    /// 全イベントを通じて同時に実行できるフック数の上限
+    #[serde(default = "default_max_concurrent")]
+    pub max_concurrent: usize,
 }
 
 impl Default for HookDefaults {
     fn default() -> Self {
         Self {
             timeout_secs: default_timeout(),
+            max_concurrent: default_max_concurrent(),
         }
     }
 }
@@ -163,6 +510,7 @@ impl Default for HookConfig {
             version: default_version(),
             hooks: Vec::new(),
             defaults: HookDefaults::default(),
+            events: BTreeMap::new(),
             hooks_by_event: HashMap::new(),
         }
     }
@@ -201,25 +549,32 @@ impl HookConfig {
 
     /// デフォルトの設定ファイルパスを取得
     pub fn default_config_path() -> Result<PathBuf, HookConfigError> {
-        dirs::home_dir()
-            .map(|h| h.join(".pomodoro").join("hooks.json"))
-            .ok_or_else(|| {
-                HookConfigError::IoError(std::io::Error::new(
-                    std::io::ErrorKind::NotFound,
-                    "ホームディレクトリが見つかりません",
-                ))
-            })
+        crate::paths::data_file("hooks.json").ok_or_else(|| {
+            HookConfigError::IoError(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "ホームディレクトリが見つかりません",
+            ))
+        })
     }
 
     /// 設定を検証する
     fn validate(&self) -> Result<(), HookConfigError> {
         // イベントごとのフック数をカウント
         let mut event_counts: HashMap<&str, usize> = HashMap::new();
+        // 失敗・再実行の状態と実行記録はフック名で管理するため、名前の重複を許可しない
+        let mut names: HashSet<&str> = HashSet::new();
 
         for hook in &self.hooks {
             // 個別のフック定義を検証
             hook.validate()?;
 
+            if !names.insert(hook.name.as_str()) {
+                return Err(HookConfigError::ValidationError(format!(
+                    "フック名 '{}' が重複しています",
+                    hook.name
+                )));
+            }
+
             // イベントごとのフック数をカウント
             *event_counts.entry(hook.event.as_str()).or_insert(0) += 1;
         }
@@ -244,13 +599,51 @@ impl HookConfig {
             )));
         }
 
+        // 同時実行数の上限の検証
+        if self.defaults.max_concurrent == 0 || self.defaults.max_concurrent > MAX_CONCURRENT_LIMIT
+        {
+            return Err(HookConfigError::ValidationError(format!(
+                "同時実行数の上限 {} が範囲外です (許可: 1-{})",
+                self.defaults.max_concurrent, MAX_CONCURRENT_LIMIT
+            )));
+        }
+
+        // イベントごとの実行オプションの検証
+        for event in self.events.keys() {
+            if !VALID_EVENTS.contains(&event.as_str()) {
+                return Err(HookConfigError::ValidationError(format!(
+                    "events に無効なイベント名があります: '{}'. 許可されるイベント: {:?}",
+                    event, VALID_EVENTS
+                )));
+            }
+        }
+
         Ok(())
     }
 
-    /// スクリプトパスを正規化する（~展開、絶対パス化）
+    /// スクリプトパスとシークレットのファイルパスを正規化する（~展開、絶対パス化）
     fn normalize_paths(&mut self) -> Result<(), HookConfigError> {
         for hook in &mut self.hooks {
-            hook.script = Self::normalize_path(&hook.script)?;
+            if !hook.script.as_os_str().is_empty() {
+                hook.script = Self::normalize_path(&hook.script)?;
+            }
+            // コマンド名（`/` を含まない）はPATHから検索するためそのまま
+            if let Some(command) = &hook.command {
+                if command.starts_with('~') || command.contains('/') {
+                    let path = Self::normalize_path(Path::new(command))?;
+                    hook.command = Some(path.to_string_lossy().into_owned());
+                }
+            }
+            if let Some(dir) = &hook.working_dir {
+                hook.working_dir = Some(Self::normalize_path(dir)?);
+            }
+            if let Some(http) = &mut hook.http {
+                for secret in http.secret_headers.values_mut() {
+                    if let Some(file) = &secret.file {
+                        secret.file = Some(Self::normalize_path(file)?);
+                    }
+                }
+            }
         }
         Ok(())
     }
@@ -278,13 +671,13 @@ impl HookConfig {
         } else {
             // 相対パスの場合はエラー（セキュリティ対策）
             Err(HookConfigError::ValidationError(format!(
-                "スクリプトパスは絶対パスまたは~/で始まる必要があります: {:?}",
+                "パスは絶対パスまたは~/で始まる必要があります: {:?}",
                 path
             )))
         }
     }
 
-    /// イベントごとのフックマップを構築
+    /// イベントごとのフックマップを構築（優先度の高い順、同じ優先度は定義順）
     fn build_event_map(&mut self) {
         self.hooks_by_event.clear();
         for hook in &self.hooks {
@@ -295,6 +688,9 @@ impl HookConfig {
                     .push(hook.clone());
             }
         }
+        for hooks in self.hooks_by_event.values_mut() {
+            hooks.sort_by_key(|hook| std::cmp::Reverse(hook.priority));
+        }
     }
 
     /// 指定されたイベントに対応するフックを取得
@@ -316,6 +712,13 @@ impl HookConfig {
     pub fn default_timeout(&self) -> u64 {
         self.defaults.timeout_secs
     }
+
+    /// 指定されたイベントのフックを並列に実行するかどうか
+    pub fn is_parallel(&self, event: &HookEvent) -> bool {
+        self.events
+            .get(event.as_str())
+            .is_some_and(|options| options.parallel)
+    }
 }
 
 #[cfg(test)]
@@ -667,4 +1070,416 @@ mod tests {
         let err = result.unwrap_err();
         assert!(err.to_string().contains("デフォルトタイムアウト値"));
     }
+
+    /// httpフックの設定JSONを組み立てる
+    fn http_hook_json(http: &str) -> String {
+        format!(
+            r#"{{
+            "version": "1.0",
+            "hooks": [
+                {{
+                    "name": "webhook",
+                    "event": "work_end",
+                    "type": "http",
+                    "http": {}
+                }}
+            ]
+        }}"#,
+            http
+        )
+    }
+
+    #[test]
+    fn test_parse_http_hook() {
+        let json = http_hook_json(
+            r#"{
+                "url": "https://hooks.example.com/pomodoro",
+                "headers": {"X-Source": "pomodoro"},
+                "secret_headers": {
+                    "Authorization": {"env": "POMODORO_TOKEN", "prefix": "Bearer "}
+                },
+                "body": "{\"text\": \"{{task_name}} 完了\"}"
+            }"#,
+        );
+
+        let config = HookConfig::parse_and_validate(&json).unwrap();
+        let hook = &config.hooks[0];
+        assert_eq!(hook.kind, HookKind::Http);
+        assert!(hook.script.as_os_str().is_empty());
+        let http = hook.http.as_ref().unwrap();
+        assert_eq!(http.method, "POST");
+        assert_eq!(http.headers["X-Source"], "pomodoro");
+        assert_eq!(
+            http.secret_headers["Authorization"].env.as_deref(),
+            Some("POMODORO_TOKEN")
+        );
+        assert_eq!(http.secret_headers["Authorization"].prefix, "Bearer ");
+    }
+
+    #[test]
+    fn test_script_hook_kind_defaults_to_script() {
+        let json = r#"{
+            "version": "1.0",
+            "hooks": [
+                {"name": "test", "event": "work_end", "script": "/usr/local/bin/test.sh"}
+            ]
+        }"#;
+
+        let config = HookConfig::parse_and_validate(json).unwrap();
+        assert_eq!(config.hooks[0].kind, HookKind::Script);
+
+        // 保存時に空の http は出力しない
+        let saved = serde_json::to_value(&config.hooks[0]).unwrap();
+        assert_eq!(saved["type"], "script");
+        assert!(saved.get("http").is_none());
+    }
+
+    #[test]
+    fn test_validate_script_hook_requires_script() {
+        let json = r#"{
+            "version": "1.0",
+            "hooks": [{"name": "test", "event": "work_end"}]
+        }"#;
+
+        let err = HookConfig::parse_and_validate(json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("script・command・shell のいずれか1つを指定してください"));
+    }
+
+    #[test]
+    fn test_validate_http_hook_kind_mismatch() {
+        let json = r#"{
+            "version": "1.0",
+            "hooks": [{"name": "test", "event": "work_end", "type": "http"}]
+        }"#;
+        let err = HookConfig::parse_and_validate(json).unwrap_err();
+        assert!(err.to_string().contains("http は必須です"));
+
+        let json = r#"{
+            "version": "1.0",
+            "hooks": [{
+                "name": "test",
+                "event": "work_end",
+                "script": "/usr/local/bin/test.sh",
+                "http": {"url": "https://example.com"}
+            }]
+        }"#;
+        let err = HookConfig::parse_and_validate(json).unwrap_err();
+        assert!(err.to_string().contains("type が \"http\" の場合のみ"));
+
+        let json = r#"{
+            "version": "1.0",
+            "hooks": [{
+                "name": "test",
+                "event": "work_end",
+                "type": "http",
+                "script": "/usr/local/bin/test.sh",
+                "http": {"url": "https://example.com"}
+            }]
+        }"#;
+        let err = HookConfig::parse_and_validate(json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("type が \"http\" のため script・command・shell"));
+    }
+
+    #[test]
+    fn test_validate_http_hook_url_and_method() {
+        let err =
+            HookConfig::parse_and_validate(&http_hook_json(r#"{"url": "ftp://example.com"}"#))
+                .unwrap_err();
+        assert!(err.to_string().contains("http:// または https://"));
+
+        let err = HookConfig::parse_and_validate(&http_hook_json(
+            r#"{"url": "https://example.com", "method": "TRACE"}"#,
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("無効なHTTPメソッド"));
+    }
+
+    #[test]
+    fn test_validate_http_hook_headers() {
+        let err = HookConfig::parse_and_validate(&http_hook_json(
+            r#"{"url": "https://example.com", "headers": {"Bad Header": "x"}}"#,
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("無効なヘッダー名"));
+
+        let err = HookConfig::parse_and_validate(&http_hook_json(
+            r#"{"url": "https://example.com", "secret_headers": {"Authorization": {"env": "A", "file": "/tmp/token"}}}"#,
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("どちらか一方"));
+
+        let err = HookConfig::parse_and_validate(&http_hook_json(
+            r#"{"url": "https://example.com", "secret_headers": {"Authorization": {}}}"#,
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("どちらか一方"));
+    }
+
+    #[test]
+    fn test_validate_http_hook_body_template() {
+        let err = HookConfig::parse_and_validate(&http_hook_json(
+            r#"{"url": "https://example.com", "body": "{{unknown}}"}"#,
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("不明なプレースホルダー"));
+    }
+
+    #[test]
+    fn test_secret_value_resolve_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token");
+        fs::write(&path, "s3cret\n").unwrap();
+
+        let secret = SecretValue {
+            env: None,
+            file: Some(path),
+            prefix: "Bearer ".to_string(),
+        };
+        assert_eq!(secret.resolve().unwrap(), "Bearer s3cret");
+
+        let missing = SecretValue {
+            env: Some("POMODORO_TEST_UNSET_SECRET_VARIABLE".to_string()),
+            file: None,
+            prefix: String::new(),
+        };
+        assert!(missing
+            .resolve()
+            .unwrap_err()
+            .contains("設定されていません"));
+    }
+
+    #[test]
+    fn test_hooks_sorted_by_priority() {
+        let json = r#"{
+            "version": "1.0",
+            "hooks": [
+                {"name": "a", "event": "work_end", "script": "/bin/true"},
+                {"name": "b", "event": "work_end", "script": "/bin/true", "priority": 10},
+                {"name": "c", "event": "work_end", "script": "/bin/true"},
+                {"name": "d", "event": "work_end", "script": "/bin/true", "priority": -5}
+            ]
+        }"#;
+
+        let config = HookConfig::parse_and_validate(json).unwrap();
+        let names: Vec<&str> = config
+            .get_hooks_for_event(&HookEvent::WorkEnd)
+            .unwrap()
+            .iter()
+            .map(|hook| hook.name.as_str())
+            .collect();
+        assert_eq!(names, ["b", "a", "c", "d"]);
+        // 設定ファイルの定義順は変わらない
+        assert_eq!(config.hooks[0].name, "a");
+    }
+
+    #[test]
+    fn test_parse_event_options_and_on_error() {
+        let json = r#"{
+            "version": "1.0",
+            "hooks": [
+                {"name": "a", "event": "work_end", "script": "/bin/true", "on_error": "stop"}
+            ],
+            "events": {"work_end": {"parallel": true}},
+            "defaults": {"max_concurrent": 2}
+        }"#;
+
+        let config = HookConfig::parse_and_validate(json).unwrap();
+        assert_eq!(config.hooks[0].on_error, OnError::Stop);
+        assert!(config.is_parallel(&HookEvent::WorkEnd));
+        assert!(!config.is_parallel(&HookEvent::WorkStart));
+        assert_eq!(config.defaults.max_concurrent, 2);
+
+        let config = HookConfig::default();
+        assert_eq!(config.defaults.max_concurrent, DEFAULT_MAX_CONCURRENT);
+    }
+
+    #[test]
+    fn test_validate_event_options() {
+        let json = r#"{
+            "version": "1.0",
+            "events": {"lunch": {"parallel": true}}
+        }"#;
+        let err = HookConfig::parse_and_validate(json).unwrap_err();
+        assert!(err.to_string().contains("events に無効なイベント名"));
+
+        for max in [0, 33] {
+            let json = format!(
+                r#"{{"version": "1.0", "defaults": {{"max_concurrent": {}}}}}"#,
+                max
+            );
+            let err = HookConfig::parse_and_validate(&json).unwrap_err();
+            assert!(err.to_string().contains("同時実行数の上限"));
+        }
+    }
+
+    /// フック定義1つだけの設定JSONを組み立てる（`fields` はフック定義のフィールド）
+    fn single_hook_json(fields: &str) -> String {
+        format!(
+            r#"{{"version": "1.0", "hooks": [{{"name": "inline", "event": "work_end", {}}}]}}"#,
+            fields
+        )
+    }
+
+    #[test]
+    fn test_parse_command_and_shell_hooks() {
+        let config = HookConfig::parse_and_validate(&single_hook_json(
+            r#""command": "osascript", "args": ["-e", "display notification \"{{task_name}}\""],
+               "working_dir": "/tmp", "env": {"LANG": "ja_JP.UTF-8"}"#,
+        ))
+        .unwrap();
+        let hook = &config.hooks[0];
+        assert_eq!(hook.command.as_deref(), Some("osascript"));
+        assert_eq!(hook.args.len(), 2);
+        assert_eq!(hook.working_dir.as_deref(), Some(Path::new("/tmp")));
+        assert_eq!(hook.env["LANG"], "ja_JP.UTF-8");
+
+        let config = HookConfig::parse_and_validate(&single_hook_json(
+            r#""shell": "say done | tee -a ~/log""#,
+        ))
+        .unwrap();
+        assert_eq!(
+            config.hooks[0].shell.as_deref(),
+            Some("say done | tee -a ~/log")
+        );
+    }
+
+    #[test]
+    fn test_command_path_is_normalized() {
+        let config =
+            HookConfig::parse_and_validate(&single_hook_json(r#""command": "~/bin/notify""#))
+                .unwrap();
+        let command = config.hooks[0].command.clone().unwrap();
+        assert!(Path::new(&command).is_absolute());
+        assert!(command.ends_with("bin/notify"));
+
+        let err = HookConfig::parse_and_validate(&single_hook_json(r#""command": "bin/notify""#))
+            .unwrap_err();
+        assert!(err.to_string().contains("絶対パスまたは~/"));
+
+        let err = HookConfig::parse_and_validate(&single_hook_json(
+            r#""command": "true", "working_dir": "relative/dir""#,
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("絶対パスまたは~/"));
+
+        // 作業ディレクトリの存在は実行時に確認する（設定全体を無効にしない）
+        let config = HookConfig::parse_and_validate(&single_hook_json(
+            r#""command": "true", "working_dir": "/nonexistent/pomodoro-hook-dir""#,
+        ))
+        .unwrap();
+        assert_eq!(
+            config.hooks[0].working_dir.as_deref(),
+            Some(Path::new("/nonexistent/pomodoro-hook-dir"))
+        );
+    }
+
+    #[test]
+    fn test_duplicate_hook_names_are_rejected() {
+        let json = r#"{"version": "1.0", "hooks": [
+            {"name": "notify", "event": "work_start", "command": "true"},
+            {"name": "notify", "event": "work_end", "command": "true"}
+        ]}"#;
+        let err = HookConfig::parse_and_validate(json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("フック名 'notify' が重複しています"));
+    }
+
+    #[test]
+    fn test_literal_braces_in_args_and_env_are_allowed() {
+        let config = HookConfig::parse_and_validate(&single_hook_json(
+            r#""command": "awk", "args": ["{print}", "{task_name}"], "env": {"SEARCH": "${HOME}:${PATH}"}"#,
+        ))
+        .unwrap();
+        assert_eq!(config.hooks[0].args, vec!["{print}", "{task_name}"]);
+        assert_eq!(config.hooks[0].env["SEARCH"], "${HOME}:${PATH}");
+    }
+
+    #[test]
+    fn test_parse_retries_and_backoff() {
+        let config = HookConfig::parse_and_validate(&single_hook_json(
+            r#""command": "true", "retries": 3, "retry_backoff_secs": 5"#,
+        ))
+        .unwrap();
+        let hook = &config.hooks[0];
+        assert_eq!(hook.max_attempts(), 4);
+        assert_eq!(hook.retry_delay(1), Duration::from_secs(5));
+        assert_eq!(hook.retry_delay(2), Duration::from_secs(10));
+        assert_eq!(hook.retry_delay(3), Duration::from_secs(20));
+
+        // 待ち時間は MAX_RETRY_DELAY_SECS で打ち切る
+        let mut hook = hook.clone();
+        hook.retry_backoff_secs = MAX_RETRY_BACKOFF_SECS;
+        assert_eq!(
+            hook.retry_delay(MAX_RETRIES),
+            Duration::from_secs(MAX_RETRY_DELAY_SECS)
+        );
+
+        // デフォルトは再実行しない
+        let config =
+            HookConfig::parse_and_validate(&single_hook_json(r#""command": "true""#)).unwrap();
+        assert_eq!(config.hooks[0].retries, 0);
+        assert_eq!(config.hooks[0].max_attempts(), 1);
+        assert_eq!(config.hooks[0].retry_backoff_secs, 2);
+    }
+
+    #[test]
+    fn test_validate_retries() {
+        let cases = [
+            (r#""command": "true", "retries": 6"#, "retries 6 が範囲外"),
+            (
+                r#""command": "true", "retries": 1, "retry_backoff_secs": 0"#,
+                "retry_backoff_secs 0 が範囲外",
+            ),
+            (
+                r#""command": "true", "retry_backoff_secs": 61"#,
+                "retry_backoff_secs 61 が範囲外",
+            ),
+        ];
+        for (fields, expected) in cases {
+            let err = HookConfig::parse_and_validate(&single_hook_json(fields)).unwrap_err();
+            assert!(err.to_string().contains(expected), "{}: {}", fields, err);
+        }
+
+        let json = r#"{"version": "1.0", "hooks": [
+            {"name": "gate", "event": "before_work_start", "command": "true", "retries": 1}
+        ]}"#;
+        let err = HookConfig::parse_and_validate(json).unwrap_err();
+        assert!(err.to_string().contains("retries を指定できません"));
+    }
+
+    #[test]
+    fn test_validate_process_hook_fields() {
+        let cases = [
+            (
+                r#""script": "/bin/true", "command": "true""#,
+                "いずれか1つを指定してください",
+            ),
+            (r#""command": " ""#, "command が空です"),
+            (
+                r#""shell": "echo", "args": ["x"]"#,
+                "shell と args は同時に指定できません",
+            ),
+            (
+                r#""command": "echo", "args": ["{{task}}"]"#,
+                "args: 不明なプレースホルダー",
+            ),
+            (
+                r#""command": "echo", "env": {"A=B": "x"}"#,
+                "無効な環境変数名",
+            ),
+            (
+                r#""command": "echo", "env": {"TASK": "{{nope}}"}"#,
+                "env 'TASK': 不明なプレースホルダー",
+            ),
+        ];
+        for (fields, expected) in cases {
+            let err = HookConfig::parse_and_validate(&single_hook_json(fields)).unwrap_err();
+            assert!(err.to_string().contains(expected), "{}: {}", fields, err);
+        }
+    }
 }
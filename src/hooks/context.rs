@@ -1,12 +1,18 @@
-use crate::types::HookEvent;
+use crate::types::{HookEvent, PomodoroConfig};
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::collections::HashMap;
 use uuid::Uuid;
 
+/// 標準入力に渡すJSONのスキーマバージョン
+///
+/// フィールドの削除や意味の変更をした場合に上げる（フィールドの追加では上げない）。
+pub const HOOK_CONTEXT_SCHEMA_VERSION: u32 = 1;
+
 /// フック実行コンテキスト
 ///
-/// スクリプト実行時に環境変数として渡される情報
-#[derive(Debug, Clone)]
+/// スクリプト実行時に環境変数と標準入力（JSON）で渡される情報
+#[derive(Debug, Clone, Serialize)]
 pub struct HookContext {
     /// 発生したイベント
     pub event: HookEvent,
@@ -28,6 +34,16 @@ pub struct HookContext {
     pub timestamp: DateTime<Utc>,
     /// セッションID
     pub session_id: Uuid,
+    /// イベント発生時のタイマー設定
+    pub config: PomodoroConfig,
+}
+
+/// 標準入力に渡すJSON
+#[derive(Serialize)]
+struct HookPayload<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    context: &'a HookContext,
 }
 
 impl HookContext {
@@ -77,6 +93,16 @@ impl HookContext {
         vars
     }
 
+    /// 標準入力に渡すJSONに変換
+    ///
+    /// 環境変数と異なり、タスク名などの値はサニタイズせずにそのまま渡す。
+    pub fn to_json(&self) -> serde_json::Result<Vec<u8>> {
+        serde_json::to_vec(&HookPayload {
+            schema_version: HOOK_CONTEXT_SCHEMA_VERSION,
+            context: self,
+        })
+    }
+
     /// 値をサニタイズする（シェルで安全に使用できるように）
     fn sanitize_value(value: &str) -> String {
         value
@@ -104,6 +130,7 @@ mod tests {
             total_cycles: 4,
             timestamp: Utc.timestamp_opt(1672531200, 0).unwrap(), // 2023-01-01 00:00:00 UTC
             session_id: Uuid::from_str("00000000-0000-0000-0000-000000000000").unwrap(),
+            config: PomodoroConfig::default(),
         }
     }
 
@@ -177,4 +204,43 @@ mod tests {
         // $ や {} が除去されていること
         assert_eq!(vars.get("POMODORO_TASK_NAME"), Some(&"VAR VAR".to_string()));
     }
+
+    #[test]
+    fn test_to_json() {
+        let context = create_test_context();
+        let json: serde_json::Value = serde_json::from_slice(&context.to_json().unwrap()).unwrap();
+
+        assert_eq!(json["schema_version"], HOOK_CONTEXT_SCHEMA_VERSION);
+        assert_eq!(json["event"], "work_start");
+        assert_eq!(json["task_name"], "Test Task");
+        assert_eq!(json["phase"], "Work");
+        assert_eq!(json["duration_secs"], 1500);
+        assert_eq!(json["remaining_secs"], 1500);
+        assert_eq!(json["cycle"], 1);
+        assert_eq!(json["total_cycles"], 4);
+        assert_eq!(json["timestamp"], "2023-01-01T00:00:00Z");
+        assert_eq!(json["session_id"], "00000000-0000-0000-0000-000000000000");
+        assert_eq!(json["config"]["work_minutes"], 25);
+        assert_eq!(json["config"]["auto_cycle"], false);
+    }
+
+    #[test]
+    fn test_to_json_keeps_raw_task_name() {
+        let mut context = create_test_context();
+        context.task_name = Some("レビュー; $HOME \"quoted\"".to_string());
+
+        let json: serde_json::Value = serde_json::from_slice(&context.to_json().unwrap()).unwrap();
+
+        assert_eq!(json["task_name"], "レビュー; $HOME \"quoted\"");
+    }
+
+    #[test]
+    fn test_to_json_no_task_name() {
+        let mut context = create_test_context();
+        context.task_name = None;
+
+        let json: serde_json::Value = serde_json::from_slice(&context.to_json().unwrap()).unwrap();
+
+        assert!(json["task_name"].is_null());
+    }
 }
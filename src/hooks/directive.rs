@@ -0,0 +1,284 @@
+//! フックからタイマーへの指示
+//!
+//! フックは標準出力（HTTPフックはレスポンスのボディ）に `{"pomodoro": {...}}` 形式のJSONを
+//! 出力することで、デーモンにタスク名の変更・タグの追加・通知・タイマーの操作を指示できる。
+//! 出力全体、または出力の最後の行がこの形式のJSONの場合のみ指示として扱い、
+//! それ以外の出力（`pomodoro` キーを持たないJSONを含む）は従来どおりログに記録するだけにする。
+//!
+//! 指示は成功したフックのものだけをデーモンに送り、デーモンが操作の完了後に適用する。
+
+use crate::types::HookEvent;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `extend_secs` の上限（秒）
+pub const MAX_EXTEND_SECS: u64 = 60 * 60;
+
+/// タスク名の最大文字数（`pomodoro start --task` と同じ）
+const MAX_TASK_NAME_CHARS: usize = 100;
+
+/// 通知のタイトル・本文の最大文字数
+const MAX_NOTIFY_CHARS: usize = 200;
+
+/// フックからの指示（`{"pomodoro": {...}}` の中身）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HookDirective {
+    /// タスク名を変更する
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_name: Option<String>,
+
+    /// タスク名に `#タグ` を追加する（既にあるタグは追加しない）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub add_tags: Vec<String>,
+
+    /// 通知を送信する
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify: Option<DirectiveNotification>,
+
+    /// タイマーを操作する
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<DirectiveAction>,
+
+    /// 現在のフェーズの残り時間を延長する（秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extend_secs: Option<u64>,
+}
+
+/// 指示によるタイマーの操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DirectiveAction {
+    /// 一時停止
+    Pause,
+    /// 現在のフェーズをスキップ
+    Skip,
+}
+
+/// 指示による通知
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DirectiveNotification {
+    /// タイトル（省略時はアプリ名）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 本文
+    pub message: String,
+}
+
+/// デーモンに送る指示
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookDirectiveRequest {
+    /// 指示を出したフックを発火したタイマーのセッションID
+    pub session_id: Uuid,
+    /// フックを発火したイベント
+    pub event: HookEvent,
+    /// フック名
+    pub hook: String,
+    /// 指示
+    pub directive: HookDirective,
+}
+
+impl HookDirective {
+    /// フックの出力から指示を取り出す
+    ///
+    /// # Returns
+    ///
+    /// 指示がない場合はNone
+    ///
+    /// # Errors
+    ///
+    /// `pomodoro` キーはあるが、指示の形式・値が不正な場合
+    pub fn parse_output(output: &str) -> Result<Option<Self>, String> {
+        let output = output.trim();
+        let last_line = output.lines().next_back().unwrap_or("").trim();
+
+        let value = [output, last_line]
+            .into_iter()
+            .filter(|candidate| candidate.starts_with('{'))
+            .find_map(|candidate| serde_json::from_str::<serde_json::Value>(candidate).ok());
+        let Some(directive) = value.as_ref().and_then(|value| value.get("pomodoro")) else {
+            return Ok(None);
+        };
+
+        let directive =
+            Self::deserialize(directive).map_err(|e| format!("指示の形式が不正です: {}", e))?;
+        directive.validate()?;
+        Ok(Some(directive))
+    }
+
+    /// 指示の値を検証する
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(task_name) = &self.task_name {
+            if task_name.trim().is_empty() {
+                return Err("task_name が空です".to_string());
+            }
+            if task_name.chars().count() > MAX_TASK_NAME_CHARS {
+                return Err(format!(
+                    "task_name は{}文字以内にしてください",
+                    MAX_TASK_NAME_CHARS
+                ));
+            }
+        }
+        for tag in &self.add_tags {
+            let tag = tag.trim_start_matches('#');
+            if tag.is_empty() || tag.contains(char::is_whitespace) {
+                return Err(format!("add_tags の値が不正です: '{}'", tag));
+            }
+        }
+        if let Some(notify) = &self.notify {
+            if notify.message.trim().is_empty() {
+                return Err("notify.message が空です".to_string());
+            }
+            let too_long = |text: &str| text.chars().count() > MAX_NOTIFY_CHARS;
+            if too_long(&notify.message) || notify.title.as_deref().is_some_and(too_long) {
+                return Err(format!(
+                    "notify のタイトル・本文は{}文字以内にしてください",
+                    MAX_NOTIFY_CHARS
+                ));
+            }
+        }
+        if let Some(secs) = self.extend_secs {
+            if !(1..=MAX_EXTEND_SECS).contains(&secs) {
+                return Err(format!(
+                    "extend_secs は1〜{}の範囲で指定してください",
+                    MAX_EXTEND_SECS
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// タイマーの状態遷移・残り時間を変える指示を含むか
+    pub fn changes_timer(&self) -> bool {
+        self.action.is_some() || self.extend_secs.is_some()
+    }
+
+    /// 指示を適用した後のタスク名
+    ///
+    /// # Returns
+    ///
+    /// タスク名が変わらない場合はNone
+    pub fn task_name_after(&self, current: Option<&str>) -> Option<String> {
+        let mut task_name = self
+            .task_name
+            .clone()
+            .or_else(|| current.map(str::to_string))
+            .unwrap_or_default();
+        for tag in &self.add_tags {
+            let tag = tag.trim_start_matches('#');
+            let exists = task_name
+                .split_whitespace()
+                .any(|word| word.strip_prefix('#') == Some(tag));
+            if !exists {
+                if !task_name.is_empty() {
+                    task_name.push(' ');
+                }
+                task_name.push('#');
+                task_name.push_str(tag);
+            }
+        }
+
+        (!task_name.is_empty() && Some(task_name.as_str()) != current).then_some(task_name)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_output_without_directive() {
+        assert_eq!(HookDirective::parse_output(""), Ok(None));
+        assert_eq!(HookDirective::parse_output("通知しました\n"), Ok(None));
+        assert_eq!(HookDirective::parse_output(r#"{"ok": true}"#), Ok(None));
+        assert_eq!(HookDirective::parse_output("{壊れたJSON"), Ok(None));
+    }
+
+    #[test]
+    fn test_parse_output_whole_json() {
+        let output = r#"{
+            "pomodoro": {
+                "task_name": "PROJ-123 ログイン修正",
+                "notify": {"message": "次のチケットを取得しました"}
+            }
+        }"#;
+
+        let directive = HookDirective::parse_output(output).unwrap().unwrap();
+
+        assert_eq!(
+            directive.task_name.as_deref(),
+            Some("PROJ-123 ログイン修正")
+        );
+        assert_eq!(
+            directive.notify,
+            Some(DirectiveNotification {
+                title: None,
+                message: "次のチケットを取得しました".to_string()
+            })
+        );
+        assert!(!directive.changes_timer());
+    }
+
+    #[test]
+    fn test_parse_output_last_line() {
+        let output =
+            "チケットを取得中...\n{\"pomodoro\": {\"action\": \"pause\", \"extend_secs\": 300}}\n";
+
+        let directive = HookDirective::parse_output(output).unwrap().unwrap();
+
+        assert_eq!(directive.action, Some(DirectiveAction::Pause));
+        assert_eq!(directive.extend_secs, Some(300));
+        assert!(directive.changes_timer());
+    }
+
+    #[test]
+    fn test_parse_output_rejects_invalid_directive() {
+        let cases = [
+            (r#"{"pomodoro": {"action": "stop"}}"#, "形式が不正"),
+            (r#"{"pomodoro": {"task": "x"}}"#, "形式が不正"),
+            (r#"{"pomodoro": {"task_name": " "}}"#, "task_name が空"),
+            (r#"{"pomodoro": {"add_tags": ["a b"]}}"#, "add_tags"),
+            (
+                r#"{"pomodoro": {"notify": {"message": ""}}}"#,
+                "notify.message",
+            ),
+            (r#"{"pomodoro": {"extend_secs": 0}}"#, "extend_secs"),
+            (r#"{"pomodoro": {"extend_secs": 7200}}"#, "extend_secs"),
+        ];
+        for (output, expected) in cases {
+            let err = HookDirective::parse_output(output).unwrap_err();
+            assert!(err.contains(expected), "{}: {}", output, err);
+        }
+    }
+
+    #[test]
+    fn test_task_name_after() {
+        let tags = HookDirective {
+            add_tags: vec!["bug".to_string(), "#urgent".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            tags.task_name_after(Some("ログイン修正 #bug")),
+            Some("ログイン修正 #bug #urgent".to_string())
+        );
+        assert_eq!(tags.task_name_after(None), Some("#bug #urgent".to_string()));
+        assert_eq!(tags.task_name_after(Some("x #bug #urgent")), None);
+
+        let rename = HookDirective {
+            task_name: Some("PROJ-1".to_string()),
+            add_tags: vec!["api".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            rename.task_name_after(Some("old")),
+            Some("PROJ-1 #api".to_string())
+        );
+
+        assert_eq!(HookDirective::default().task_name_after(Some("same")), None);
+    }
+}
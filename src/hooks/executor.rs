@@ -1,17 +1,233 @@
-use crate::hooks::{HookConfig, HookConfigError, HookContext, HookDefinition};
+use crate::hooks::log::truncate_output;
+use crate::hooks::template;
+use crate::hooks::{
+    HookConfig, HookConfigError, HookContext, HookDefinition, HookDirective, HookDirectiveRequest,
+    HookKind, HookRunLog, HookRunRecord, HttpHook, OnError,
+};
+use crate::metrics::Metrics;
+use crate::paths;
+use crate::types::{HookEvent, HookFailure};
+use chrono::Utc;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard};
 use std::time::Duration;
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
-use tokio::time::timeout;
+use tokio::sync::{mpsc, Semaphore};
+use tokio::time::{sleep, timeout, Instant};
 use tracing::{debug, error, info, warn};
 
 /// フック実行機能
 #[derive(Debug, Clone)]
 pub struct HookExecutor {
-    config: HookConfig,
+    /// フック設定（再読み込みで差し替える）
+    config: Arc<RwLock<HookConfig>>,
     enabled: bool,
+    /// 実行中のフック（イベント単位）の数
+    in_flight: Arc<AtomicUsize>,
+    /// フックに `POMODORO_SOCKET` として渡すデーモンのソケットパス
+    socket_path: Option<PathBuf>,
+    /// 実行結果の記録先
+    metrics: Option<Arc<Metrics>>,
+    /// 実行記録（`pomodoro hooks logs` で参照する）の書き込み先
+    run_log: Option<Arc<HookRunLog>>,
+    /// フックの出力に含まれていた指示の送信先（Noneの場合は指示を無視する）
+    directives: Option<mpsc::UnboundedSender<HookDirectiveRequest>>,
+    /// 再実行しても失敗したままのフック（フック名 -> 最後の失敗）
+    failures: Arc<Mutex<BTreeMap<String, HookFailure>>>,
+    /// HTTPフック用のクライアント（接続を使い回す）
+    http_client: reqwest::Client,
+    /// 同時実行数の制限（上限値とセマフォ。上限が変わった再読み込みで差し替える）
+    limiter: Arc<RwLock<(usize, Arc<Semaphore>)>>,
+}
+
+/// 事前フックの実行時間の上限（秒、1回の操作で実行するすべての事前フックの合計）
+///
+/// 事前フックは操作を要求したクライアントを待たせるため、クライアントのタイムアウト（5秒）より
+/// 短く打ち切り、拒否の理由がクライアントに必ず届くようにする。`timeout_secs` より優先する。
+pub const MAX_PRE_HOOK_TOTAL_SECS: u64 = 4;
+
+/// HTTPフックで実行回数（1から始まる）を渡すヘッダー（スクリプトでは `POMODORO_HOOK_ATTEMPT`）
+const HOOK_ATTEMPT_HEADER: &str = "X-Pomodoro-Hook-Attempt";
+
+/// フックの実行結果（切り詰める前の出力を含む）
+#[derive(Debug, Default)]
+struct HookOutcome {
+    /// 終了コード（HTTPフックはステータスコード）
+    exit_code: Option<i32>,
+    /// 標準出力（HTTPフックはレスポンスのボディ）
+    stdout: String,
+    /// 標準エラー出力
+    stderr: String,
+    /// 失敗の内容（成功した場合はNone）
+    error: Option<String>,
+}
+
+impl HookOutcome {
+    /// 出力を得られずに失敗した結果
+    fn failed(message: String) -> Self {
+        Self {
+            error: Some(message),
+            ..Self::default()
+        }
+    }
+}
+
+/// 1つのフックの実行に必要なもの（実行タスク間で共有する）
+#[derive(Clone)]
+struct HookRunner {
+    socket_path: Option<PathBuf>,
+    metrics: Option<Arc<Metrics>>,
+    run_log: Option<Arc<HookRunLog>>,
+    directives: Option<mpsc::UnboundedSender<HookDirectiveRequest>>,
+    failures: Arc<Mutex<BTreeMap<String, HookFailure>>>,
+    http_client: reqwest::Client,
+    limiter: Arc<Semaphore>,
+}
+
+impl HookRunner {
+    /// 同時実行数の枠を確保してフックを実行し、結果を記録する
+    ///
+    /// 失敗した場合は `retries` 回まで、待ち時間を倍にしながら再実行する。
+    /// 実行記録には毎回の結果を書き込み、メトリクスには最後の実行の結果だけを記録する。
+    ///
+    /// # Returns
+    ///
+    /// フックが成功した場合はtrue
+    async fn run(&self, hook: &HookDefinition, context: &HookContext) -> bool {
+        let max_attempts = hook.max_attempts();
+        let mut attempt = 1;
+        let record = loop {
+            let record = {
+                // セマフォは閉じないため、取得に失敗することはない
+                // （再実行を待つ間は枠を空けておく）
+                let _permit = self.limiter.acquire().await.ok();
+
+                // 各フックは必ず timeout_secs を持つ（デフォルト値30秒）
+                let (record, _) = HookExecutor::run_and_record(
+                    hook,
+                    context,
+                    attempt,
+                    Duration::from_secs(hook.timeout_secs),
+                    self.socket_path.as_deref(),
+                    &self.http_client,
+                )
+                .await;
+                record
+            };
+            let last = record.success || attempt >= max_attempts;
+            let metrics = if last { self.metrics.as_deref() } else { None };
+            record_run(metrics, self.run_log.as_deref(), &record);
+            if last {
+                break record;
+            }
+
+            let delay = hook.retry_delay(attempt);
+            warn!(
+                "フック実行エラー ({}、{}/{}回目): {}。{}秒後に再実行します",
+                hook.name,
+                attempt,
+                max_attempts,
+                record.error.as_deref().unwrap_or_default(),
+                delay.as_secs()
+            );
+            sleep(delay).await;
+            attempt += 1;
+        };
+
+        let mut failures = self.failures.lock().unwrap_or_else(|e| e.into_inner());
+        match &record.error {
+            Some(error) => {
+                error!("フック実行エラー ({}): {}", hook.name, error);
+                failures.insert(
+                    hook.name.clone(),
+                    HookFailure {
+                        hook: hook.name.clone(),
+                        event: context.event,
+                        attempts: attempt,
+                        error: error.clone(),
+                        timestamp: record.timestamp,
+                    },
+                );
+            }
+            None => {
+                failures.remove(&hook.name);
+            }
+        }
+        drop(failures);
+
+        if let Some(request) = directive_request(context, &record) {
+            send_directive(self.directives.as_ref(), request);
+        }
+        record.success
+    }
+}
+
+/// フックの出力に指示があれば、デーモンに送る指示を作成する（指示は成功したフックのみが持つ）
+fn directive_request(
+    context: &HookContext,
+    record: &HookRunRecord,
+) -> Option<HookDirectiveRequest> {
+    let directive = record.directive.clone()?;
+    Some(HookDirectiveRequest {
+        session_id: context.session_id,
+        event: context.event,
+        hook: record.hook.clone(),
+        directive,
+    })
+}
+
+/// 指示をデーモンに送る（送信先がない場合は無視する）
+fn send_directive(
+    directives: Option<&mpsc::UnboundedSender<HookDirectiveRequest>>,
+    request: HookDirectiveRequest,
+) {
+    match directives {
+        Some(directives) => {
+            info!("フック '{}' の指示を送信します", request.hook);
+            if directives.send(request).is_err() {
+                warn!("フックの指示の送信先が閉じています");
+            }
+        }
+        None => debug!("フック '{}' の指示は無視しました", request.hook),
+    }
+}
+
+/// 実行結果をメトリクスと実行記録に書き込む
+fn record_run(metrics: Option<&Metrics>, run_log: Option<&HookRunLog>, record: &HookRunRecord) {
+    if let Some(metrics) = metrics {
+        metrics.record_hook(
+            &record.hook,
+            record.success,
+            Duration::from_millis(record.duration_ms),
+        );
+    }
+    if let Some(run_log) = run_log {
+        if let Err(e) = run_log.append(record) {
+            warn!("フックの実行記録を書き込めません: {}", e);
+        }
+    }
+}
+
+/// 実行中フック数のカウンタ（ドロップ時に減算）
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl InFlightGuard {
+    fn new(counter: &Arc<AtomicUsize>) -> Self {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Self(counter.clone())
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 impl Default for HookExecutor {
@@ -24,101 +240,501 @@ impl HookExecutor {
     /// 新しいHookExecutorを作成
     ///
     /// ~/.pomodoro/hooks.json から設定を読み込む。
-    /// ファイルが存在しない、または読み込みエラーの場合は無効状態で初期化する。
+    /// ファイルが存在しない、または読み込みエラーの場合はフックなしで初期化する
+    /// （後から `reload` で読み込み直せる）。
     pub fn new() -> Self {
-        match HookConfig::load() {
+        let config = match HookConfig::load() {
             Ok(config) => {
-                let enabled = config.has_hooks();
                 debug!(
                     "フック設定を読み込みました: {} フック登録",
                     config.hooks.len()
                 );
-                Self { config, enabled }
+                config
             }
             Err(HookConfigError::FileNotFound(path)) => {
                 debug!("フック設定ファイルが見つかりません: {:?}", path);
-                Self {
-                    config: HookConfig::default(),
-                    enabled: false,
-                }
+                HookConfig::default()
             }
             Err(e) => {
                 warn!("フック設定の読み込みに失敗しました: {}", e);
-                Self {
-                    config: HookConfig::default(),
-                    enabled: false,
-                }
+                HookConfig::default()
             }
-        }
+        };
+        Self::with_config(config)
     }
 
     /// テスト用に設定を指定して作成
     pub fn with_config(config: HookConfig) -> Self {
+        let max_concurrent = config.defaults.max_concurrent;
         Self {
-            config,
+            limiter: Arc::new(RwLock::new((
+                max_concurrent,
+                Arc::new(Semaphore::new(max_concurrent)),
+            ))),
+            config: Arc::new(RwLock::new(config)),
             enabled: true,
+            in_flight: Arc::default(),
+            socket_path: None,
+            metrics: None,
+            run_log: None,
+            directives: None,
+            failures: Arc::default(),
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// フックに渡すデーモンのソケットパスを設定
+    ///
+    /// フックから `pomodoro` コマンドを実行したときに、同じデーモンへ接続できるようにする。
+    pub fn with_socket_path(mut self, socket_path: PathBuf) -> Self {
+        self.socket_path = Some(socket_path);
+        self
+    }
+
+    /// フックの実行回数・失敗回数・実行時間の記録先を設定
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// フックの実行記録の書き込み先を設定
+    pub fn with_run_log(mut self, run_log: Arc<HookRunLog>) -> Self {
+        self.run_log = Some(run_log);
+        self
+    }
+
+    /// フックの出力に含まれていた指示（`HookDirective`）の送信先を設定
+    ///
+    /// 設定しない場合、指示は実行記録に残るだけで適用されない。
+    pub fn with_directive_sender(
+        mut self,
+        directives: mpsc::UnboundedSender<HookDirectiveRequest>,
+    ) -> Self {
+        self.directives = Some(directives);
+        self
+    }
+
+    /// デフォルトパス (`~/.pomodoro/hooks.json`) から設定を再読み込みする
+    ///
+    /// 詳細は `reload_from_path` を参照。
+    pub fn reload(&self) -> Result<usize, HookConfigError> {
+        let path = HookConfig::default_config_path()?;
+        self.reload_from_path(&path)
+    }
+
+    /// 指定されたパスから設定を再読み込みする
+    ///
+    /// 新しい設定の検証に成功した場合のみ差し替える。失敗した場合は現在の設定を維持する。
+    /// ファイルが存在しない場合はフックなしの設定に差し替える。
+    /// 実行中のフックは読み込み前の設定のまま完了する。
+    ///
+    /// # Returns
+    ///
+    /// 新しい設定に登録されたフックの数
+    pub fn reload_from_path(&self, path: &Path) -> Result<usize, HookConfigError> {
+        let config = Self::load_for_reload(path)?;
+        let count = config.hooks.len();
+        self.replace_config(config);
+        Ok(count)
+    }
+
+    /// 再読み込み用に設定を読み込んで検証する（差し替えはしない）
+    ///
+    /// ファイルが存在しない場合はフックなしの設定を返す。
+    pub fn load_for_reload(path: &Path) -> Result<HookConfig, HookConfigError> {
+        match HookConfig::load_from_path(path) {
+            Ok(config) => Ok(config),
+            Err(HookConfigError::FileNotFound(_)) => Ok(HookConfig::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 設定を差し替える
+    ///
+    /// 同時実行数の上限が変わった場合は新しい上限を以降の実行に適用する
+    /// （実行中・待機中のフックは以前の上限のまま完了する）。
+    pub fn replace_config(&self, config: HookConfig) {
+        let max_concurrent = config.defaults.max_concurrent;
+        {
+            let mut limiter = self.limiter.write().unwrap_or_else(|e| e.into_inner());
+            if limiter.0 != max_concurrent {
+                *limiter = (max_concurrent, Arc::new(Semaphore::new(max_concurrent)));
+            }
+        }
+        // 設定から削除されたフックの失敗は表示しない
+        self.failures
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|name, _| config.hooks.iter().any(|hook| &hook.name == name));
+        let mut current = self.config.write().unwrap_or_else(|e| e.into_inner());
+        *current = config;
+    }
+
+    /// 登録されているフックの数
+    pub fn hook_count(&self) -> usize {
+        self.current_config().hooks.len()
+    }
+
+    /// 指定したイベントのフックが登録されているか（`when` の条件は評価しない）
+    pub fn has_hooks_for(&self, event: &HookEvent) -> bool {
+        self.enabled
+            && self
+                .current_config()
+                .get_hooks_for_event(event)
+                .is_some_and(|hooks| !hooks.is_empty())
+    }
+
+    /// 現在の設定を参照する
+    fn current_config(&self) -> RwLockReadGuard<'_, HookConfig> {
+        self.config.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 再実行しても失敗したままのフック（新しい順）
+    ///
+    /// 同じフックが次に成功するか、設定から削除されると一覧から外れる。
+    /// 事前フックの失敗（操作の拒否）は含まない。
+    pub fn hook_failures(&self) -> Vec<HookFailure> {
+        let mut failures: Vec<HookFailure> = self
+            .failures
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect();
+        failures.sort_by_key(|failure| Reverse(failure.timestamp));
+        failures
+    }
+
+    /// 実行中のフック（イベント単位）の数
+    pub fn pending_count(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// 実行中のフックの完了を待つ
+    ///
+    /// デーモン終了時に使用する。`max_wait` を過ぎても完了しない場合は待つのをやめる。
+    ///
+    /// # Returns
+    ///
+    /// すべてのフックが完了した場合はtrue
+    pub async fn wait_for_pending(&self, max_wait: Duration) -> bool {
+        let deadline = Instant::now() + max_wait;
+        while self.pending_count() > 0 {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            sleep(Duration::from_millis(50)).await;
         }
+        true
     }
 
     /// フックを実行（非同期・Fire-and-forget）
+    ///
+    /// フックは優先度順に1つずつ実行する（`events` で `parallel` を指定したイベントは並列に実行する）。
+    /// 順次実行では `on_error: "stop"` のフックが失敗した時点で後続のフックを実行しない。
+    /// どちらの場合も、全イベントを通じた同時実行数は `defaults.max_concurrent` までに制限する。
     pub fn execute(&self, context: HookContext) {
         if !self.enabled {
             return;
         }
 
-        if let Some(hooks) = self.config.get_hooks_for_event(&context.event) {
-            if hooks.is_empty() {
-                return;
+        let (hooks, parallel) = {
+            let config = self.current_config();
+            match config.get_hooks_for_event(&context.event) {
+                Some(hooks) => (
+                    Self::filter_by_condition(hooks, &context),
+                    config.is_parallel(&context.event),
+                ),
+                None => return,
             }
+        };
 
-            let hooks = hooks.clone();
-            let default_timeout = self.config.default_timeout();
-            let context = context.clone();
+        if hooks.is_empty() {
+            return;
+        }
 
-            // Fire-and-forget execution
-            tokio::spawn(async move {
-                for hook in hooks {
-                    // 注: get_hooks_for_event は既に enabled=true のみを返す
-                    if let Err(e) =
-                        Self::execute_single_hook(&hook, &context, default_timeout).await
-                    {
-                        error!("フック実行エラー ({}): {}", hook.name, e);
+        let guard = InFlightGuard::new(&self.in_flight);
+        let runner = HookRunner {
+            socket_path: self.socket_path.clone(),
+            metrics: self.metrics.clone(),
+            run_log: self.run_log.clone(),
+            directives: self.directives.clone(),
+            failures: self.failures.clone(),
+            http_client: self.http_client.clone(),
+            limiter: self
+                .limiter
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .1
+                .clone(),
+        };
+
+        // Fire-and-forget execution
+        // 注: get_hooks_for_event は既に enabled=true のみを優先度順に返す
+        tokio::spawn(async move {
+            let _guard = guard;
+            if parallel {
+                let context = Arc::new(context);
+                let handles: Vec<_> = hooks
+                    .into_iter()
+                    .map(|hook| {
+                        let runner = runner.clone();
+                        let context = context.clone();
+                        tokio::spawn(async move { runner.run(&hook, &context).await })
+                    })
+                    .collect();
+                for handle in handles {
+                    let _ = handle.await;
+                }
+            } else {
+                let mut remaining = hooks.iter();
+                while let Some(hook) = remaining.next() {
+                    if !runner.run(hook, &context).await && hook.on_error == OnError::Stop {
+                        let skipped: Vec<&str> = remaining.map(|hook| hook.name.as_str()).collect();
+                        if !skipped.is_empty() {
+                            warn!(
+                                "フック '{}' が失敗したため後続のフックをスキップしました: {:?}",
+                                hook.name, skipped
+                            );
+                        }
+                        break;
                     }
                 }
-            });
+            }
+        });
+    }
+
+    /// `when` の条件を満たすフックだけを取り出す
+    fn filter_by_condition(hooks: &[HookDefinition], context: &HookContext) -> Vec<HookDefinition> {
+        hooks
+            .iter()
+            .filter(|hook| match &hook.when {
+                Some(when) if !when.matches(context) => {
+                    debug!("実行条件を満たさないためスキップ: {}", hook.name);
+                    false
+                }
+                _ => true,
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// 事前フックを実行する（同期・拒否可能）
+    ///
+    /// タイマーの状態遷移の前に呼び出し、完了を待つ。フックは優先度順に1つずつ実行し、
+    /// 失敗（非ゼロの終了コード、2xx以外のレスポンス、タイムアウトなど）したフックがあれば
+    /// その時点で中止する。`deadline` は1回の操作で実行するすべての事前フックの期限で、
+    /// 呼び出し側が `MAX_PRE_HOOK_TOTAL_SECS` 後に設定する。期限を過ぎた場合も中止する。
+    /// 同時実行数の制限は適用しない（操作を要求したクライアントが完了を待っているため）。
+    ///
+    /// # Returns
+    ///
+    /// フックの出力に含まれていた指示。指示はデーモンに送らず、呼び出し側が遷移を適用した後に
+    /// 適用する（遷移が中止された場合は適用しない）
+    ///
+    /// # Errors
+    ///
+    /// フックが失敗した場合、遷移を中止する理由（フックの標準エラー出力、なければ失敗の内容）
+    pub async fn run_pre_hooks(
+        &self,
+        context: &HookContext,
+        deadline: Instant,
+    ) -> Result<Vec<HookDirectiveRequest>, String> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+
+        let hooks = {
+            let config = self.current_config();
+            match config.get_hooks_for_event(&context.event) {
+                Some(hooks) => Self::filter_by_condition(hooks, context),
+                None => return Ok(Vec::new()),
+            }
+        };
+
+        let mut requests = Vec::new();
+        for hook in hooks {
+            let _guard = InFlightGuard::new(&self.in_flight);
+            // 残り時間が各フックの timeout_secs より短ければ残り時間で打ち切る
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let limit = Duration::from_secs(hook.timeout_secs).min(remaining);
+            let (record, outcome) = Self::run_and_record(
+                &hook,
+                context,
+                1,
+                limit,
+                self.socket_path.as_deref(),
+                &self.http_client,
+            )
+            .await;
+            record_run(self.metrics.as_deref(), self.run_log.as_deref(), &record);
+
+            if let Some(mut error) = outcome.error {
+                if limit < Duration::from_secs(hook.timeout_secs) && Instant::now() >= deadline {
+                    error = format!(
+                        "事前フックの実行時間が合計{}秒を超えました",
+                        MAX_PRE_HOOK_TOTAL_SECS
+                    );
+                }
+                warn!(
+                    "事前フック '{}' により {} を中止しました: {}",
+                    hook.name,
+                    context.event.as_str(),
+                    error
+                );
+                // HTTPフックはレスポンスのボディ、それ以外は標準エラー出力を理由にする
+                let output = match hook.kind {
+                    HookKind::Http => outcome.stdout.trim(),
+                    HookKind::Script => outcome.stderr.trim(),
+                };
+                return Err(if output.is_empty() {
+                    format!("フック '{}' が失敗しました: {}", hook.name, error)
+                } else {
+                    output.to_string()
+                });
+            }
+            requests.extend(directive_request(context, &record));
         }
+        Ok(requests)
+    }
+
+    /// 指示をデーモンに送る（事前フックの指示のうち、遷移と同時に適用しなかったもの）
+    pub fn send_directive(&self, request: HookDirectiveRequest) {
+        send_directive(self.directives.as_ref(), request);
+    }
+
+    /// 1つのフックを実行して結果を返す（`pomodoro hooks test` 用）
+    ///
+    /// `when` の条件、同時実行数の制限、再実行は適用せず、メトリクス・実行記録にも書き込まない。
+    /// 事前フックのイベントではタイムアウトを `MAX_PRE_HOOK_TOTAL_SECS` までに制限する。
+    pub async fn run_once(&self, hook: &HookDefinition, context: &HookContext) -> HookRunRecord {
+        let timeout_secs = if context.event.is_pre_transition() {
+            hook.timeout_secs.min(MAX_PRE_HOOK_TOTAL_SECS)
+        } else {
+            hook.timeout_secs
+        };
+        let (record, _) = Self::run_and_record(
+            hook,
+            context,
+            1,
+            Duration::from_secs(timeout_secs),
+            self.socket_path.as_deref(),
+            &self.http_client,
+        )
+        .await;
+        record
+    }
+
+    /// フックを実行し、実行記録（出力は切り詰める）と実行結果を返す
+    ///
+    /// 成功したフックの出力（HTTPフックはレスポンスのボディ）に指示があれば実行記録に含める。
+    /// `attempt` は何回目の実行か（1から始まる）。
+    async fn run_and_record(
+        hook: &HookDefinition,
+        context: &HookContext,
+        attempt: u32,
+        limit: Duration,
+        socket_path: Option<&Path>,
+        http_client: &reqwest::Client,
+    ) -> (HookRunRecord, HookOutcome) {
+        let timestamp = Utc::now();
+        let started = Instant::now();
+        let outcome = Self::run_hook(hook, context, attempt, limit, socket_path, http_client).await;
+        let directive = match outcome.error {
+            Some(_) => None,
+            None => HookDirective::parse_output(&outcome.stdout).unwrap_or_else(|e| {
+                warn!("フック '{}' の指示を無視しました: {}", hook.name, e);
+                None
+            }),
+        };
+        let record = HookRunRecord {
+            timestamp,
+            event: context.event,
+            hook: hook.name.clone(),
+            kind: hook.kind,
+            attempt,
+            success: outcome.error.is_none(),
+            exit_code: outcome.exit_code,
+            duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+            error: outcome.error.clone(),
+            stdout: truncate_output(&outcome.stdout),
+            stderr: truncate_output(&outcome.stderr),
+            directive,
+        };
+        (record, outcome)
+    }
+
+    /// 種類に応じてフックを実行
+    async fn run_hook(
+        hook: &HookDefinition,
+        context: &HookContext,
+        attempt: u32,
+        limit: Duration,
+        socket_path: Option<&Path>,
+        http_client: &reqwest::Client,
+    ) -> HookOutcome {
+        let result = match (hook.kind, &hook.http) {
+            (HookKind::Http, Some(http)) => {
+                Self::execute_http_hook(hook, http, context, attempt, limit, http_client).await
+            }
+            (HookKind::Http, None) => Err("http の設定がありません".to_string()),
+            (HookKind::Script, _) => {
+                Self::execute_script_hook(hook, context, attempt, limit, socket_path).await
+            }
+        };
+        result.unwrap_or_else(HookOutcome::failed)
     }
 
-    /// 単一のフックを実行
-    async fn execute_single_hook(
+    /// スクリプトフック（script・command・shell）を実行
+    async fn execute_script_hook(
         hook: &HookDefinition,
         context: &HookContext,
-        _default_timeout: u64,
-    ) -> Result<(), String> {
-        Self::validate_script(&hook.script)?;
+        attempt: u32,
+        limit: Duration,
+        socket_path: Option<&Path>,
+    ) -> Result<HookOutcome, String> {
+        let mut command = Self::build_command(hook, context)?;
 
-        // 各フックは必ず timeout_secs を持つ（デフォルト値30秒）
-        let timeout_secs = hook.timeout_secs;
         let env_vars = context.to_env_vars();
+        let payload = context.to_json().map_err(|e| e.to_string())?;
 
-        info!("フック実行開始: {} (timeout: {}s)", hook.name, timeout_secs);
+        info!("フック実行開始: {} (timeout: {:?})", hook.name, limit);
 
-        let mut command = Command::new(&hook.script);
+        // フックの env の後に設定し、POMODORO_* は上書きさせない
         command
             .envs(&env_vars)
             .env("POMODORO_HOOK_NAME", &hook.name)
+            .env("POMODORO_HOOK_ATTEMPT", attempt.to_string())
+            .stdin(Stdio::piped())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+            .stderr(Stdio::piped())
+            // タイムアウトで待機を打ち切ったときにプロセスを残さない
+            .kill_on_drop(true);
+        // シェルから起動した子プロセスもまとめて終了できるよう、プロセスグループを分ける
+        #[cfg(unix)]
+        command.process_group(0);
+        if let Some(socket_path) = socket_path {
+            command.env(paths::SOCKET_ENV, socket_path);
+        }
+
+        let mut child = command.spawn().map_err(|e| e.to_string())?;
+        #[cfg(unix)]
+        let pid = child.id();
+
+        // コンテキストのJSONを標準入力に書き込んで閉じる
+        // （出力の読み取りと並行して書き込み、標準入力を読まないスクリプトはエラーにしない）
+        if let Some(mut stdin) = child.stdin.take() {
+            tokio::spawn(async move {
+                if let Err(e) = stdin.write_all(&payload).await {
+                    if e.kind() != std::io::ErrorKind::BrokenPipe {
+                        debug!("フックの標準入力への書き込みに失敗しました: {}", e);
+                    }
+                }
+            });
+        }
 
         // Execute with timeout
-        let child_result = timeout(
-            Duration::from_secs(timeout_secs),
-            command
-                .spawn()
-                .map_err(|e| e.to_string())?
-                .wait_with_output(),
-        )
-        .await;
+        let child_result = timeout(limit, child.wait_with_output()).await;
 
         match child_result {
             Ok(Ok(output)) => {
@@ -126,18 +742,157 @@ impl HookExecutor {
                 Self::log_output(&hook.name, "stdout", &output.stdout);
                 Self::log_output(&hook.name, "stderr", &output.stderr);
 
-                if status.success() {
+                let error = if status.success() {
                     info!("フック実行成功: {}", hook.name);
-                    Ok(())
+                    None
                 } else {
-                    Err(format!(
+                    Some(format!(
                         "スクリプトが非ゼロの終了コードで終了しました: {:?}",
                         status.code()
                     ))
-                }
+                };
+                Ok(HookOutcome {
+                    exit_code: status.code(),
+                    stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+                    stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+                    error,
+                })
             }
             Ok(Err(e)) => Err(format!("プロセス実行エラー: {}", e)),
-            Err(_) => Err(format!("タイムアウトしました ({}秒)", timeout_secs)),
+            Err(_) => {
+                // フック自身は破棄時に終了させる（kill_on_drop）。残った子プロセスはグループごと終了させる
+                #[cfg(unix)]
+                if let Some(pid) = pid {
+                    Self::kill_process_group(pid);
+                }
+                Err(format!("タイムアウトしました ({}秒)", limit.as_secs()))
+            }
+        }
+    }
+
+    /// プロセスグループ内のすべてのプロセスを終了させる
+    #[cfg(unix)]
+    fn kill_process_group(pgid: u32) {
+        let Ok(pgid) = libc::pid_t::try_from(pgid) else {
+            return;
+        };
+        // SAFETY: killは引数のプロセスグループにシグナルを送るだけで、メモリを操作しない
+        if unsafe { libc::killpg(pgid, libc::SIGKILL) } != 0 {
+            let err = std::io::Error::last_os_error();
+            if err.raw_os_error() != Some(libc::ESRCH) {
+                debug!("フックのプロセスグループの終了に失敗しました: {}", err);
+            }
+        }
+    }
+
+    /// 実行するプロセスのコマンドを組み立てる（引数・環境変数のテンプレートを展開）
+    ///
+    /// パスで指定したスクリプト・コマンドと作業ディレクトリは実行前に検証する。
+    /// PATHから検索するコマンド名はそのまま渡す。
+    fn build_command(hook: &HookDefinition, context: &HookContext) -> Result<Command, String> {
+        let render = |value: &String| template::render_plain(value, context, &hook.name);
+
+        let mut command = if let Some(shell) = &hook.shell {
+            let mut command = Command::new("/bin/sh");
+            command.arg("-c").arg(shell);
+            command
+        } else {
+            let program = match &hook.command {
+                Some(program) => PathBuf::from(program),
+                None => hook.script.clone(),
+            };
+            if hook.command.is_none() || program.is_absolute() {
+                Self::validate_script(&program)?;
+            }
+            let args = hook
+                .args
+                .iter()
+                .map(render)
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut command = Command::new(&program);
+            command.args(args);
+            command
+        };
+
+        if let Some(dir) = &hook.working_dir {
+            if !dir.is_dir() {
+                return Err(format!("作業ディレクトリが存在しません: {:?}", dir));
+            }
+            command.current_dir(dir);
+        }
+        for (name, value) in &hook.env {
+            command.env(name, render(value)?);
+        }
+        Ok(command)
+    }
+
+    /// HTTPフックを実行
+    ///
+    /// `body` を指定しない場合はコンテキスト全体のJSON（スクリプトの標準入力と同じ）を送信する。
+    /// 2xx以外のレスポンスは失敗として扱う。
+    async fn execute_http_hook(
+        hook: &HookDefinition,
+        http: &HttpHook,
+        context: &HookContext,
+        attempt: u32,
+        limit: Duration,
+        client: &reqwest::Client,
+    ) -> Result<HookOutcome, String> {
+        let method = reqwest::Method::from_bytes(http.method.as_bytes())
+            .map_err(|_| format!("無効なHTTPメソッド: {}", http.method))?;
+        let body = match &http.body {
+            Some(body) => template::render_json(body, context, &hook.name)?.into_bytes(),
+            None => context.to_json().map_err(|e| e.to_string())?,
+        };
+
+        let mut request = client
+            .request(method, &http.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(
+                reqwest::header::USER_AGENT,
+                concat!("pomodoro/", env!("CARGO_PKG_VERSION")),
+            )
+            .header(HOOK_ATTEMPT_HEADER, attempt);
+        for (name, value) in &http.headers {
+            request = request.header(name, value);
+        }
+        for (name, secret) in &http.secret_headers {
+            let value = secret
+                .resolve()
+                .map_err(|e| format!("ヘッダー '{}' の値を取得できません: {}", name, e))?;
+            request = request.header(name, value);
+        }
+
+        info!(
+            "フック実行開始: {} ({} {}, timeout: {:?})",
+            hook.name, http.method, http.url, limit
+        );
+
+        let send = async {
+            let response = request.body(body).send().await?;
+            let status = response.status();
+            let text = response.text().await?;
+            Ok::<_, reqwest::Error>((status, text))
+        };
+
+        match timeout(limit, send).await {
+            Ok(Ok((status, text))) => {
+                Self::log_output(&hook.name, "response", text.as_bytes());
+                let error = if status.is_success() {
+                    info!("フック実行成功: {} ({})", hook.name, status);
+                    None
+                } else {
+                    Some(format!("HTTPステータスが成功ではありません: {}", status))
+                };
+                Ok(HookOutcome {
+                    exit_code: Some(i32::from(status.as_u16())),
+                    stdout: text,
+                    stderr: String::new(),
+                    error,
+                })
+            }
+            Ok(Err(e)) => Err(format!("HTTPリクエストエラー: {}", e)),
+            Err(_) => Err(format!("タイムアウトしました ({}秒)", limit.as_secs())),
         }
     }
 
@@ -180,7 +935,7 @@ impl HookExecutor {
         if stream_name == "stderr" {
             warn!("[Hook: {}] stderr: {}", hook_name, log_content);
         } else {
-            info!("[Hook: {}] stdout: {}", hook_name, log_content);
+            info!("[Hook: {}] {}: {}", hook_name, stream_name, log_content);
         }
     }
 }
@@ -188,7 +943,8 @@ impl HookExecutor {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{HookEvent, TimerPhase};
+    use crate::hooks::DirectiveAction;
+    use crate::types::{HookEvent, PomodoroConfig, TimerPhase};
     use chrono::Utc;
     use std::io::Write;
     use std::time::Duration;
@@ -304,6 +1060,7 @@ mod tests {
             total_cycles: 4,
             timestamp: Utc::now(),
             session_id: Uuid::new_v4(),
+            config: PomodoroConfig::default(),
         };
 
         executor.execute(context);
@@ -351,6 +1108,7 @@ mod tests {
             total_cycles: 4,
             timestamp: Utc::now(),
             session_id: Uuid::new_v4(),
+            config: PomodoroConfig::default(),
         };
 
         executor.execute(context);
@@ -377,10 +1135,968 @@ mod tests {
             total_cycles: 4,
             timestamp: Utc::now(),
             session_id: Uuid::new_v4(),
+            config: PomodoroConfig::default(),
         };
 
         executor.execute(context);
 
         tokio::time::sleep(Duration::from_millis(50)).await;
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_wait_for_pending() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let script_path = dir.path().join("slow.sh");
+        fs::write(&script_path, "#!/bin/sh\nsleep 0.3\n").unwrap();
+        fs::set_permissions(&script_path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let executor = HookExecutor::with_config(create_test_config(script_path.to_str().unwrap()));
+        assert_eq!(executor.pending_count(), 0);
+        assert!(executor.wait_for_pending(Duration::from_millis(10)).await);
+
+        executor.execute(HookContext {
+            event: HookEvent::WorkStart,
+            task_name: None,
+            phase: TimerPhase::Working.as_str().to_string(),
+            duration_secs: 1500,
+            elapsed_secs: 0,
+            remaining_secs: 1500,
+            cycle: 1,
+            total_cycles: 4,
+            timestamp: Utc::now(),
+            session_id: Uuid::new_v4(),
+            config: PomodoroConfig::default(),
+        });
+        assert_eq!(executor.pending_count(), 1);
+
+        // 短い待機では完了しない
+        assert!(!executor.wait_for_pending(Duration::from_millis(50)).await);
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+        assert_eq!(executor.pending_count(), 0);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_writes_context_json_to_stdin() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let output_path = dir.path().join("stdin.json");
+        let script_path = dir.path().join("read_stdin.sh");
+        fs::write(
+            &script_path,
+            format!("#!/bin/sh\ncat > {}\n", output_path.display()),
+        )
+        .unwrap();
+        fs::set_permissions(&script_path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let executor = HookExecutor::with_config(create_test_config(script_path.to_str().unwrap()));
+        executor.execute(HookContext {
+            event: HookEvent::WorkStart,
+            task_name: Some("設計; $(rm -rf ~)".to_string()),
+            phase: TimerPhase::Working.as_str().to_string(),
+            duration_secs: 1500,
+            elapsed_secs: 0,
+            remaining_secs: 1500,
+            cycle: 1,
+            total_cycles: 4,
+            timestamp: Utc::now(),
+            session_id: Uuid::new_v4(),
+            config: PomodoroConfig::default(),
+        });
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&output_path).unwrap()).unwrap();
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["event"], "work_start");
+        assert_eq!(json["task_name"], "設計; $(rm -rf ~)");
+        assert_eq!(json["config"]["work_minutes"], 25);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_records_metrics() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let script_path = dir.path().join("fail.sh");
+        fs::write(&script_path, "#!/bin/sh\nexit 1\n").unwrap();
+        fs::set_permissions(&script_path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let metrics = Arc::new(Metrics::new());
+        let executor = HookExecutor::with_config(create_test_config(script_path.to_str().unwrap()))
+            .with_metrics(metrics.clone());
+
+        executor.execute(HookContext {
+            event: HookEvent::WorkStart,
+            task_name: None,
+            phase: TimerPhase::Working.as_str().to_string(),
+            duration_secs: 1500,
+            elapsed_secs: 0,
+            remaining_secs: 1500,
+            cycle: 1,
+            total_cycles: 4,
+            timestamp: Utc::now(),
+            session_id: Uuid::new_v4(),
+            config: PomodoroConfig::default(),
+        });
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        let output = metrics.render(&[]);
+        assert!(output.contains("pomodoro_hook_executions_total{hook=\"test_hook\"} 1\n"));
+        assert!(output.contains("pomodoro_hook_failures_total{hook=\"test_hook\"} 1\n"));
+    }
+
+    #[test]
+    fn test_reload_from_path_replaces_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hooks.json");
+        let executor = HookExecutor::with_config(HookConfig::default());
+        assert_eq!(executor.hook_count(), 0);
+
+        let config = create_test_config("/bin/true");
+        fs::write(&path, serde_json::to_string(&config).unwrap()).unwrap();
+
+        assert_eq!(executor.reload_from_path(&path).unwrap(), 1);
+        assert_eq!(executor.hook_count(), 1);
+    }
+
+    #[test]
+    fn test_reload_from_path_keeps_config_on_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hooks.json");
+        let executor = HookExecutor::with_config(create_test_config("/bin/true"));
+
+        fs::write(&path, "{ invalid json").unwrap();
+        let result = executor.reload_from_path(&path);
+
+        assert!(matches!(result, Err(HookConfigError::ParseError(_))));
+        assert_eq!(executor.hook_count(), 1);
+    }
+
+    #[test]
+    fn test_reload_from_path_missing_file_clears_hooks() {
+        let dir = tempfile::tempdir().unwrap();
+        let executor = HookExecutor::with_config(create_test_config("/bin/true"));
+
+        let count = executor
+            .reload_from_path(&dir.path().join("hooks.json"))
+            .unwrap();
+
+        assert_eq!(count, 0);
+        assert_eq!(executor.hook_count(), 0);
+    }
+
+    #[test]
+    fn test_reload_is_shared_between_clones() {
+        let executor = HookExecutor::with_config(HookConfig::default());
+        let clone = executor.clone();
+
+        executor.replace_config(create_test_config("/bin/true"));
+
+        assert_eq!(clone.hook_count(), 1);
+    }
+
+    /// テスト用のHTTPサーバーが受け取ったリクエスト
+    struct CapturedRequest {
+        head: String,
+        body: String,
+    }
+
+    /// 1リクエストだけ受け付けるHTTPサーバーを起動する
+    ///
+    /// `status` が `None` の場合は応答せずに接続を保持する（タイムアウトの確認用）。
+    async fn spawn_http_server(
+        status: Option<u16>,
+    ) -> (
+        std::net::SocketAddr,
+        tokio::sync::oneshot::Receiver<CapturedRequest>,
+    ) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut data = Vec::new();
+            let mut buf = [0u8; 4096];
+            let (head, body) = loop {
+                let n = stream.read(&mut buf).await.unwrap();
+                data.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&data).to_string();
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let head = text[..end].to_string();
+                    let length = head
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse::<usize>().ok())?
+                        })
+                        .unwrap_or(0);
+                    if data.len() >= end + 4 + length {
+                        break (head, text[end + 4..].to_string());
+                    }
+                }
+                if n == 0 {
+                    break (text, String::new());
+                }
+            };
+            let _ = tx.send(CapturedRequest { head, body });
+
+            match status {
+                Some(status) => {
+                    let response = format!(
+                        "HTTP/1.1 {} Test\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+                        status
+                    );
+                    stream.write_all(response.as_bytes()).await.unwrap();
+                }
+                None => tokio::time::sleep(Duration::from_secs(30)).await,
+            }
+        });
+
+        (addr, rx)
+    }
+
+    fn create_http_test_config(http: &str, timeout_secs: u64) -> HookConfig {
+        let json = format!(
+            r#"{{
+            "version": "1.0",
+            "hooks": [
+                {{
+                    "name": "webhook",
+                    "event": "work_start",
+                    "type": "http",
+                    "http": {},
+                    "timeout_secs": {}
+                }}
+            ]
+        }}"#,
+            http, timeout_secs
+        );
+        HookConfig::parse_and_validate(&json).unwrap()
+    }
+
+    fn create_http_test_context() -> HookContext {
+        HookContext {
+            event: HookEvent::WorkStart,
+            task_name: Some("設計 \"レビュー\"".to_string()),
+            phase: TimerPhase::Working.as_str().to_string(),
+            duration_secs: 1500,
+            elapsed_secs: 0,
+            remaining_secs: 1500,
+            cycle: 1,
+            total_cycles: 4,
+            timestamp: Utc::now(),
+            session_id: Uuid::new_v4(),
+            config: PomodoroConfig::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_http_hook_sends_context_and_headers() {
+        let dir = tempfile::tempdir().unwrap();
+        let token_path = dir.path().join("token");
+        fs::write(&token_path, "file-token\n").unwrap();
+
+        let (addr, request) = spawn_http_server(Some(200)).await;
+        let config = create_http_test_config(
+            &format!(
+                r#"{{
+                    "url": "http://{}/hook",
+                    "headers": {{"X-Source": "pomodoro"}},
+                    "secret_headers": {{
+                        "Authorization": {{"file": "{}", "prefix": "Bearer "}}
+                    }}
+                }}"#,
+                addr,
+                token_path.display()
+            ),
+            5,
+        );
+        let metrics = Arc::new(Metrics::new());
+        let executor = HookExecutor::with_config(config).with_metrics(metrics.clone());
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        let request = request.await.unwrap();
+        assert!(request.head.starts_with("POST /hook HTTP/1.1\r\n"));
+        let head = request.head.to_lowercase();
+        assert!(head.contains("x-source: pomodoro"));
+        assert!(head.contains("authorization: bearer file-token"));
+        assert!(head.contains("content-type: application/json"));
+
+        let json: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["event"], "work_start");
+        assert_eq!(json["task_name"], "設計 \"レビュー\"");
+
+        let output = metrics.render(&[]);
+        assert!(output.contains("pomodoro_hook_failures_total{hook=\"webhook\"} 0\n"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_http_hook_renders_body_template() {
+        let (addr, request) = spawn_http_server(Some(204)).await;
+        let config = create_http_test_config(
+            &format!(
+                r#"{{
+                    "url": "http://{}/",
+                    "method": "PUT",
+                    "body": "{{\"text\": \"{{{{hook_name}}}}: {{{{task_name}}}} #{{{{cycle}}}}\"}}"
+                }}"#,
+                addr
+            ),
+            5,
+        );
+        let executor = HookExecutor::with_config(config);
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        let request = request.await.unwrap();
+        assert!(request.head.starts_with("PUT / HTTP/1.1\r\n"));
+        let json: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(json["text"], "webhook: 設計 \"レビュー\" #1");
+    }
+
+    #[tokio::test]
+    async fn test_execute_http_hook_error_status_is_failure() {
+        let (addr, _request) = spawn_http_server(Some(500)).await;
+        let config = create_http_test_config(&format!(r#"{{"url": "http://{}/"}}"#, addr), 5);
+        let metrics = Arc::new(Metrics::new());
+        let executor = HookExecutor::with_config(config).with_metrics(metrics.clone());
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        let output = metrics.render(&[]);
+        assert!(output.contains("pomodoro_hook_failures_total{hook=\"webhook\"} 1\n"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_http_hook_timeout() {
+        let (addr, _request) = spawn_http_server(None).await;
+        let config = create_http_test_config(&format!(r#"{{"url": "http://{}/"}}"#, addr), 1);
+        let hook = config.hooks[0].clone();
+
+        let started = Instant::now();
+        let outcome = HookExecutor::run_hook(
+            &hook,
+            &create_http_test_context(),
+            1,
+            Duration::from_secs(hook.timeout_secs),
+            None,
+            &reqwest::Client::new(),
+        )
+        .await;
+
+        assert_eq!(outcome.error.unwrap(), "タイムアウトしました (1秒)");
+        assert_eq!(outcome.exit_code, None);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_execute_http_hook_missing_secret_is_failure() {
+        let config = create_http_test_config(
+            r#"{
+                "url": "http://127.0.0.1:9/",
+                "secret_headers": {"Authorization": {"env": "POMODORO_TEST_UNSET_WEBHOOK_TOKEN"}}
+            }"#,
+            5,
+        );
+        let hook = config.hooks[0].clone();
+
+        let outcome = HookExecutor::run_hook(
+            &hook,
+            &create_http_test_context(),
+            1,
+            Duration::from_secs(hook.timeout_secs),
+            None,
+            &reqwest::Client::new(),
+        )
+        .await;
+
+        let err = outcome.error.unwrap();
+        assert!(err.contains("Authorization"));
+        assert!(err.contains("POMODORO_TEST_UNSET_WEBHOOK_TOKEN"));
+    }
+
+    #[test]
+    fn test_filter_by_condition() {
+        let json = r#"{
+            "version": "1.0",
+            "hooks": [
+                {"name": "always", "event": "work_start", "script": "/bin/true"},
+                {
+                    "name": "backend-only",
+                    "event": "work_start",
+                    "script": "/bin/true",
+                    "when": {"project": "backend"}
+                },
+                {
+                    "name": "every-4th",
+                    "event": "work_start",
+                    "script": "/bin/true",
+                    "when": {"every_nth": 4}
+                }
+            ]
+        }"#;
+        let config = HookConfig::parse_and_validate(json).unwrap();
+        let hooks = config.get_hooks_for_event(&HookEvent::WorkStart).unwrap();
+        let mut context = create_http_test_context();
+        context.task_name = Some("API @backend".to_string());
+
+        let names = |hooks: Vec<HookDefinition>| {
+            hooks.into_iter().map(|hook| hook.name).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(HookExecutor::filter_by_condition(hooks, &context)),
+            ["always", "backend-only"]
+        );
+
+        context.task_name = None;
+        context.cycle = 4;
+        assert_eq!(
+            names(HookExecutor::filter_by_condition(hooks, &context)),
+            ["always", "every-4th"]
+        );
+    }
+
+    /// 実行可能なスクリプトを作成
+    #[cfg(unix)]
+    fn write_script(dir: &Path, name: &str, body: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join(name);
+        fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    /// work_start のフック定義（`extra` はフック定義に追加するフィールド）を組み立てる
+    fn hook_json(name: &str, script: &Path, extra: &str) -> String {
+        format!(
+            r#"{{"name": "{}", "event": "work_start", "script": "{}"{}}}"#,
+            name,
+            script.display(),
+            extra
+        )
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_runs_hooks_in_priority_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("order.log");
+        let hooks: Vec<String> = [("low", -1), ("default", 0), ("high", 10)]
+            .iter()
+            .map(|(name, priority)| {
+                let script = write_script(
+                    dir.path(),
+                    &format!("{}.sh", name),
+                    &format!("echo {} >> {}", name, log.display()),
+                );
+                hook_json(name, &script, &format!(r#", "priority": {}"#, priority))
+            })
+            .collect();
+        let config = HookConfig::parse_and_validate(&format!(
+            r#"{{"version": "1.0", "hooks": [{}]}}"#,
+            hooks.join(",")
+        ))
+        .unwrap();
+        let executor = HookExecutor::with_config(config);
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        assert_eq!(fs::read_to_string(&log).unwrap(), "high\ndefault\nlow\n");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_stop_on_error_skips_remaining_hooks() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("ran");
+        let fail = write_script(dir.path(), "fail.sh", "exit 1");
+        let touch = write_script(
+            dir.path(),
+            "touch.sh",
+            &format!("touch {}", marker.display()),
+        );
+
+        for (on_error, expect_ran) in [("continue", true), ("stop", false)] {
+            let _ = fs::remove_file(&marker);
+            let config = HookConfig::parse_and_validate(&format!(
+                r#"{{"version": "1.0", "hooks": [{}, {}]}}"#,
+                hook_json(
+                    "fail",
+                    &fail,
+                    &format!(r#", "priority": 1, "on_error": "{}""#, on_error)
+                ),
+                hook_json("touch", &touch, "")
+            ))
+            .unwrap();
+            let executor = HookExecutor::with_config(config);
+
+            executor.execute(create_http_test_context());
+            assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+            assert_eq!(marker.exists(), expect_ran, "on_error: {}", on_error);
+        }
+    }
+
+    /// 0.3秒かかるフックを2つ並列に実行し、完了までの時間を返す
+    #[cfg(unix)]
+    async fn run_two_slow_hooks(dir: &Path, max_concurrent: usize) -> Duration {
+        let slow = write_script(dir, "slow.sh", "sleep 0.3");
+        let config = HookConfig::parse_and_validate(&format!(
+            r#"{{
+                "version": "1.0",
+                "hooks": [{}, {}],
+                "events": {{"work_start": {{"parallel": true}}}},
+                "defaults": {{"max_concurrent": {}}}
+            }}"#,
+            hook_json("slow-1", &slow, ""),
+            hook_json("slow-2", &slow, ""),
+            max_concurrent
+        ))
+        .unwrap();
+        let executor = HookExecutor::with_config(config);
+
+        let started = Instant::now();
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+        started.elapsed()
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_parallel_hooks() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let elapsed = run_two_slow_hooks(dir.path(), 4).await;
+
+        assert!(elapsed < Duration::from_millis(550), "{:?}", elapsed);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_respects_concurrency_limit() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let elapsed = run_two_slow_hooks(dir.path(), 1).await;
+
+        assert!(elapsed >= Duration::from_millis(600), "{:?}", elapsed);
+    }
+
+    #[test]
+    fn test_replace_config_updates_concurrency_limit() {
+        let executor = HookExecutor::with_config(HookConfig::default());
+        let before = executor.limiter.read().unwrap().1.clone();
+        assert_eq!(before.available_permits(), 4);
+
+        executor.replace_config(HookConfig::default());
+        assert!(Arc::ptr_eq(&before, &executor.limiter.read().unwrap().1));
+
+        let config = HookConfig::parse_and_validate(
+            r#"{"version": "1.0", "defaults": {"max_concurrent": 2}}"#,
+        )
+        .unwrap();
+        executor.replace_config(config);
+        assert_eq!(executor.limiter.read().unwrap().1.available_permits(), 2);
+    }
+
+    /// フック定義1つだけの設定を組み立てる（`fields` はフック定義のフィールド）
+    fn create_single_hook_config(fields: &str) -> HookConfig {
+        HookConfig::parse_and_validate(&format!(
+            r#"{{"version": "1.0", "hooks": [{{"name": "inline", "event": "work_start", {}}}]}}"#,
+            fields
+        ))
+        .unwrap()
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_script_with_args_env_and_working_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("out.txt");
+        let script = write_script(
+            dir.path(),
+            "args.sh",
+            &format!(
+                r#"printf '%s|' "$@" "$EXTRA" "$POMODORO_EVENT" "$(pwd)" > {}"#,
+                output.display()
+            ),
+        );
+        let config = create_single_hook_config(&format!(
+            r##""script": "{}",
+               "args": ["--task", "{{{{task_name}}}}", "#{{{{cycle}}}}"],
+               "env": {{"EXTRA": "{{{{event}}}}-extra", "POMODORO_EVENT": "overridden"}},
+               "working_dir": "{}""##,
+            script.display(),
+            dir.path().display()
+        ));
+        let executor = HookExecutor::with_config(config);
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        let cwd = dir.path().canonicalize().unwrap();
+        assert_eq!(
+            fs::read_to_string(&output).unwrap(),
+            format!(
+                "--task|設計 \"レビュー\"|#1|work_start-extra|work_start|{}|",
+                cwd.display()
+            )
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_command_from_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("created");
+        let config = create_single_hook_config(&format!(
+            r#""command": "touch", "args": ["{}"]"#,
+            marker.display()
+        ));
+        let executor = HookExecutor::with_config(config);
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        assert!(marker.exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_inline_shell() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("out.txt");
+        let config = create_single_hook_config(&format!(
+            r#""shell": "echo \"$POMODORO_EVENT $POMODORO_HOOK_NAME\" > {}""#,
+            output.display()
+        ));
+        let executor = HookExecutor::with_config(config);
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        assert_eq!(fs::read_to_string(&output).unwrap(), "work_start inline\n");
+    }
+
+    #[test]
+    fn test_build_command_validates_script_path() {
+        let config = create_single_hook_config(r#""command": "/nonexistent/bin/tool""#);
+
+        let result = HookExecutor::build_command(&config.hooks[0], &create_http_test_context());
+
+        assert!(result.unwrap_err().contains("ファイルが存在しません"));
+    }
+
+    #[test]
+    fn test_build_command_validates_working_dir() {
+        let config = create_single_hook_config(
+            r#""command": "true", "working_dir": "/nonexistent/pomodoro-hook-dir""#,
+        );
+
+        let result = HookExecutor::build_command(&config.hooks[0], &create_http_test_context());
+
+        assert!(result
+            .unwrap_err()
+            .contains("作業ディレクトリが存在しません"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_writes_run_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = create_single_hook_config(r#""shell": "echo done; echo warn >&2; exit 3""#);
+        let run_log = Arc::new(HookRunLog::new(dir.path().join("hook-runs.jsonl")));
+        let executor = HookExecutor::with_config(config).with_run_log(run_log.clone());
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        let records = run_log.recent(10).unwrap();
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.hook, "inline");
+        assert_eq!(record.event, HookEvent::WorkStart);
+        assert!(!record.success);
+        assert_eq!(record.exit_code, Some(3));
+        assert_eq!(record.stdout, "done\n");
+        assert_eq!(record.stderr, "warn\n");
+        assert!(record
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("非ゼロの終了コード"));
+    }
+
+    /// プロセスが終了しているか（回収前のゾンビも終了とみなす）
+    #[cfg(unix)]
+    fn process_exited(pid: &str) -> bool {
+        let output = std::process::Command::new("ps")
+            .args(["-o", "stat=", "-p", pid])
+            .output()
+            .unwrap();
+        let stat = String::from_utf8_lossy(&output.stdout);
+        stat.trim().is_empty() || stat.trim().starts_with('Z')
+    }
+
+    /// pidファイルに記録されたプロセスがすべて終了するのを待つ
+    #[cfg(unix)]
+    async fn assert_processes_exited(pid_file: &Path) {
+        let pids = fs::read_to_string(pid_file).unwrap();
+        assert_eq!(pids.lines().count(), 2, "{:?}", pids);
+        for pid in pids.lines() {
+            let deadline = Instant::now() + Duration::from_secs(2);
+            while !process_exited(pid) && Instant::now() < deadline {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+            assert!(process_exited(pid), "process {} is still running", pid);
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_script_hook_timeout_kills_processes() {
+        let dir = tempfile::tempdir().unwrap();
+        let pid_file = dir.path().join("pids");
+        // シェル自身と、シェルが起動した子プロセスのpidを記録する
+        let config = create_single_hook_config(&format!(
+            r#""shell": "echo $$ > {0}; sleep 30 & echo $! >> {0}; wait", "timeout_secs": 1"#,
+            pid_file.display()
+        ));
+        let hook = config.hooks[0].clone();
+
+        let outcome = HookExecutor::run_hook(
+            &hook,
+            &create_http_test_context(),
+            1,
+            Duration::from_secs(hook.timeout_secs),
+            None,
+            &reqwest::Client::new(),
+        )
+        .await;
+
+        assert_eq!(outcome.error.unwrap(), "タイムアウトしました (1秒)");
+        assert_processes_exited(&pid_file).await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_pre_hook_timeout_rejects_and_kills_processes() {
+        let dir = tempfile::tempdir().unwrap();
+        let pid_file = dir.path().join("pids");
+        let config = HookConfig::parse_and_validate(&format!(
+            r#"{{"version": "1.0", "hooks": [{{
+                "name": "guard",
+                "event": "before_work_start",
+                "shell": "echo $$ > {0}; sleep 30 & echo $! >> {0}; wait",
+                "timeout_secs": 1
+            }}]}}"#,
+            pid_file.display()
+        ))
+        .unwrap();
+        let executor = HookExecutor::with_config(config);
+        let mut context = create_http_test_context();
+        context.event = HookEvent::BeforeWorkStart;
+
+        let deadline = Instant::now() + Duration::from_secs(MAX_PRE_HOOK_TOTAL_SECS);
+        let err = executor
+            .run_pre_hooks(&context, deadline)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            "フック 'guard' が失敗しました: タイムアウトしました (1秒)"
+        );
+        assert_processes_exited(&pid_file).await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_slow_pre_hooks_reject_within_total_limit() {
+        let config = HookConfig::parse_and_validate(
+            r#"{"version": "1.0", "hooks": [
+                {"name": "first", "event": "before_work_start", "shell": "sleep 2", "priority": 1},
+                {"name": "second", "event": "before_work_start", "priority": 0,
+                 "shell": "sleep 1; echo 会議中です >&2; exit 1"},
+                {"name": "third", "event": "before_break_start", "timeout_secs": 30,
+                 "shell": "sleep 30"}
+            ]}"#,
+        )
+        .unwrap();
+        let executor = HookExecutor::with_config(config);
+        let limit = Duration::from_secs(MAX_PRE_HOOK_TOTAL_SECS);
+        let deadline = || Instant::now() + limit;
+
+        // 合計時間内に拒否したフックは、標準エラー出力が理由になる
+        let mut context = create_http_test_context();
+        context.event = HookEvent::BeforeWorkStart;
+        let started = Instant::now();
+        let err = executor
+            .run_pre_hooks(&context, deadline())
+            .await
+            .unwrap_err();
+        assert_eq!(err, "会議中です");
+        assert!(started.elapsed() < limit);
+
+        // 合計時間を超えたフックは timeout_secs を待たずに打ち切る
+        context.event = HookEvent::BeforeBreakStart;
+        let started = Instant::now();
+        let err = executor
+            .run_pre_hooks(&context, deadline())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            format!(
+                "フック 'third' が失敗しました: 事前フックの実行時間が合計{}秒を超えました",
+                MAX_PRE_HOOK_TOTAL_SECS
+            )
+        );
+        assert!(started.elapsed() < limit + Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_run_once_http_hook_records_status_and_body() {
+        let (addr, _request) = spawn_http_server(Some(503)).await;
+        let config = create_http_test_config(&format!(r#"{{"url": "http://{}/"}}"#, addr), 5);
+        let dir = tempfile::tempdir().unwrap();
+        let run_log = Arc::new(HookRunLog::new(dir.path().join("hook-runs.jsonl")));
+        let executor = HookExecutor::with_config(config.clone()).with_run_log(run_log.clone());
+
+        let record = executor
+            .run_once(&config.hooks[0], &create_http_test_context())
+            .await;
+
+        assert!(!record.success);
+        assert_eq!(record.kind, HookKind::Http);
+        assert_eq!(record.exit_code, Some(503));
+        assert_eq!(record.stdout, "ok");
+        assert!(record.error.unwrap().contains("503"));
+        // run_once は実行記録に書き込まない
+        assert!(run_log.recent(10).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_sends_directive_from_successful_hook() {
+        let config = create_single_hook_config(
+            r#""shell": "echo start; echo '{\"pomodoro\": {\"action\": \"pause\"}}'""#,
+        );
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let executor = HookExecutor::with_config(config).with_directive_sender(tx);
+        let context = create_http_test_context();
+
+        executor.execute(context.clone());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        let request = rx.try_recv().unwrap();
+        assert_eq!(request.session_id, context.session_id);
+        assert_eq!(request.event, HookEvent::WorkStart);
+        assert_eq!(request.hook, "inline");
+        assert_eq!(request.directive.action, Some(DirectiveAction::Pause));
+    }
+
+    #[tokio::test]
+    async fn test_execute_ignores_directive_from_failed_hook() {
+        let config = create_single_hook_config(
+            r#""shell": "echo '{\"pomodoro\": {\"action\": \"skip\"}}'; exit 1""#,
+        );
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let executor = HookExecutor::with_config(config).with_directive_sender(tx);
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_retries_until_success() {
+        let dir = tempfile::tempdir().unwrap();
+        let attempts = dir.path().join("attempts");
+        let config = create_single_hook_config(&format!(
+            r#""shell": "echo $POMODORO_HOOK_ATTEMPT >> {}; [ $POMODORO_HOOK_ATTEMPT -ge 2 ]",
+               "retries": 3, "retry_backoff_secs": 1"#,
+            attempts.display()
+        ));
+        let run_log = Arc::new(HookRunLog::new(dir.path().join("hook-runs.jsonl")));
+        let metrics = Arc::new(Metrics::new());
+        let executor = HookExecutor::with_config(config)
+            .with_run_log(run_log.clone())
+            .with_metrics(metrics.clone());
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(10)).await);
+
+        // 2回目で成功したため3回目は実行しない
+        assert_eq!(fs::read_to_string(&attempts).unwrap(), "1\n2\n");
+        let records = run_log.recent(10).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!((records[0].attempt, records[0].success), (2, true));
+        assert_eq!((records[1].attempt, records[1].success), (1, false));
+        // メトリクスには最後の実行だけを記録する
+        let output = metrics.render(&[]);
+        assert!(output.contains("pomodoro_hook_executions_total{hook=\"inline\"} 1\n"));
+        assert!(output.contains("pomodoro_hook_failures_total{hook=\"inline\"} 0\n"));
+        assert!(executor.hook_failures().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_reports_failure_after_last_attempt() {
+        let config = create_single_hook_config(
+            r#""shell": "echo failed >&2; exit 2", "retries": 1, "retry_backoff_secs": 1"#,
+        );
+        let executor = HookExecutor::with_config(config);
+
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(10)).await);
+
+        let failures = executor.hook_failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].hook, "inline");
+        assert_eq!(failures[0].event, HookEvent::WorkStart);
+        assert_eq!(failures[0].attempts, 2);
+        assert!(failures[0].error.contains("非ゼロの終了コード"));
+
+        // 次に成功すれば一覧から外す
+        executor.replace_config(create_single_hook_config(r#""shell": "true""#));
+        assert_eq!(executor.hook_failures().len(), 1);
+        executor.execute(create_http_test_context());
+        assert!(executor.wait_for_pending(Duration::from_secs(5)).await);
+        assert!(executor.hook_failures().is_empty());
+    }
+
+    #[test]
+    fn test_replace_config_drops_failures_of_removed_hooks() {
+        let executor = HookExecutor::with_config(create_single_hook_config(r#""shell": "true""#));
+        executor.failures.lock().unwrap().insert(
+            "inline".to_string(),
+            HookFailure {
+                hook: "inline".to_string(),
+                event: HookEvent::WorkStart,
+                attempts: 1,
+                error: "失敗".to_string(),
+                timestamp: Utc::now(),
+            },
+        );
+
+        executor.replace_config(HookConfig::default());
+
+        assert!(executor.hook_failures().is_empty());
+    }
 }
@@ -0,0 +1,255 @@
+//! フックの実行記録
+//!
+//! デーモンが実行したフックの結果（終了コード・実行時間・出力）を
+//! `~/.pomodoro/hook-runs.jsonl` に1行1件のJSONで追記する。`pomodoro hooks logs` で参照する。
+//! ファイルが大きくなった場合は新しい記録だけを残して書き直す。
+
+use crate::hooks::{HookDirective, HookKind};
+use crate::types::HookEvent;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::debug;
+
+/// 記録する出力の最大サイズ（バイト、標準出力・標準エラー出力それぞれ）
+pub const MAX_OUTPUT_BYTES: usize = 2 * 1024;
+
+/// このサイズを超えたら古い記録を削除する（バイト）
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+
+/// 古い記録を削除するときに残す件数
+const KEEP_RECORDS: usize = 200;
+
+/// フック1回分の実行記録
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookRunRecord {
+    /// 実行開始時刻
+    pub timestamp: DateTime<Utc>,
+    /// イベント
+    pub event: HookEvent,
+    /// フック名
+    pub hook: String,
+    /// フックの種類
+    #[serde(rename = "type", default)]
+    pub kind: HookKind,
+    /// 何回目の実行か（1から始まる。再実行した場合は2以上）
+    #[serde(default = "first_attempt")]
+    pub attempt: u32,
+    /// 成功したかどうか
+    pub success: bool,
+    /// 終了コード（HTTPフックはステータスコード。起動失敗・タイムアウトなどの場合はNone）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// 実行時間（ミリ秒）
+    pub duration_ms: u64,
+    /// 失敗の内容
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 標準出力（HTTPフックはレスポンスのボディ。`MAX_OUTPUT_BYTES` まで）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub stdout: String,
+    /// 標準エラー出力（`MAX_OUTPUT_BYTES` まで）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub stderr: String,
+    /// 出力に含まれていたタイマーへの指示
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directive: Option<HookDirective>,
+}
+
+fn first_attempt() -> u32 {
+    1
+}
+
+/// 出力を `MAX_OUTPUT_BYTES` までに切り詰める（文字の途中では切らない）
+pub fn truncate_output(output: &str) -> String {
+    if output.len() <= MAX_OUTPUT_BYTES {
+        return output.to_string();
+    }
+    let mut end = MAX_OUTPUT_BYTES;
+    while !output.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}... (truncated)", &output[..end])
+}
+
+/// フックの実行記録ファイル
+#[derive(Debug)]
+pub struct HookRunLog {
+    /// 記録ファイルのパス
+    path: PathBuf,
+    /// 追記と書き直しを直列化する
+    lock: Mutex<()>,
+}
+
+impl HookRunLog {
+    /// 指定されたパスの記録ファイルを使用する
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// デフォルトの記録ファイルパス（データディレクトリの `hook-runs.jsonl`）を取得
+    pub fn default_path() -> Option<PathBuf> {
+        crate::paths::data_file("hook-runs.jsonl")
+    }
+
+    /// 記録ファイルのパス
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 実行記録を追記する
+    ///
+    /// ファイルが `MAX_FILE_BYTES` を超えた場合は新しい `KEEP_RECORDS` 件だけを残す。
+    pub fn append(&self, record: &HookRunRecord) -> io::Result<()> {
+        let _lock = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())?;
+
+        if file.metadata()?.len() > MAX_FILE_BYTES {
+            let records = self.read_all()?;
+            let keep = &records[records.len().saturating_sub(KEEP_RECORDS)..];
+            let mut content = String::new();
+            for record in keep {
+                content.push_str(&serde_json::to_string(record)?);
+                content.push('\n');
+            }
+            fs::write(&self.path, content)?;
+            debug!("フックの実行記録を {} 件に削減しました", keep.len());
+        }
+        Ok(())
+    }
+
+    /// 新しい順に最大 `limit` 件の実行記録を取得する
+    ///
+    /// ファイルが存在しない場合は空を返す。解析できない行は無視する。
+    pub fn recent(&self, limit: usize) -> io::Result<Vec<HookRunRecord>> {
+        let mut records = self.read_all()?;
+        records.reverse();
+        records.truncate(limit);
+        Ok(records)
+    }
+
+    /// すべての実行記録を古い順に読み込む
+    fn read_all(&self) -> io::Result<Vec<HookRunRecord>> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        Ok(content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn create_record(hook: &str, success: bool) -> HookRunRecord {
+        HookRunRecord {
+            timestamp: Utc::now(),
+            event: HookEvent::WorkEnd,
+            hook: hook.to_string(),
+            kind: HookKind::Script,
+            attempt: 1,
+            success,
+            exit_code: Some(if success { 0 } else { 1 }),
+            duration_ms: 12,
+            error: (!success).then(|| "失敗".to_string()),
+            stdout: "ok".to_string(),
+            stderr: String::new(),
+            directive: None,
+        }
+    }
+
+    #[test]
+    fn test_recent_missing_file_is_empty() {
+        let dir = tempdir().unwrap();
+        let log = HookRunLog::new(dir.path().join("hook-runs.jsonl"));
+
+        assert!(log.recent(10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_append_and_recent_newest_first() {
+        let dir = tempdir().unwrap();
+        let log = HookRunLog::new(dir.path().join("data").join("hook-runs.jsonl"));
+
+        log.append(&create_record("first", true)).unwrap();
+        log.append(&create_record("second", false)).unwrap();
+        log.append(&create_record("third", true)).unwrap();
+
+        let records = log.recent(2).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].hook, "third");
+        assert_eq!(records[1].hook, "second");
+        assert!(!records[1].success);
+        assert_eq!(records[1].exit_code, Some(1));
+        assert_eq!(records[1].error.as_deref(), Some("失敗"));
+    }
+
+    #[test]
+    fn test_recent_skips_invalid_lines() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("hook-runs.jsonl");
+        let log = HookRunLog::new(path.clone());
+        log.append(&create_record("valid", true)).unwrap();
+        let mut content = fs::read_to_string(&path).unwrap();
+        content.push_str("{broken\n");
+        fs::write(&path, content).unwrap();
+
+        let records = log.recent(10).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].hook, "valid");
+    }
+
+    #[test]
+    fn test_append_trims_large_file() {
+        let dir = tempdir().unwrap();
+        let log = HookRunLog::new(dir.path().join("hook-runs.jsonl"));
+        let mut record = create_record("big", true);
+        record.stdout = "x".repeat(MAX_OUTPUT_BYTES);
+
+        let count = (MAX_FILE_BYTES as usize / MAX_OUTPUT_BYTES) + 10;
+        for _ in 0..count {
+            log.append(&record).unwrap();
+        }
+
+        assert!(fs::metadata(log.path()).unwrap().len() <= MAX_FILE_BYTES);
+        let records = log.recent(usize::MAX).unwrap();
+        assert!(records.len() >= KEEP_RECORDS);
+        assert!(records.len() < count);
+    }
+
+    #[test]
+    fn test_truncate_output() {
+        assert_eq!(truncate_output("short"), "short");
+
+        let long = "あ".repeat(MAX_OUTPUT_BYTES);
+        let truncated = truncate_output(&long);
+        assert!(truncated.ends_with("... (truncated)"));
+        assert!(truncated.len() <= MAX_OUTPUT_BYTES + "... (truncated)".len());
+    }
+}
@@ -1,11 +1,21 @@
 //! イベントフックモジュール
 //!
-//! タイマーイベント発生時に外部スクリプトを実行する機能を提供する。
+//! タイマーイベント発生時に外部スクリプトの実行やHTTPリクエストの送信を行う機能を提供する。
 
+pub mod condition;
 pub mod config;
 pub mod context;
+pub mod directive;
 pub mod executor;
+pub mod log;
+pub mod template;
 
-pub use config::{HookConfig, HookConfigError, HookDefinition};
+pub use condition::{HookCondition, TimeWindow};
+pub use config::{
+    EventOptions, HookConfig, HookConfigError, HookDefinition, HookKind, HttpHook, OnError,
+    SecretValue,
+};
 pub use context::HookContext;
+pub use directive::{DirectiveAction, DirectiveNotification, HookDirective, HookDirectiveRequest};
 pub use executor::HookExecutor;
+pub use log::{HookRunLog, HookRunRecord};
@@ -0,0 +1,259 @@
+//! フックのテンプレート展開
+//!
+//! `{{task_name}}` 形式のプレースホルダーをフック実行コンテキストの値に置き換える。
+//! HTTPフックのボディと、コマンドの引数・環境変数で使用する。
+//! プレースホルダーは設定の読み込み時に検証し、未知の名前はエラーにする。
+
+use crate::hooks::HookContext;
+
+/// 使用できるプレースホルダー名
+pub const PLACEHOLDERS: &[&str] = &[
+    "event",
+    "task_name",
+    "phase",
+    "duration_secs",
+    "elapsed_secs",
+    "remaining_secs",
+    "cycle",
+    "total_cycles",
+    "timestamp",
+    "session_id",
+    "hook_name",
+    "context",
+];
+
+/// テンプレートの構成要素
+#[derive(Debug, PartialEq, Eq)]
+enum Segment<'a> {
+    /// そのまま出力する文字列
+    Text(&'a str),
+    /// プレースホルダー（前後の空白を除いた名前）
+    Placeholder(&'a str),
+}
+
+/// テンプレートを構成要素に分解
+fn parse(template: &str) -> Result<Vec<Segment<'_>>, String> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            segments.push(Segment::Text(&rest[..start]));
+        }
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            "プレースホルダーが閉じられていません（`}}` がありません）".to_string()
+        })?;
+        let name = after[..end].trim();
+        if !PLACEHOLDERS.contains(&name) {
+            return Err(format!(
+                "不明なプレースホルダー: '{{{{{}}}}}'. 使用できる名前: {:?}",
+                name, PLACEHOLDERS
+            ));
+        }
+        segments.push(Segment::Placeholder(name));
+        rest = &after[end + 2..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+
+    Ok(segments)
+}
+
+/// テンプレートを検証
+///
+/// # Errors
+///
+/// 未知のプレースホルダー、または閉じられていないプレースホルダーがある場合
+pub fn validate(template: &str) -> Result<(), String> {
+    parse(template).map(|_| ())
+}
+
+/// JSONの文字列内に埋め込む形でテンプレートを展開
+///
+/// 値はJSON文字列としてエスケープする（前後の `"` は付けない）。
+/// `{{context}}` はコンテキスト全体のJSON（標準入力に渡すものと同じ）をそのまま埋め込む。
+///
+/// # Errors
+///
+/// テンプレートが不正な場合（通常は設定の読み込み時に検証済み）
+pub fn render_json(
+    template: &str,
+    context: &HookContext,
+    hook_name: &str,
+) -> Result<String, String> {
+    let mut output = String::with_capacity(template.len());
+    for segment in parse(template)? {
+        match segment {
+            Segment::Text(text) => output.push_str(text),
+            Segment::Placeholder("context") => {
+                let json = context.to_json().map_err(|e| e.to_string())?;
+                output.push_str(&String::from_utf8_lossy(&json));
+            }
+            Segment::Placeholder(name) => {
+                output.push_str(&escape_json(&value(context, hook_name, name)));
+            }
+        }
+    }
+    Ok(output)
+}
+
+/// 値をそのまま埋め込む形でテンプレートを展開（コマンドの引数・環境変数用）
+///
+/// 値はサニタイズしない（シェルを介さずにプロセスへ渡すため）。
+/// `{{context}}` はコンテキスト全体のJSONに置き換える。
+///
+/// # Errors
+///
+/// テンプレートが不正な場合（通常は設定の読み込み時に検証済み）
+pub fn render_plain(
+    template: &str,
+    context: &HookContext,
+    hook_name: &str,
+) -> Result<String, String> {
+    let mut output = String::with_capacity(template.len());
+    for segment in parse(template)? {
+        match segment {
+            Segment::Text(text) => output.push_str(text),
+            Segment::Placeholder("context") => {
+                let json = context.to_json().map_err(|e| e.to_string())?;
+                output.push_str(&String::from_utf8_lossy(&json));
+            }
+            Segment::Placeholder(name) => output.push_str(&value(context, hook_name, name)),
+        }
+    }
+    Ok(output)
+}
+
+/// プレースホルダーの値（タスク名がない場合は空文字）
+fn value(context: &HookContext, hook_name: &str, name: &str) -> String {
+    match name {
+        "event" => context.event.as_str().to_string(),
+        "task_name" => context.task_name.clone().unwrap_or_default(),
+        "phase" => context.phase.clone(),
+        "duration_secs" => context.duration_secs.to_string(),
+        "elapsed_secs" => context.elapsed_secs.to_string(),
+        "remaining_secs" => context.remaining_secs.to_string(),
+        "cycle" => context.cycle.to_string(),
+        "total_cycles" => context.total_cycles.to_string(),
+        "timestamp" => context.timestamp.to_rfc3339(),
+        "session_id" => context.session_id.to_string(),
+        "hook_name" => hook_name.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// JSON文字列としてエスケープ（前後の `"` を除く）
+fn escape_json(value: &str) -> String {
+    let quoted = serde_json::Value::String(value.to_string()).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{HookEvent, PomodoroConfig};
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    fn create_test_context(task_name: Option<&str>) -> HookContext {
+        HookContext {
+            event: HookEvent::WorkEnd,
+            task_name: task_name.map(str::to_string),
+            phase: "working".to_string(),
+            duration_secs: 1500,
+            elapsed_secs: 1500,
+            remaining_secs: 0,
+            cycle: 2,
+            total_cycles: 4,
+            timestamp: Utc.timestamp_opt(1672531200, 0).unwrap(),
+            session_id: Uuid::nil(),
+            config: PomodoroConfig::default(),
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_known_placeholders() {
+        assert!(validate("{{event}} #{{ cycle }} {{task_name}}").is_ok());
+        assert!(validate("プレースホルダーなし").is_ok());
+        assert!(validate("").is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_or_unclosed() {
+        let err = validate("{{task}}").unwrap_err();
+        assert!(err.contains("不明なプレースホルダー"));
+        assert!(err.contains("{{task}}"));
+
+        let err = validate("{{task_name").unwrap_err();
+        assert!(err.contains("閉じられていません"));
+    }
+
+    #[test]
+    fn test_render_json_substitutes_values() {
+        let context = create_test_context(Some("設計"));
+
+        let body = render_json(
+            r#"{"text":"{{hook_name}}: {{event}} #{{cycle}}/{{total_cycles}} {{task_name}} at {{timestamp}}"}"#,
+            &context,
+            "slack",
+        )
+        .unwrap();
+
+        assert_eq!(
+            body,
+            r#"{"text":"slack: work_end #2/4 設計 at 2023-01-01T00:00:00+00:00"}"#
+        );
+    }
+
+    #[test]
+    fn test_render_json_escapes_values() {
+        let context = create_test_context(Some("say \"hi\"\\\n"));
+
+        let body = render_json(r#"{"text":"{{task_name}}"}"#, &context, "slack").unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["text"], "say \"hi\"\\\n");
+    }
+
+    #[test]
+    fn test_render_json_missing_task_name_is_empty() {
+        let context = create_test_context(None);
+
+        let body = render_json("[{{task_name}}]", &context, "slack").unwrap();
+
+        assert_eq!(body, "[]");
+    }
+
+    #[test]
+    fn test_render_json_embeds_context() {
+        let context = create_test_context(Some("設計"));
+
+        let body =
+            render_json(r#"{"source":"pomodoro","data":{{context}}}"#, &context, "h").unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["data"]["task_name"], "設計");
+        assert_eq!(json["data"]["schema_version"], 1);
+    }
+
+    #[test]
+    fn test_render_plain_keeps_values_raw() {
+        let context = create_test_context(Some("設計; $(rm -rf ~) \"x\""));
+
+        let arg = render_plain("{{task_name}}", &context, "h").unwrap();
+        assert_eq!(arg, "設計; $(rm -rf ~) \"x\"");
+
+        let arg = render_plain("--cycle={{cycle}}/{{total_cycles}}", &context, "h").unwrap();
+        assert_eq!(arg, "--cycle=2/4");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_plain("{{context}}", &context, "h").unwrap()).unwrap();
+        assert_eq!(json["event"], "work_end");
+    }
+}
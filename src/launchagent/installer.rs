@@ -2,14 +2,17 @@
 //!
 //! LaunchAgentのインストールおよびアンインストール処理を提供する。
 
+use std::collections::HashMap;
 use std::fs;
 use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
 use std::process::Command;
 
 use super::error::{LaunchAgentError, Result};
 use super::launchctl;
 use super::plist::PomodoroLaunchAgent;
 use super::{get_launch_agents_dir, get_log_dir, get_plist_path};
+use crate::paths;
 
 /// pomodoroバイナリの絶対パスを解決する
 ///
@@ -65,8 +68,8 @@ pub fn resolve_binary_path() -> Result<String> {
 ///
 /// 以下の処理を順番に実行する:
 /// 1. バイナリパスを解決（`which pomodoro`）
-/// 2. ログディレクトリを作成（`~/.pomodoro/logs/`）
-/// 3. Plistを生成（`PomodoroLaunchAgent::new`）
+/// 2. ログディレクトリを作成（データディレクトリの `logs/`）
+/// 3. Plistを生成（`PomodoroLaunchAgent::new`、ソケットと `POMODORO_HOME` の指定を引き継ぐ）
 /// 4. plistファイルを書き込み（`~/Library/LaunchAgents/`）
 /// 5. パーミッションを設定（0644）
 /// 6. 既存サービスをアンロード（冪等性確保）
@@ -75,6 +78,9 @@ pub fn resolve_binary_path() -> Result<String> {
 /// # Returns
 /// インストール成功時はOk、失敗時はErr
 ///
+/// # Arguments
+/// * `socket_path` - デーモンのソケットパス（`--socket` / `POMODORO_SOCKET` で指定された場合）
+///
 /// # Errors
 /// - バイナリパスの解決に失敗
 /// - ホームディレクトリの取得に失敗
@@ -86,10 +92,10 @@ pub fn resolve_binary_path() -> Result<String> {
 /// ```rust,ignore
 /// use pomodoro::launchagent::installer;
 ///
-/// installer::install()?;
+/// installer::install(None)?;
 /// println!("LaunchAgent installed successfully");
 /// ```
-pub fn install() -> Result<()> {
+pub fn install(socket_path: Option<&Path>) -> Result<()> {
     // 1. バイナリパスを解決
     let binary_path = resolve_binary_path()?;
 
@@ -99,7 +105,17 @@ pub fn install() -> Result<()> {
     tracing::debug!("Created log directory: {:?}", log_dir);
 
     // 3. Plist生成
-    let plist = PomodoroLaunchAgent::new(&binary_path, log_dir.to_string_lossy().to_string());
+    let mut plist = PomodoroLaunchAgent::new(&binary_path, log_dir.to_string_lossy().to_string());
+    if let Some(socket_path) = socket_path {
+        plist = plist.with_socket_path(socket_path.to_string_lossy().to_string());
+    }
+    if let Some(home) = paths::home_override() {
+        let env_vars = HashMap::from([(
+            paths::HOME_ENV.to_string(),
+            home.to_string_lossy().to_string(),
+        )]);
+        plist = plist.with_environment_variables(env_vars);
+    }
     let plist_xml = plist.to_xml()?;
 
     // 4. LaunchAgentsディレクトリを作成（存在しない場合）
@@ -214,7 +230,7 @@ mod tests {
         // Run manually: cargo test test_install_and_uninstall_integration -- --ignored
 
         // Install
-        let install_result = install();
+        let install_result = install(None);
         assert!(
             install_result.is_ok(),
             "Install failed: {:?}",
@@ -265,7 +281,7 @@ mod tests {
         // Test that log dir path can be obtained
         let result = get_log_dir();
         if let Ok(log_dir) = result {
-            assert!(log_dir.to_string_lossy().contains(".pomodoro/logs"));
+            assert!(log_dir.ends_with("logs"));
         }
     }
 
@@ -17,7 +17,7 @@
 //! use pomodoro::launchagent::{install, uninstall, is_running, get_status};
 //!
 //! // LaunchAgentをインストール
-//! install()?;
+//! install(None)?;
 //!
 //! // サービス状態を確認
 //! if is_running()? {
@@ -60,13 +60,12 @@ pub fn get_plist_path() -> Result<std::path::PathBuf> {
 /// ログディレクトリのデフォルトパスを取得
 ///
 /// # Returns
-/// ログディレクトリのパス（`~/.pomodoro/logs`）
+/// ログディレクトリのパス（データディレクトリの `logs`、通常は `~/.pomodoro/logs`）
 ///
 /// # Errors
 /// ホームディレクトリの取得に失敗した場合
 pub fn get_log_dir() -> Result<std::path::PathBuf> {
-    let home_dir = dirs::home_dir().ok_or(LaunchAgentError::HomeDirectoryNotFound)?;
-    Ok(home_dir.join(".pomodoro/logs"))
+    crate::paths::data_file("logs").ok_or(LaunchAgentError::HomeDirectoryNotFound)
 }
 
 /// LaunchAgentsディレクトリのデフォルトパスを取得
@@ -114,7 +113,8 @@ mod tests {
         let result = get_log_dir();
 
         if let Ok(path) = result {
-            assert!(path.to_string_lossy().contains(".pomodoro/logs"));
+            assert!(path.ends_with("logs"));
+            assert_eq!(Some(path.clone()), crate::paths::data_file("logs"));
         }
     }
 
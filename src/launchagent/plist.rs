@@ -125,6 +125,21 @@ impl PomodoroLaunchAgent {
         self
     }
 
+    /// デーモンが待ち受けるソケットパスを設定
+    ///
+    /// `--socket` オプションとして起動引数に追加する。
+    ///
+    /// # Arguments
+    /// * `socket_path` - ソケットの絶対パス
+    ///
+    /// # Returns
+    /// 更新された自身への参照
+    pub fn with_socket_path(mut self, socket_path: impl Into<String>) -> Self {
+        self.program_arguments.push("--socket".to_string());
+        self.program_arguments.push(socket_path.into());
+        self
+    }
+
     /// KeepAliveを無効化
     ///
     /// # Returns
@@ -229,6 +244,22 @@ mod tests {
         assert_eq!(plist.environment_variables, Some(env_vars));
     }
 
+    #[test]
+    fn test_with_socket_path() {
+        let plist = PomodoroLaunchAgent::new("/usr/local/bin/pomodoro", "/tmp/logs")
+            .with_socket_path("/tmp/pomodoro/pomodoro.sock");
+
+        assert_eq!(
+            plist.program_arguments,
+            vec![
+                "/usr/local/bin/pomodoro",
+                "daemon",
+                "--socket",
+                "/tmp/pomodoro/pomodoro.sock"
+            ]
+        );
+    }
+
     #[test]
     fn test_without_keep_alive() {
         let plist =
@@ -1,13 +1,30 @@
 //! ポモドーロタイマーライブラリ
 //!
 //! macOS専用のポモドーロタイマーCLIツールのコア機能を提供する。
+//!
+//! クライアントライブラリ（`client`）と型定義は常に含まれる。デーモン・CLI・メニューバーは
+//! それぞれ `daemon`・`cli`・`tray` フィーチャー（デフォルトで有効）で含める。
 
+#[cfg(feature = "cli")]
 pub mod cli;
+pub mod client;
+#[cfg(feature = "daemon")]
 pub mod daemon;
+#[cfg(feature = "daemon")]
 pub mod focus;
+#[cfg(feature = "daemon")]
+pub mod history;
+#[cfg(feature = "daemon")]
 pub mod hooks;
+#[cfg(feature = "cli")]
 pub mod launchagent;
+#[cfg(feature = "tray")]
 pub mod menubar;
+#[cfg(feature = "daemon")]
+pub mod metrics;
+#[cfg(feature = "daemon")]
 pub mod notification;
+pub mod paths;
+#[cfg(feature = "daemon")]
 pub mod sound;
 pub mod types;
@@ -8,20 +8,40 @@ use clap::Parser;
 use pomodoro::cli::{
     generate_completions, Cli, Commands, Display, EnhancedDisplayState, IpcClient,
 };
+use pomodoro::types::{IpcResponse, ResponseData, StreamMessage, TimerEvent};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
     let display = Display::new();
-    let client = IpcClient::new();
+    let auto_spawn = !cli.no_spawn
+        && pomodoro::cli::spawn::ClientConfig::load()
+            .map(|config| config.auto_spawn)
+            .unwrap_or(true);
+    let socket_path = pomodoro::paths::socket_path(cli.socket.as_deref());
+    let client = IpcClient::with_socket_path(socket_path.clone())
+        .with_auto_spawn(auto_spawn)
+        .with_timer(cli.timer.clone());
+
+    // デーモンが起動していなければバックグラウンドで起動する
+    if cli.command.requires_daemon() {
+        match client.ensure_daemon().await {
+            // watchの出力（NDJSON）には混ぜない
+            Ok(true) if !matches!(cli.command, Commands::Watch) => {
+                display.show_success("デーモンを起動しました")
+            }
+            Ok(_) => {}
+            Err(e) => display.show_error(&format!("Failed to start daemon: {}", e)),
+        }
+    }
 
     match cli.command {
         Commands::Start(args) => match client.start(args).await {
             Ok(response) => {
-                if response.status == "success" {
+                if response.is_success() {
                     display.show_start_success(response);
                 } else {
-                    display.show_error(&response.message);
+                    display.show_response_error(&response);
                 }
             }
             Err(e) => {
@@ -30,10 +50,10 @@ async fn main() -> Result<()> {
         },
         Commands::Pause => match client.pause().await {
             Ok(response) => {
-                if response.status == "success" {
+                if response.is_success() {
                     display.show_pause_success(response);
                 } else {
-                    display.show_error(&response.message);
+                    display.show_response_error(&response);
                 }
             }
             Err(e) => {
@@ -42,10 +62,10 @@ async fn main() -> Result<()> {
         },
         Commands::Resume => match client.resume().await {
             Ok(response) => {
-                if response.status == "success" {
+                if response.is_success() {
                     display.show_resume_success(response);
                 } else {
-                    display.show_error(&response.message);
+                    display.show_response_error(&response);
                 }
             }
             Err(e) => {
@@ -54,39 +74,77 @@ async fn main() -> Result<()> {
         },
         Commands::Stop => match client.stop().await {
             Ok(response) => {
-                if response.status == "success" {
+                if response.is_success() {
                     display.show_stop_success(response);
                 } else {
-                    display.show_error(&response.message);
+                    display.show_response_error(&response);
                 }
             }
             Err(e) => {
                 display.show_error(&format!("Failed to stop timer: {}", e));
             }
         },
-        Commands::Status => {
-            let mut state = EnhancedDisplayState::new();
-            loop {
-                match client.status().await {
-                    Ok(response) => {
-                        if response.status == "success" {
-                            if !display.update_status_enhanced(response, &mut state) {
-                                break;
-                            }
-                        } else {
-                            display.show_error(&response.message);
-                            break;
-                        }
-                    }
-                    Err(e) => {
-                        display.show_error(&format!("Failed to get status: {}", e));
-                        break;
-                    }
+        Commands::Skip => match client.skip().await {
+            Ok(response) => {
+                if response.is_success() {
+                    display.show_skip_success(response);
+                } else {
+                    display.show_response_error(&response);
                 }
-                tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            }
+            Err(e) => {
+                display.show_error(&format!("Failed to skip phase: {}", e));
+            }
+        },
+        Commands::Status { all: true } => match client.list().await {
+            Ok(response) => {
+                if response.is_success() {
+                    display.show_timer_list(response);
+                } else {
+                    display.show_response_error(&response);
+                }
+            }
+            Err(e) => {
+                display.show_error(&format!("Failed to get status: {}", e));
+            }
+        },
+        Commands::Status { all: false } => {
+            if let Err(e) = show_status(&client, &display).await {
+                display.show_error(&format!("Failed to get status: {}", e));
+            }
+        }
+        Commands::Watch => {
+            if let Err(e) = watch_events(&client).await {
+                display.show_error(&format!("Failed to watch events: {}", e));
             }
         }
-        Commands::Install => match pomodoro::launchagent::install() {
+        Commands::Shutdown => match client.shutdown().await {
+            Ok(response) => {
+                if response.is_success() {
+                    display.show_success(&response.message);
+                } else {
+                    display.show_response_error(&response);
+                }
+            }
+            Err(e) => {
+                display.show_error(&format!("Failed to shut down daemon: {}", e));
+            }
+        },
+        Commands::Reload => match client.reload().await {
+            Ok(response) => {
+                if response.is_success() {
+                    display.show_success(&response.message);
+                } else {
+                    display.show_response_error(&response);
+                }
+            }
+            Err(e) => {
+                display.show_error(&format!("Failed to reload configuration: {}", e));
+            }
+        },
+        Commands::Install => match pomodoro::launchagent::install(
+            pomodoro::paths::socket_override(cli.socket.as_deref()).as_deref(),
+        ) {
             Ok(_) => display.show_success("LaunchAgent installed successfully"),
             Err(e) => display.show_error(&format!("Failed to install LaunchAgent: {}", e)),
         },
@@ -95,29 +153,180 @@ async fn main() -> Result<()> {
             Err(e) => display.show_error(&format!("Failed to uninstall LaunchAgent: {}", e)),
         },
         Commands::Config(args) => {
-            pomodoro::cli::sound::handle_config(args)?;
+            // 起動中のデーモンにはすぐに反映する（起動していなければ次回起動時に読み込まれる）
+            if pomodoro::cli::sound::handle_config(args)? {
+                if let Ok(response) = client.reload().await {
+                    if response.is_success() {
+                        display.show_success("デーモンに設定を反映しました");
+                    } else {
+                        display.show_response_error(&response);
+                    }
+                }
+            }
         }
         Commands::Sounds => {
             pomodoro::cli::sound::handle_sounds()?;
         }
-        Commands::Daemon => {
+        Commands::Log(args) => {
+            if let Err(e) = pomodoro::cli::log::handle_log(args) {
+                display.show_error(&e.to_string());
+            }
+        }
+        Commands::Hooks(args) => {
+            // validate・test の失敗は終了コードで判定できるようにする
+            pomodoro::cli::hooks::handle_hooks(args).await?;
+        }
+        Commands::Daemon {
+            watch_config,
+            lead,
+            follow,
+        } => {
             // デーモン設定の初期化
             let config = pomodoro::types::PomodoroConfig::default();
             let sound_config = pomodoro::sound::SoundConfig::load().unwrap_or_default();
-            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            // メトリクスの集計（エンドポイントが無効でも集計はする）
+            let metrics = std::sync::Arc::new(pomodoro::metrics::Metrics::new());
+            // フック設定はすべてのタイマーで共有する
+            // フックから実行した `pomodoro` コマンドも同じデーモンに接続できるようにする
+            let mut hook_executor = pomodoro::hooks::HookExecutor::new()
+                .with_socket_path(socket_path.clone())
+                .with_metrics(metrics.clone());
+            // 実行結果は `pomodoro hooks logs` で参照できるように記録する
+            if let Some(path) = pomodoro::hooks::HookRunLog::default_path() {
+                hook_executor = hook_executor
+                    .with_run_log(std::sync::Arc::new(pomodoro::hooks::HookRunLog::new(path)));
+            }
+            // フックの出力に含まれる指示はメインループで受け取り、別タスクで適用する
+            let (directive_tx, mut directive_rx) = tokio::sync::mpsc::unbounded_channel();
+            let hook_executor =
+                std::sync::Arc::new(hook_executor.with_directive_sender(directive_tx));
+
+            // タイマーの初期化（デフォルトタイマーと、全タイマーのイベントのレシーバー）
+            let (timers, mut rx) =
+                pomodoro::daemon::TimerRegistry::new(config, Some(hook_executor.clone()));
+            let timers = std::sync::Arc::new(timers);
+            let directive_applier = std::sync::Arc::new(tokio::sync::Mutex::new(
+                pomodoro::daemon::DirectiveApplier::new(timers.clone()),
+            ));
+            // 指示による通知（macOSの通知はメインスレッドで送るため、メインループに戻す）
+            let (directive_notify_tx, mut directive_notify_rx) =
+                tokio::sync::mpsc::unbounded_channel::<pomodoro::hooks::DirectiveNotification>();
+
+            // 多重起動の防止（ロックはデーモン終了まで保持する）
+            let _daemon_lock = pomodoro::daemon::DaemonLock::acquire(&socket_path)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
 
-            // TimerEngineの初期化
-            let engine = std::sync::Arc::new(tokio::sync::Mutex::new(
-                pomodoro::daemon::TimerEngine::new(config, tx),
+            // 設定の再読み込み（SIGHUP・reloadコマンド・ファイル監視）
+            let reloader = std::sync::Arc::new(pomodoro::daemon::ConfigReloader::new(
+                Some(hook_executor.clone()),
+                sound_config,
             ));
+            if watch_config {
+                tokio::spawn(
+                    reloader
+                        .clone()
+                        .watch(pomodoro::daemon::reload::DEFAULT_WATCH_INTERVAL),
+                );
+            }
 
             // IPCサーバーの初期化
-            let socket_path = get_socket_path();
             let server = pomodoro::daemon::IpcServer::new(&socket_path)
-                .map_err(|e| anyhow::anyhow!("Failed to create IPC server: {}", e))?;
+                .map_err(|e| anyhow::anyhow!("Failed to create IPC server: {}", e))?
+                .with_reloader(reloader.clone())
+                .with_metrics(metrics.clone());
 
             println!("Daemon started at {:?}", socket_path);
 
+            // HTTP APIの起動（設定で有効な場合のみ）
+            let http_config = pomodoro::daemon::HttpApiConfig::load().unwrap_or_else(|e| {
+                eprintln!("Failed to load HTTP API config: {}", e);
+                Default::default()
+            });
+            if http_config.enabled {
+                match pomodoro::daemon::HttpApiServer::from_config(&http_config).await {
+                    Ok(http_server) => {
+                        if let Ok(addr) = http_server.local_addr() {
+                            println!("HTTP API listening on http://{}", addr);
+                        }
+                        let timers = timers.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = http_server.run(timers).await {
+                                eprintln!("HTTP API error: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => eprintln!("Failed to start HTTP API: {}", e),
+                }
+            }
+
+            // メトリクスエンドポイントの起動（設定で有効な場合のみ）
+            let metrics_config = pomodoro::daemon::MetricsConfig::load().unwrap_or_else(|e| {
+                eprintln!("Failed to load metrics config: {}", e);
+                Default::default()
+            });
+            if metrics_config.enabled {
+                match pomodoro::daemon::MetricsServer::bind(metrics_config.port).await {
+                    Ok(metrics_server) => {
+                        if let Ok(addr) = metrics_server.local_addr() {
+                            println!("Metrics listening on http://{}/metrics", addr);
+                        }
+                        let timers = timers.clone();
+                        let metrics = metrics.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = metrics_server.run(timers, metrics).await {
+                                eprintln!("Metrics endpoint error: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => eprintln!("Failed to start metrics endpoint: {}", e),
+                }
+            }
+
+            // チームタイマーの起動（設定またはフラグで有効な場合のみ、デフォルトタイマーが対象）
+            let mut team_config = pomodoro::daemon::TeamConfig::load().unwrap_or_else(|e| {
+                eprintln!("Failed to load team config: {}", e);
+                Default::default()
+            });
+            if let Some(addr) = lead {
+                team_config = team_config.lead(addr);
+            } else if let Some(addr) = follow {
+                team_config = team_config.follow(addr);
+            }
+            let team_task = match team_config.mode {
+                pomodoro::daemon::TeamMode::Off => None,
+                pomodoro::daemon::TeamMode::Leader => {
+                    match pomodoro::daemon::TeamLeader::bind(team_config.listen).await {
+                        Ok(leader) => {
+                            if let Ok(addr) = leader.local_addr() {
+                                println!("Team leader listening on {}", addr);
+                            }
+                            let timer = timers.default_timer();
+                            Some(tokio::spawn(async move {
+                                if let Err(e) = leader.run(timer).await {
+                                    eprintln!("Team leader error: {}", e);
+                                }
+                            }))
+                        }
+                        Err(e) => {
+                            eprintln!("Failed to start team leader: {}", e);
+                            None
+                        }
+                    }
+                }
+                pomodoro::daemon::TeamMode::Follower => match team_config.leader.clone() {
+                    Some(addr) => {
+                        let engine = timers.default_timer().engine().clone();
+                        Some(tokio::spawn(
+                            pomodoro::daemon::TeamFollower::new(addr).run(engine),
+                        ))
+                    }
+                    None => {
+                        eprintln!("Team follower mode requires a leader address");
+                        None
+                    }
+                },
+            };
+
             // NotificationManagerの初期化 (macOSのみ)
             #[cfg(target_os = "macos")]
             let notification_manager = {
@@ -140,33 +349,53 @@ async fn main() -> Result<()> {
             // タイマーティッカー（1秒間隔でカウントダウン）
             let mut ticker = pomodoro::daemon::TimerEngine::create_ticker();
 
-            // メインループ
-            loop {
+            // 終了シグナル（SIGINTはtokio::signal::ctrl_cで受け取る）
+            let mut sigterm =
+                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
+            // 設定の再読み込みシグナル
+            let mut sighup =
+                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())?;
+
+            // メインループ（終了理由を返して抜ける）
+            let reason = loop {
                 tokio::select! {
+                    _ = sigterm.recv() => break "SIGTERM",
+                    _ = tokio::signal::ctrl_c() => break "SIGINT",
+                    _ = server.shutdown_requested() => break "shutdown request",
+
+                    // SIGHUPで設定を再読み込み（不正な場合は現在の設定を維持）
+                    _ = sighup.recv() => match reloader.reload() {
+                        Ok(summary) => println!("Configuration reloaded ({} hooks)", summary.hook_count),
+                        Err(e) => eprintln!("{} (keeping current configuration)", e),
+                    },
+
                     // ティック処理（残り時間の減算）
                     _ = ticker.tick() => {
-                        let mut engine_guard = engine.lock().await;
-                        if let Err(e) = engine_guard.process_tick() {
-                            eprintln!("Failed to process tick: {}", e);
+                        for (name, timer) in timers.timers() {
+                            let mut engine = timer.engine().lock().await;
+                            if let Err(e) = engine.process_tick() {
+                                eprintln!("Failed to process tick ({}): {}", name, e);
+                            }
+                            // フェーズの完了前の事前フックはメインループを止めないよう別タスクで実行する
+                            if let Some(check) = engine.take_completion_check() {
+                                let engine = timer.engine().clone();
+                                tokio::spawn(async move {
+                                    if let Err(e) = pomodoro::daemon::TimerEngine::complete_with_pre_hooks(&engine, check).await {
+                                        eprintln!("Phase completion held ({}): {}", name, e);
+                                    }
+                                });
+                            }
                         }
                     }
 
                     // IPCリクエスト処理
                     result = server.accept() => {
                         match result {
-                            Ok(mut stream) => {
-                                let engine = engine.clone();
+                            Ok(connection) => {
+                                let timers = timers.clone();
                                 tokio::spawn(async move {
-                                    match pomodoro::daemon::IpcServer::receive_request(&mut stream).await {
-                                        Ok(request) => {
-                                            let response = pomodoro::daemon::handle_request(request, engine).await;
-                                            if let Err(e) = pomodoro::daemon::IpcServer::send_response(&mut stream, &response).await {
-                                                eprintln!("Failed to send response: {}", e);
-                                            }
-                                        }
-                                        Err(e) => {
-                                            eprintln!("Failed to receive request: {}", e);
-                                        }
+                                    if let Err(e) = connection.serve(timers).await {
+                                        eprintln!("IPC connection error: {}", e);
                                     }
                                 });
                             }
@@ -178,11 +407,62 @@ async fn main() -> Result<()> {
                         }
                     }
 
+                    // フックからの指示の適用（スキップの事前フックでメインループを止めないよう別タスクで行う）
+                    Some(request) = directive_rx.recv() => {
+                        let applier = directive_applier.clone();
+                        let notify_tx = directive_notify_tx.clone();
+                        tokio::spawn(async move {
+                            match applier.lock().await.apply(&request).await {
+                                Ok(outcome) => {
+                                    println!("Hook directive applied ({}): {}", outcome.timer, request.hook);
+                                    for reason in &outcome.ignored {
+                                        eprintln!("Hook directive ignored ({}): {}", request.hook, reason);
+                                    }
+                                    if let Some(notify) = outcome.notification {
+                                        let _ = notify_tx.send(notify);
+                                    }
+                                }
+                                Err(e) => eprintln!("Failed to apply hook directive: {}", e),
+                            }
+                        });
+                    }
+
+                    // フックの指示による通知
+                    Some(notify) = directive_notify_rx.recv() => {
+                        #[cfg(target_os = "macos")]
+                        if let Some(nm) = &notification_manager {
+                            if let Err(e) = nm.send_custom_notification(notify.title.as_deref(), &notify.message) {
+                                eprintln!("Failed to send notification: {}", e);
+                            }
+                        }
+                        #[cfg(not(target_os = "macos"))]
+                        println!("Hook notification: {}", notify.message);
+                    }
+
                     // タイマーイベント処理
-                    Some(event) = rx.recv() => {
-                        println!("Event received: {:?}", event);
+                    // （購読者への配信はTimerRegistryが行う）
+                    Some((name, event)) = rx.recv() => {
+                        println!("Event received ({}): {:?}", name, event);
+                        metrics.record_event(&name, &event);
                         match event {
-                            pomodoro::daemon::TimerEvent::WorkCompleted { .. } => {
+                            pomodoro::daemon::TimerEvent::WorkCompleted { task_name, .. } => {
+                                let work_minutes = match timers.get(&name) {
+                                    Some(timer) => timer.engine().lock().await.get_state().config.work_minutes,
+                                    None => pomodoro::types::PomodoroConfig::default().work_minutes,
+                                };
+                                let work_secs = work_minutes as u64 * 60;
+                                // CLIの `log` と同じロックを取得するため、メインループを止めないよう別スレッドで記録する
+                                let timer_name = name.clone();
+                                tokio::task::spawn_blocking(move || {
+                                    let ended_at = chrono::Utc::now();
+                                    let result = pomodoro::history::HistoryStore::update(|history| {
+                                        Ok(history.record_timer_completed(&timer_name, task_name, ended_at, work_secs))
+                                    });
+                                    if let Err(e) = result {
+                                        eprintln!("Failed to record history: {}", e);
+                                    }
+                                });
+
                                 #[cfg(target_os = "macos")]
                                 if let Some(nm) = &notification_manager {
                                     if let Err(e) = nm.send_work_complete_notification(Some("作業完了")) {
@@ -190,8 +470,8 @@ async fn main() -> Result<()> {
                                     }
                                 }
 
-                                let sound_name = &sound_config.work_end_sound;
-                                let source = pomodoro::sound::SoundSource::find_by_name(sound_name)
+                                let sound_name = reloader.sound_config().work_end_sound;
+                                let source = pomodoro::sound::SoundSource::find_by_name(&sound_name)
                                     .unwrap_or(pomodoro::sound::SoundSource::Embedded { name: "default".to_string() });
 
                                 if let Err(e) = sound_player.play(&source).await {
@@ -199,6 +479,9 @@ async fn main() -> Result<()> {
                                 }
                             }
                             pomodoro::daemon::TimerEvent::BreakCompleted { .. } => {
+                                // 自動サイクルなしで終了した名前付きタイマーは削除する
+                                timers.remove_if_stopped(&name).await;
+
                                 #[cfg(target_os = "macos")]
                                 if let Some(nm) = &notification_manager {
                                     if let Err(e) = nm.send_break_complete_notification(None) {
@@ -206,8 +489,8 @@ async fn main() -> Result<()> {
                                     }
                                 }
 
-                                let sound_name = &sound_config.break_end_sound;
-                                let source = pomodoro::sound::SoundSource::find_by_name(sound_name)
+                                let sound_name = reloader.sound_config().break_end_sound;
+                                let source = pomodoro::sound::SoundSource::find_by_name(&sound_name)
                                     .unwrap_or(pomodoro::sound::SoundSource::Embedded { name: "default".to_string() });
 
                                 if let Err(e) = sound_player.play(&source).await {
@@ -218,7 +501,45 @@ async fn main() -> Result<()> {
                         }
                     }
                 }
+            };
+
+            // グレースフルシャットダウン
+            println!("Shutting down daemon ({})", reason);
+            // リーダーの終了でフォロワーのタイマーを止めないよう、停止より先にチームタイマーを切断する
+            if let Some(task) = team_task {
+                task.abort();
+            }
+            let mut stopped = 0;
+            for (name, timer) in timers.timers() {
+                match timer.engine().lock().await.shutdown() {
+                    Ok(true) => stopped += 1,
+                    Ok(false) => {}
+                    Err(e) => eprintln!("Failed to stop timer ({}): {}", name, e),
+                }
             }
+
+            // 停止イベントが購読者へ転送されるのを待つ
+            for _ in 0..stopped {
+                let drain = std::time::Duration::from_millis(SHUTDOWN_EVENT_DRAIN_MS);
+                match tokio::time::timeout(drain, rx.recv()).await {
+                    Ok(Some((name, event))) => println!("Event received ({}): {:?}", name, event),
+                    _ => break,
+                }
+            }
+
+            // 実行中のフックの完了を待つ（猶予を過ぎたら打ち切る）
+            let grace = std::time::Duration::from_secs(SHUTDOWN_HOOK_GRACE_SECS);
+            if !hook_executor.wait_for_pending(grace).await {
+                eprintln!(
+                    "Timed out waiting for {} hook(s) to finish",
+                    hook_executor.pending_count()
+                );
+            }
+
+            // ソケットとPIDファイルを削除する
+            drop(server);
+            drop(_daemon_lock);
+            println!("Daemon stopped");
         }
         Commands::Completions { shell } => {
             generate_completions(shell);
@@ -228,13 +549,66 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-/// ソケットパスを取得
-fn get_socket_path() -> std::path::PathBuf {
-    let home = std::env::var("HOME")
-        .or_else(|_| std::env::var("USERPROFILE"))
-        .unwrap_or_else(|_| ".".to_string());
+/// 終了時に実行中のフックを待つ最大秒数
+const SHUTDOWN_HOOK_GRACE_SECS: u64 = 5;
+
+/// 終了時に停止イベントの転送を待つ最大時間（ミリ秒、タイマーごと）
+const SHUTDOWN_EVENT_DRAIN_MS: u64 = 500;
+
+/// ステータス表示の更新間隔（ミリ秒）
+const STATUS_REDRAW_INTERVAL_MS: u64 = 200;
 
-    std::path::PathBuf::from(home)
-        .join(".pomodoro")
-        .join("pomodoro.sock")
+/// ステータスをリアルタイム表示
+///
+/// イベント購読で受信したスナップショットとTickイベントで状態を更新し、
+/// アニメーションのために200ms間隔で再描画する。タイマーが停止したら終了する。
+async fn show_status(client: &IpcClient, display: &Display) -> Result<()> {
+    let mut subscription = client.subscribe().await?;
+    let mut state = EnhancedDisplayState::new();
+    let mut latest: Option<ResponseData> = None;
+    let mut redraw =
+        tokio::time::interval(std::time::Duration::from_millis(STATUS_REDRAW_INTERVAL_MS));
+
+    loop {
+        tokio::select! {
+            message = subscription.next() => match message? {
+                Some(StreamMessage::Snapshot { data }) => {
+                    // 失敗したフックは表示を始める前に一度だけ表示する
+                    if latest.is_none() {
+                        if let Some(failures) = &data.hook_failures {
+                            display.show_hook_failures(failures);
+                        }
+                    }
+                    latest = Some(data);
+                }
+                Some(StreamMessage::Event {
+                    event: TimerEvent::Tick { remaining_seconds },
+                }) => {
+                    if let Some(data) = latest.as_mut() {
+                        data.remaining_seconds = Some(remaining_seconds);
+                    }
+                }
+                // フェーズ変化はデーモンが続けて送るスナップショットで反映する
+                Some(StreamMessage::Event { .. }) => {}
+                None => anyhow::bail!("Connection closed by daemon"),
+            },
+            _ = redraw.tick() => {
+                if let Some(data) = &latest {
+                    let response = IpcResponse::success("", Some(data.clone()));
+                    if !display.update_status_enhanced(response, &mut state) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// タイマーイベントを購読して1行1メッセージのJSONで出力
+async fn watch_events(client: &IpcClient) -> Result<()> {
+    let mut subscription = client.subscribe().await?;
+    while let Some(message) = subscription.next().await? {
+        println!("{}", serde_json::to_string(&message)?);
+    }
+    Ok(())
 }
@@ -0,0 +1,428 @@
+//! メトリクスの集計
+//!
+//! デーモンの動作（完了したポモドーロ、一時停止、フックの実行、IPCリクエスト）を集計し、
+//! Prometheusのテキスト形式で出力する。
+//! カウンタはデーモンの起動からの累計で、再起動するとリセットされる。
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::types::{TimerEvent, TimerPhase};
+
+/// 出力するフェーズ（`pomodoro_timer_phase` の `phase` ラベル）
+const PHASES: [TimerPhase; 5] = [
+    TimerPhase::Stopped,
+    TimerPhase::Working,
+    TimerPhase::Breaking,
+    TimerPhase::LongBreaking,
+    TimerPhase::Paused,
+];
+
+/// メトリクスの集計
+#[derive(Debug, Default)]
+pub struct Metrics {
+    counters: Mutex<Counters>,
+}
+
+/// 累計カウンタ（ラベル順に出力するためBTreeMapで保持する）
+#[derive(Debug, Default)]
+struct Counters {
+    /// タイマーごとの完了したポモドーロ数
+    pomodoros_completed: BTreeMap<String, u64>,
+    /// タスクごとの完了したポモドーロ数（タスク名なしは空文字）
+    task_pomodoros_completed: BTreeMap<String, u64>,
+    /// タイマーごとの一時停止回数
+    pauses: BTreeMap<String, u64>,
+    /// フックごとの実行結果
+    hooks: BTreeMap<String, HookStats>,
+    /// コマンドごとのIPCリクエスト数
+    ipc_requests: BTreeMap<String, u64>,
+}
+
+/// フックの実行結果の集計
+#[derive(Debug, Default)]
+struct HookStats {
+    executions: u64,
+    failures: u64,
+    duration_seconds: f64,
+}
+
+/// 出力時点のタイマーの状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimerGauge {
+    /// タイマー名
+    pub timer: String,
+    /// 現在のフェーズ
+    pub phase: TimerPhase,
+    /// 残り時間（秒）
+    pub remaining_seconds: u32,
+}
+
+impl Metrics {
+    /// 新しいMetricsを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// タイマーイベントを記録
+    ///
+    /// 作業完了（ポモドーロ数）と一時停止を集計する。その他のイベントは無視する。
+    pub fn record_event(&self, timer: &str, event: &TimerEvent) {
+        let mut counters = self.lock();
+        match event {
+            TimerEvent::WorkCompleted { task_name, .. } => {
+                *counters
+                    .pomodoros_completed
+                    .entry(timer.to_string())
+                    .or_default() += 1;
+                *counters
+                    .task_pomodoros_completed
+                    .entry(task_name.clone().unwrap_or_default())
+                    .or_default() += 1;
+            }
+            TimerEvent::Paused => {
+                *counters.pauses.entry(timer.to_string()).or_default() += 1;
+            }
+            _ => {}
+        }
+    }
+
+    /// フックの実行結果を記録
+    pub fn record_hook(&self, hook: &str, success: bool, duration: Duration) {
+        let mut counters = self.lock();
+        let stats = counters.hooks.entry(hook.to_string()).or_default();
+        stats.executions += 1;
+        if !success {
+            stats.failures += 1;
+        }
+        stats.duration_seconds += duration.as_secs_f64();
+    }
+
+    /// IPCリクエストを記録
+    pub fn record_ipc_request(&self, command: &str) {
+        *self
+            .lock()
+            .ipc_requests
+            .entry(command.to_string())
+            .or_default() += 1;
+    }
+
+    /// Prometheusのテキスト形式で出力
+    ///
+    /// # Arguments
+    ///
+    /// * `timers` - 出力時点の各タイマーの状態
+    pub fn render(&self, timers: &[TimerGauge]) -> String {
+        let counters = self.lock();
+        let mut out = String::new();
+
+        header(
+            &mut out,
+            "pomodoro_timer_phase",
+            "gauge",
+            "Current phase of each timer (1 for the active phase)",
+        );
+        for gauge in timers {
+            for phase in PHASES {
+                let value = u8::from(gauge.phase == phase);
+                sample(
+                    &mut out,
+                    "pomodoro_timer_phase",
+                    &[("timer", &gauge.timer), ("phase", phase.as_str())],
+                    value,
+                );
+            }
+        }
+
+        header(
+            &mut out,
+            "pomodoro_timer_remaining_seconds",
+            "gauge",
+            "Remaining seconds in the current phase",
+        );
+        for gauge in timers {
+            sample(
+                &mut out,
+                "pomodoro_timer_remaining_seconds",
+                &[("timer", &gauge.timer)],
+                gauge.remaining_seconds,
+            );
+        }
+
+        header(
+            &mut out,
+            "pomodoro_pomodoros_completed_total",
+            "counter",
+            "Completed pomodoros per timer",
+        );
+        for (timer, count) in &counters.pomodoros_completed {
+            sample(
+                &mut out,
+                "pomodoro_pomodoros_completed_total",
+                &[("timer", timer)],
+                count,
+            );
+        }
+
+        header(
+            &mut out,
+            "pomodoro_task_pomodoros_completed_total",
+            "counter",
+            "Completed pomodoros per task",
+        );
+        for (task, count) in &counters.task_pomodoros_completed {
+            sample(
+                &mut out,
+                "pomodoro_task_pomodoros_completed_total",
+                &[("task", task)],
+                count,
+            );
+        }
+
+        header(
+            &mut out,
+            "pomodoro_pauses_total",
+            "counter",
+            "Number of pauses per timer",
+        );
+        for (timer, count) in &counters.pauses {
+            sample(
+                &mut out,
+                "pomodoro_pauses_total",
+                &[("timer", timer)],
+                count,
+            );
+        }
+
+        header(
+            &mut out,
+            "pomodoro_hook_executions_total",
+            "counter",
+            "Hook executions",
+        );
+        for (hook, stats) in &counters.hooks {
+            sample(
+                &mut out,
+                "pomodoro_hook_executions_total",
+                &[("hook", hook)],
+                stats.executions,
+            );
+        }
+
+        header(
+            &mut out,
+            "pomodoro_hook_failures_total",
+            "counter",
+            "Hook failures, including timeouts",
+        );
+        for (hook, stats) in &counters.hooks {
+            sample(
+                &mut out,
+                "pomodoro_hook_failures_total",
+                &[("hook", hook)],
+                stats.failures,
+            );
+        }
+
+        header(
+            &mut out,
+            "pomodoro_hook_duration_seconds",
+            "summary",
+            "Hook execution time in seconds",
+        );
+        for (hook, stats) in &counters.hooks {
+            sample(
+                &mut out,
+                "pomodoro_hook_duration_seconds_sum",
+                &[("hook", hook)],
+                stats.duration_seconds,
+            );
+            sample(
+                &mut out,
+                "pomodoro_hook_duration_seconds_count",
+                &[("hook", hook)],
+                stats.executions,
+            );
+        }
+
+        header(
+            &mut out,
+            "pomodoro_ipc_requests_total",
+            "counter",
+            "IPC requests received per command",
+        );
+        for (command, count) in &counters.ipc_requests {
+            sample(
+                &mut out,
+                "pomodoro_ipc_requests_total",
+                &[("command", command)],
+                count,
+            );
+        }
+
+        out
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Counters> {
+        self.counters.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// `# HELP` と `# TYPE` の行を出力
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+/// サンプルの行を出力
+fn sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: impl std::fmt::Display) {
+    out.push_str(name);
+    if !labels.is_empty() {
+        out.push('{');
+        for (i, (key, value)) in labels.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{}=\"{}\"", key, escape_label(value));
+        }
+        out.push('}');
+    }
+    let _ = writeln!(out, " {}", value);
+}
+
+/// ラベル値のエスケープ（`\`・`"`・改行）
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('"', r#"\""#)
+        .replace('\n', r"\n")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gauge(timer: &str, phase: TimerPhase, remaining_seconds: u32) -> TimerGauge {
+        TimerGauge {
+            timer: timer.to_string(),
+            phase,
+            remaining_seconds,
+        }
+    }
+
+    fn work_completed(task: Option<&str>) -> TimerEvent {
+        TimerEvent::WorkCompleted {
+            pomodoro_count: 1,
+            task_name: task.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_render_timer_gauges() {
+        let metrics = Metrics::new();
+
+        let output = metrics.render(&[gauge("default", TimerPhase::Working, 1234)]);
+
+        assert!(output.contains("# TYPE pomodoro_timer_phase gauge\n"));
+        assert!(output.contains("pomodoro_timer_phase{timer=\"default\",phase=\"working\"} 1\n"));
+        assert!(output.contains("pomodoro_timer_phase{timer=\"default\",phase=\"paused\"} 0\n"));
+        assert!(output.contains("pomodoro_timer_remaining_seconds{timer=\"default\"} 1234\n"));
+    }
+
+    #[test]
+    fn test_record_completed_pomodoros() {
+        let metrics = Metrics::new();
+        metrics.record_event("default", &work_completed(Some("設計")));
+        metrics.record_event("default", &work_completed(Some("設計")));
+        metrics.record_event("prep", &work_completed(None));
+
+        let output = metrics.render(&[]);
+
+        assert!(output.contains("pomodoro_pomodoros_completed_total{timer=\"default\"} 2\n"));
+        assert!(output.contains("pomodoro_pomodoros_completed_total{timer=\"prep\"} 1\n"));
+        assert!(output.contains("pomodoro_task_pomodoros_completed_total{task=\"設計\"} 2\n"));
+        assert!(output.contains("pomodoro_task_pomodoros_completed_total{task=\"\"} 1\n"));
+    }
+
+    #[test]
+    fn test_record_pauses_ignores_other_events() {
+        let metrics = Metrics::new();
+        metrics.record_event("default", &TimerEvent::Paused);
+        metrics.record_event("default", &TimerEvent::Resumed);
+        metrics.record_event(
+            "default",
+            &TimerEvent::Tick {
+                remaining_seconds: 10,
+            },
+        );
+
+        let output = metrics.render(&[]);
+
+        assert!(output.contains("pomodoro_pauses_total{timer=\"default\"} 1\n"));
+        assert!(!output.contains("pomodoro_pomodoros_completed_total{"));
+    }
+
+    #[test]
+    fn test_record_hooks() {
+        let metrics = Metrics::new();
+        metrics.record_hook("slack", true, Duration::from_millis(500));
+        metrics.record_hook("slack", false, Duration::from_millis(1500));
+
+        let output = metrics.render(&[]);
+
+        assert!(output.contains("pomodoro_hook_executions_total{hook=\"slack\"} 2\n"));
+        assert!(output.contains("pomodoro_hook_failures_total{hook=\"slack\"} 1\n"));
+        assert!(output.contains("# TYPE pomodoro_hook_duration_seconds summary\n"));
+        assert!(output.contains("pomodoro_hook_duration_seconds_sum{hook=\"slack\"} 2\n"));
+        assert!(output.contains("pomodoro_hook_duration_seconds_count{hook=\"slack\"} 2\n"));
+    }
+
+    #[test]
+    fn test_record_ipc_requests() {
+        let metrics = Metrics::new();
+        metrics.record_ipc_request("status");
+        metrics.record_ipc_request("status");
+        metrics.record_ipc_request("timer.start");
+
+        let output = metrics.render(&[]);
+
+        assert!(output.contains("pomodoro_ipc_requests_total{command=\"status\"} 2\n"));
+        assert!(output.contains("pomodoro_ipc_requests_total{command=\"timer.start\"} 1\n"));
+    }
+
+    #[test]
+    fn test_every_metric_has_help_and_type() {
+        let output = Metrics::new().render(&[]);
+
+        for name in [
+            "pomodoro_timer_phase",
+            "pomodoro_timer_remaining_seconds",
+            "pomodoro_pomodoros_completed_total",
+            "pomodoro_task_pomodoros_completed_total",
+            "pomodoro_pauses_total",
+            "pomodoro_hook_executions_total",
+            "pomodoro_hook_failures_total",
+            "pomodoro_hook_duration_seconds",
+            "pomodoro_ipc_requests_total",
+        ] {
+            assert!(output.contains(&format!("# HELP {} ", name)), "{}", name);
+            assert!(output.contains(&format!("# TYPE {} ", name)), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_escape_label() {
+        let metrics = Metrics::new();
+        metrics.record_event("default", &work_completed(Some("a\"b\\c\nd")));
+
+        let output = metrics.render(&[]);
+
+        assert!(output.contains(r#"{task="a\"b\\c\nd"} 1"#));
+    }
+}
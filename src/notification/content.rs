@@ -103,6 +103,17 @@ pub fn create_long_break_complete_content(
     builder.build()
 }
 
+pub fn create_custom_content(
+    title: Option<&str>,
+    body: &str,
+) -> Retained<UNMutableNotificationContent> {
+    NotificationContentBuilder::new()
+        .title(title.unwrap_or("🍅 ポモドーロタイマー"))
+        .body(body)
+        .with_default_sound()
+        .build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,7 +8,8 @@ use objc2_user_notifications::{UNMutableNotificationContent, UNNotificationReque
 use super::actions::create_categories;
 use super::center::NotificationCenter;
 use super::content::{
-    create_break_complete_content, create_long_break_complete_content, create_work_complete_content,
+    create_break_complete_content, create_custom_content, create_long_break_complete_content,
+    create_work_complete_content,
 };
 use super::delegate::{NotificationActionEvent, NotificationDelegate};
 use super::error::NotificationError;
@@ -112,6 +113,24 @@ impl NotificationManager {
         Ok(request_id)
     }
 
+    pub fn send_custom_notification(
+        &self,
+        title: Option<&str>,
+        body: &str,
+    ) -> Result<NotificationRequestId, NotificationError> {
+        let content = create_custom_content(title, body);
+        let request_id = NotificationRequestId::new();
+        let request = create_un_request(&request_id, &content);
+
+        let (tx, rx) = channel();
+        self.center.add_notification_request(&request, tx);
+
+        rx.recv()
+            .map_err(|_| NotificationError::SendFailed("チャネル受信エラー".to_string()))??;
+
+        Ok(request_id)
+    }
+
     pub fn try_recv_action(&self) -> Result<NotificationActionEvent, TryRecvError> {
         self.action_receiver.try_recv()
     }
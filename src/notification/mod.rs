@@ -21,7 +21,7 @@ pub use actions::{create_actions, create_categories};
 pub use center::NotificationCenter;
 #[cfg(target_os = "macos")]
 pub use content::{
-    create_break_complete_content, create_long_break_complete_content,
+    create_break_complete_content, create_custom_content, create_long_break_complete_content,
     create_work_complete_content, NotificationContentBuilder,
 };
 #[cfg(target_os = "macos")]
@@ -0,0 +1,240 @@
+//! ファイルパスの解決
+//!
+//! 設定・履歴などを置くデータディレクトリと、デーモンのソケットパスを決定する。
+//! デーモン・CLI・フック・LaunchAgentはすべてこのモジュールを通してパスを取得する。
+//!
+//! # データディレクトリ
+//!
+//! 1. 環境変数 `POMODORO_HOME`
+//! 2. （Linux）`~/.pomodoro` が存在しなければ `$XDG_CONFIG_HOME/pomodoro`（未設定時は `~/.config/pomodoro`）
+//! 3. `~/.pomodoro`
+//!
+//! # ソケットパス
+//!
+//! 1. `--socket` オプション
+//! 2. 環境変数 `POMODORO_SOCKET`
+//! 3. `POMODORO_HOME` が設定されていれば `$POMODORO_HOME/pomodoro.sock`
+//! 4. （Linux）`$XDG_RUNTIME_DIR/pomodoro/pomodoro.sock`
+//! 5. データディレクトリの `pomodoro.sock`
+
+use std::path::{Path, PathBuf};
+
+/// データディレクトリを指定する環境変数
+pub const HOME_ENV: &str = "POMODORO_HOME";
+
+/// ソケットパスを指定する環境変数
+pub const SOCKET_ENV: &str = "POMODORO_SOCKET";
+
+/// ソケットのファイル名
+pub const SOCKET_FILE_NAME: &str = "pomodoro.sock";
+
+/// ホームディレクトリ直下のデータディレクトリ名
+const LEGACY_DIR_NAME: &str = ".pomodoro";
+
+/// XDGディレクトリ内のサブディレクトリ名
+const XDG_DIR_NAME: &str = "pomodoro";
+
+/// 空でない環境変数をパスとして取得
+fn env_path(name: &str) -> Option<PathBuf> {
+    std::env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// XDG設定ディレクトリ（Linuxのみ）
+fn xdg_config_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "linux") {
+        dirs::config_dir()
+    } else {
+        None
+    }
+}
+
+/// XDGランタイムディレクトリ（Linuxのみ）
+fn xdg_runtime_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "linux") {
+        dirs::runtime_dir()
+    } else {
+        None
+    }
+}
+
+/// `POMODORO_HOME` で指定されたデータディレクトリを取得（未設定ならNone）
+pub fn home_override() -> Option<PathBuf> {
+    env_path(HOME_ENV)
+}
+
+/// データディレクトリを取得
+///
+/// ホームディレクトリが分からず、`POMODORO_HOME` も設定されていない場合はNone。
+pub fn data_dir() -> Option<PathBuf> {
+    resolve_data_dir(home_override(), dirs::home_dir(), xdg_config_dir())
+}
+
+/// データディレクトリ内のファイルパスを取得（例: `hooks.json`）
+pub fn data_file(name: &str) -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join(name))
+}
+
+/// ソケットパスの明示的な指定を取得（`--socket` オプション、なければ `POMODORO_SOCKET`）
+///
+/// 自動起動するデーモンやLaunchAgentに同じソケットを引き継ぐために使用する。
+pub fn socket_override(explicit: Option<&Path>) -> Option<PathBuf> {
+    explicit
+        .map(Path::to_path_buf)
+        .or_else(|| env_path(SOCKET_ENV))
+}
+
+/// ソケットパスを取得
+///
+/// # Arguments
+///
+/// * `explicit` - `--socket` オプションで指定されたパス
+pub fn socket_path(explicit: Option<&Path>) -> PathBuf {
+    resolve_socket_path(
+        socket_override(explicit),
+        home_override(),
+        xdg_runtime_dir(),
+        data_dir(),
+    )
+}
+
+/// データディレクトリを決定する
+fn resolve_data_dir(
+    pomodoro_home: Option<PathBuf>,
+    home: Option<PathBuf>,
+    xdg_config: Option<PathBuf>,
+) -> Option<PathBuf> {
+    if pomodoro_home.is_some() {
+        return pomodoro_home;
+    }
+    let legacy = home.map(|home| home.join(LEGACY_DIR_NAME));
+    match (legacy, xdg_config) {
+        // 既存の ~/.pomodoro があればそのまま使い続ける
+        (Some(legacy), _) if legacy.exists() => Some(legacy),
+        (_, Some(xdg)) => Some(xdg.join(XDG_DIR_NAME)),
+        (legacy, None) => legacy,
+    }
+}
+
+/// ソケットパスを決定する
+fn resolve_socket_path(
+    socket_override: Option<PathBuf>,
+    pomodoro_home: Option<PathBuf>,
+    xdg_runtime: Option<PathBuf>,
+    data_dir: Option<PathBuf>,
+) -> PathBuf {
+    if let Some(path) = socket_override {
+        return path;
+    }
+    if let Some(home) = pomodoro_home {
+        return home.join(SOCKET_FILE_NAME);
+    }
+    if let Some(runtime) = xdg_runtime {
+        return runtime.join(XDG_DIR_NAME).join(SOCKET_FILE_NAME);
+    }
+    data_dir
+        .unwrap_or_else(|| PathBuf::from(LEGACY_DIR_NAME))
+        .join(SOCKET_FILE_NAME)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_data_dir_prefers_pomodoro_home() {
+        let dir = resolve_data_dir(
+            Some(PathBuf::from("/srv/pomodoro")),
+            Some(PathBuf::from("/home/user")),
+            Some(PathBuf::from("/home/user/.config")),
+        );
+        assert_eq!(dir, Some(PathBuf::from("/srv/pomodoro")));
+    }
+
+    #[test]
+    fn test_data_dir_keeps_existing_legacy_dir() {
+        let home = tempdir().unwrap();
+        std::fs::create_dir(home.path().join(".pomodoro")).unwrap();
+
+        let dir = resolve_data_dir(
+            None,
+            Some(home.path().to_path_buf()),
+            Some(home.path().join(".config")),
+        );
+
+        assert_eq!(dir, Some(home.path().join(".pomodoro")));
+    }
+
+    #[test]
+    fn test_data_dir_uses_xdg_config_for_new_installs() {
+        let home = tempdir().unwrap();
+
+        let dir = resolve_data_dir(
+            None,
+            Some(home.path().to_path_buf()),
+            Some(home.path().join(".config")),
+        );
+
+        assert_eq!(dir, Some(home.path().join(".config").join("pomodoro")));
+    }
+
+    #[test]
+    fn test_data_dir_defaults_to_legacy_dir() {
+        let dir = resolve_data_dir(None, Some(PathBuf::from("/Users/me")), None);
+        assert_eq!(dir, Some(PathBuf::from("/Users/me/.pomodoro")));
+
+        assert_eq!(resolve_data_dir(None, None, None), None);
+    }
+
+    #[test]
+    fn test_socket_path_prefers_override() {
+        let path = resolve_socket_path(
+            Some(PathBuf::from("/tmp/custom.sock")),
+            Some(PathBuf::from("/srv/pomodoro")),
+            Some(PathBuf::from("/run/user/1000")),
+            Some(PathBuf::from("/home/user/.pomodoro")),
+        );
+        assert_eq!(path, PathBuf::from("/tmp/custom.sock"));
+    }
+
+    #[test]
+    fn test_socket_path_under_pomodoro_home() {
+        let path = resolve_socket_path(
+            None,
+            Some(PathBuf::from("/srv/pomodoro")),
+            Some(PathBuf::from("/run/user/1000")),
+            Some(PathBuf::from("/srv/pomodoro")),
+        );
+        assert_eq!(path, PathBuf::from("/srv/pomodoro/pomodoro.sock"));
+    }
+
+    #[test]
+    fn test_socket_path_uses_xdg_runtime_dir() {
+        let path = resolve_socket_path(
+            None,
+            None,
+            Some(PathBuf::from("/run/user/1000")),
+            Some(PathBuf::from("/home/user/.pomodoro")),
+        );
+        assert_eq!(path, PathBuf::from("/run/user/1000/pomodoro/pomodoro.sock"));
+    }
+
+    #[test]
+    fn test_socket_path_defaults_to_data_dir() {
+        let path =
+            resolve_socket_path(None, None, None, Some(PathBuf::from("/Users/me/.pomodoro")));
+        assert_eq!(path, PathBuf::from("/Users/me/.pomodoro/pomodoro.sock"));
+    }
+
+    #[test]
+    fn test_socket_override_prefers_explicit_path() {
+        let path = socket_override(Some(Path::new("/tmp/flag.sock")));
+        assert_eq!(path, Some(PathBuf::from("/tmp/flag.sock")));
+    }
+}
@@ -18,9 +18,9 @@ impl Default for SoundConfig {
 }
 
 impl SoundConfig {
-    /// デフォルトの設定ファイルパスを取得
-    fn get_config_path() -> Option<PathBuf> {
-        dirs::home_dir().map(|home| home.join(".pomodoro").join("sound-config.json"))
+    /// デフォルトの設定ファイルパス（データディレクトリの `sound-config.json`）を取得
+    pub fn get_config_path() -> Option<PathBuf> {
+        crate::paths::data_file("sound-config.json")
     }
 
     /// 設定ファイルから読み込む
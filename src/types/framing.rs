@@ -0,0 +1,198 @@
+//! IPCメッセージのフレーミング
+//!
+//! デーモンとクライアント間のメッセージを改行区切り（1行1メッセージ）で
+//! 読み書きする。JSONは改行をエスケープするため、1メッセージが1行に収まる。
+//! 最大メッセージサイズを超えるメッセージはエラーとして扱う。
+
+use thiserror::Error;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+
+/// デフォルトの最大メッセージサイズ（バイト、改行を除く）
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 1024 * 1024;
+
+/// フレーミングのエラー型
+#[derive(Debug, Error)]
+pub enum FrameError {
+    /// メッセージが最大サイズを超えている
+    #[error("メッセージサイズが上限（{max}バイト）を超えています")]
+    TooLarge {
+        /// 最大メッセージサイズ
+        max: usize,
+    },
+
+    /// IOエラー
+    #[error("IOエラー: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// 1メッセージ分のフレームを読み取る
+///
+/// 改行までのバイト列を返す。空行は読み飛ばす。
+/// 末尾に改行がないまま接続が閉じられた場合は、それまでのデータを1メッセージとして扱う。
+///
+/// # Returns
+///
+/// 読み取ったメッセージ。メッセージの途中でなく接続が閉じられた場合は `None`。
+///
+/// # Errors
+///
+/// - メッセージが `max_size` を超えた場合（`FrameError::TooLarge`）
+/// - 読み取りに失敗した場合
+pub async fn read_frame<R>(reader: &mut R, max_size: usize) -> Result<Option<Vec<u8>>, FrameError>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut frame = Vec::new();
+
+    loop {
+        let available = reader.fill_buf().await?;
+        if available.is_empty() {
+            return Ok((!frame.is_empty()).then_some(frame));
+        }
+
+        let (chunk_len, consumed, complete) = match available.iter().position(|&b| b == b'\n') {
+            Some(pos) => (pos, pos + 1, true),
+            None => (available.len(), available.len(), false),
+        };
+
+        if frame.len() + chunk_len > max_size {
+            return Err(FrameError::TooLarge { max: max_size });
+        }
+
+        frame.extend_from_slice(&available[..chunk_len]);
+        reader.consume(consumed);
+
+        if complete {
+            if frame.iter().all(u8::is_ascii_whitespace) {
+                frame.clear();
+                continue;
+            }
+            return Ok(Some(frame));
+        }
+    }
+}
+
+/// 1メッセージ分のフレームを書き込む
+///
+/// メッセージの末尾に改行を付けて書き込み、フラッシュする。
+/// メッセージに改行を含めてはならない。
+///
+/// # Errors
+///
+/// - メッセージが `max_size` を超えた場合（`FrameError::TooLarge`）
+/// - 書き込みに失敗した場合
+pub async fn write_frame<W>(
+    writer: &mut W,
+    payload: &[u8],
+    max_size: usize,
+) -> Result<(), FrameError>
+where
+    W: AsyncWrite + Unpin,
+{
+    if payload.len() > max_size {
+        return Err(FrameError::TooLarge { max: max_size });
+    }
+
+    let mut buffer = Vec::with_capacity(payload.len() + 1);
+    buffer.extend_from_slice(payload);
+    buffer.push(b'\n');
+
+    writer.write_all(&buffer).await?;
+    writer.flush().await?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::BufReader;
+
+    #[tokio::test]
+    async fn test_read_frame_multiple_messages() {
+        let mut reader = BufReader::new(&b"{\"a\":1}\n{\"b\":2}\n"[..]);
+
+        let first = read_frame(&mut reader, 64).await.unwrap();
+        let second = read_frame(&mut reader, 64).await.unwrap();
+        let end = read_frame(&mut reader, 64).await.unwrap();
+
+        assert_eq!(first.as_deref(), Some(&b"{\"a\":1}"[..]));
+        assert_eq!(second.as_deref(), Some(&b"{\"b\":2}"[..]));
+        assert!(end.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_read_frame_without_trailing_newline() {
+        let mut reader = BufReader::new(&b"{\"a\":1}"[..]);
+
+        let frame = read_frame(&mut reader, 64).await.unwrap();
+        assert_eq!(frame.as_deref(), Some(&b"{\"a\":1}"[..]));
+        assert!(read_frame(&mut reader, 64).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_read_frame_skips_blank_lines() {
+        let mut reader = BufReader::new(&b"\n\r\n{\"a\":1}\n"[..]);
+
+        let frame = read_frame(&mut reader, 64).await.unwrap();
+        assert_eq!(frame.as_deref(), Some(&b"{\"a\":1}"[..]));
+    }
+
+    #[tokio::test]
+    async fn test_read_frame_across_partial_reads() {
+        // 小さいバッファで1メッセージが複数回の読み取りに分割されるケース
+        let data = format!("{{\"taskName\":\"{}\"}}\n", "あ".repeat(100));
+        let mut reader = BufReader::with_capacity(8, data.as_bytes());
+
+        let frame = read_frame(&mut reader, 1024).await.unwrap().unwrap();
+        assert_eq!(frame, data.trim_end().as_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_read_frame_too_large() {
+        let data = format!("{}\n", "x".repeat(100));
+        let mut reader = BufReader::new(data.as_bytes());
+
+        let result = read_frame(&mut reader, 10).await;
+        assert!(matches!(result, Err(FrameError::TooLarge { max: 10 })));
+    }
+
+    #[tokio::test]
+    async fn test_read_frame_exactly_max_size() {
+        let data = format!("{}\n", "x".repeat(10));
+        let mut reader = BufReader::new(data.as_bytes());
+
+        let frame = read_frame(&mut reader, 10).await.unwrap().unwrap();
+        assert_eq!(frame.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_write_frame_appends_newline() {
+        let mut buffer = Vec::new();
+        write_frame(&mut buffer, b"{\"a\":1}", 64).await.unwrap();
+
+        assert_eq!(buffer, b"{\"a\":1}\n");
+    }
+
+    #[tokio::test]
+    async fn test_write_frame_too_large() {
+        let mut buffer = Vec::new();
+        let result = write_frame(&mut buffer, &[b'x'; 100], 10).await;
+
+        assert!(matches!(result, Err(FrameError::TooLarge { max: 10 })));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_frame_error_message() {
+        let error = FrameError::TooLarge { max: 4096 };
+        assert_eq!(
+            error.to_string(),
+            "メッセージサイズが上限（4096バイト）を超えています"
+        );
+    }
+}
@@ -2,7 +2,11 @@
 //!
 //! タイマーの状態管理とIPC通信に使用するデータ型を提供する。
 
+pub mod framing;
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// タイマーのフェーズ
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -32,6 +36,18 @@ impl TimerPhase {
         }
     }
 
+    /// フェーズ名（`as_str` の値）から変換
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "stopped" => Some(TimerPhase::Stopped),
+            "working" => Some(TimerPhase::Working),
+            "breaking" => Some(TimerPhase::Breaking),
+            "long_breaking" => Some(TimerPhase::LongBreaking),
+            "paused" => Some(TimerPhase::Paused),
+            _ => None,
+        }
+    }
+
     /// 実行中のフェーズかどうか
     pub fn is_active(&self) -> bool {
         matches!(
@@ -78,9 +94,42 @@ pub enum HookEvent {
     Resume,
     /// 停止
     Stop,
+    /// 作業開始の前（拒否可能）
+    BeforeWorkStart,
+    /// 休憩開始の前（拒否可能）
+    BeforeBreakStart,
+    /// 長い休憩開始の前（拒否可能）
+    BeforeLongBreakStart,
+    /// 休憩終了の前（拒否可能）
+    BeforeBreakEnd,
+    /// 長い休憩終了の前（拒否可能）
+    BeforeLongBreakEnd,
 }
 
 impl HookEvent {
+    /// すべてのイベント（設定ファイル・一覧表示の順序）
+    pub const ALL: [HookEvent; 14] = [
+        HookEvent::WorkStart,
+        HookEvent::WorkEnd,
+        HookEvent::BreakStart,
+        HookEvent::BreakEnd,
+        HookEvent::LongBreakStart,
+        HookEvent::LongBreakEnd,
+        HookEvent::Pause,
+        HookEvent::Resume,
+        HookEvent::Stop,
+        HookEvent::BeforeWorkStart,
+        HookEvent::BeforeBreakStart,
+        HookEvent::BeforeLongBreakStart,
+        HookEvent::BeforeBreakEnd,
+        HookEvent::BeforeLongBreakEnd,
+    ];
+
+    /// イベント名から変換
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|event| event.as_str() == name)
+    }
+
     /// イベント名を取得
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -93,8 +142,25 @@ impl HookEvent {
             HookEvent::Pause => "pause",
             HookEvent::Resume => "resume",
             HookEvent::Stop => "stop",
+            HookEvent::BeforeWorkStart => "before_work_start",
+            HookEvent::BeforeBreakStart => "before_break_start",
+            HookEvent::BeforeLongBreakStart => "before_long_break_start",
+            HookEvent::BeforeBreakEnd => "before_break_end",
+            HookEvent::BeforeLongBreakEnd => "before_long_break_end",
         }
     }
+
+    /// 状態遷移の前に同期的に実行し、遷移を拒否できるイベントかどうか
+    pub fn is_pre_transition(&self) -> bool {
+        matches!(
+            self,
+            HookEvent::BeforeWorkStart
+                | HookEvent::BeforeBreakStart
+                | HookEvent::BeforeLongBreakStart
+                | HookEvent::BeforeBreakEnd
+                | HookEvent::BeforeLongBreakEnd
+        )
+    }
 }
 
 /// タイマー設定
@@ -175,8 +241,15 @@ pub struct TimerState {
     /// タイマー設定
     pub config: PomodoroConfig,
     /// 一時停止前のフェーズ（再開時に使用）
-    #[serde(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     previous_phase: Option<TimerPhase>,
+    /// 現在のフェーズで延長した時間（秒、フェーズの開始時にリセット）
+    #[serde(default, skip_serializing_if = "is_zero")]
+    extended_seconds: u32,
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
 }
 
 impl TimerState {
@@ -189,6 +262,7 @@ impl TimerState {
             task_name: None,
             config,
             previous_phase: None,
+            extended_seconds: 0,
         }
     }
 
@@ -198,12 +272,17 @@ impl TimerState {
         self.remaining_seconds = self.config.work_minutes * 60;
         self.task_name = task_name;
         self.previous_phase = None;
+        self.extended_seconds = 0;
+    }
+
+    /// 次の休憩が長い休憩かどうか（4ポモドーロごとに長い休憩）
+    pub fn next_break_is_long(&self) -> bool {
+        self.pomodoro_count > 0 && self.pomodoro_count % 4 == 0
     }
 
     /// 休憩フェーズを開始
     pub fn start_breaking(&mut self) {
-        // 4ポモドーロごとに長い休憩
-        if self.pomodoro_count > 0 && self.pomodoro_count % 4 == 0 {
+        if self.next_break_is_long() {
             self.phase = TimerPhase::LongBreaking;
             self.remaining_seconds = self.config.long_break_minutes * 60;
         } else {
@@ -211,6 +290,7 @@ impl TimerState {
             self.remaining_seconds = self.config.break_minutes * 60;
         }
         self.previous_phase = None;
+        self.extended_seconds = 0;
     }
 
     /// 一時停止
@@ -242,6 +322,13 @@ impl TimerState {
         self.remaining_seconds = 0;
         self.task_name = None;
         self.previous_phase = None;
+        self.extended_seconds = 0;
+    }
+
+    /// 現在のフェーズの残り時間を延長
+    pub fn extend(&mut self, secs: u32) {
+        self.remaining_seconds = self.remaining_seconds.saturating_add(secs);
+        self.extended_seconds = self.extended_seconds.saturating_add(secs);
     }
 
     /// 1秒経過
@@ -266,27 +353,157 @@ impl TimerState {
         self.phase == TimerPhase::Paused
     }
 
-    /// 現在のフェーズの合計時間（秒）を取得
-    pub fn current_duration(&self) -> u32 {
-        let phase = if self.phase == TimerPhase::Paused {
+    /// 一時停止を除いたフェーズを取得
+    ///
+    /// 一時停止中の場合は一時停止前のフェーズを返す（不明な場合は停止中）。
+    pub fn active_phase(&self) -> TimerPhase {
+        if self.phase == TimerPhase::Paused {
             self.previous_phase.unwrap_or(TimerPhase::Stopped)
         } else {
             self.phase
-        };
+        }
+    }
 
-        match phase {
+    /// 現在のフェーズの合計時間（秒、延長した時間を含む）を取得
+    pub fn current_duration(&self) -> u32 {
+        let duration = match self.active_phase() {
             TimerPhase::Working => self.config.work_minutes * 60,
             TimerPhase::Breaking => self.config.break_minutes * 60,
             TimerPhase::LongBreaking => self.config.long_break_minutes * 60,
-            _ => 0,
-        }
+            _ => return 0,
+        };
+        duration.saturating_add(self.extended_seconds)
     }
 }
 
+/// タイマーイベント
+///
+/// タイマーエンジンが発火するイベント。
+/// 通知システム、サウンド再生、メニューバーUI更新、IPCのイベント購読などに使用。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TimerEvent {
+    /// 作業開始
+    WorkStarted {
+        #[serde(rename = "taskName")]
+        task_name: Option<String>,
+    },
+    /// 作業完了
+    WorkCompleted {
+        #[serde(rename = "pomodoroCount")]
+        pomodoro_count: u32,
+        #[serde(rename = "taskName")]
+        task_name: Option<String>,
+    },
+    /// 休憩開始
+    BreakStarted {
+        #[serde(rename = "isLongBreak")]
+        is_long_break: bool,
+    },
+    /// 休憩完了
+    BreakCompleted {
+        #[serde(rename = "isLongBreak")]
+        is_long_break: bool,
+    },
+    /// 一時停止
+    Paused,
+    /// 再開
+    Resumed,
+    /// 停止
+    Stopped,
+    /// ティック（1秒経過）
+    Tick {
+        #[serde(rename = "remainingSeconds")]
+        remaining_seconds: u32,
+    },
+}
+
 // ============================================================================
 // IPC Types
 // ============================================================================
 
+/// IPCプロトコルのバージョン
+///
+/// リクエスト・レスポンスの形式に互換性のない変更を加えた場合に上げる。
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// デフォルトタイマーの名前（タイマー名を省略したリクエストの対象）
+pub const DEFAULT_TIMER_NAME: &str = "default";
+
+/// IPCエラーコード
+///
+/// エラーレスポンスの種類を表す。クライアントはメッセージの文字列ではなく
+/// このコードでエラーを判別する。`Display` はデーモン側の標準メッセージ。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Error)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// タイマーが既に実行中
+    #[error("タイマーは既に実行中です")]
+    AlreadyRunning,
+    /// タイマーが実行されていない
+    #[error("タイマーは実行されていません")]
+    NotRunning,
+    /// タイマーが一時停止していない
+    #[error("タイマーは一時停止していません")]
+    NotPaused,
+    /// リクエストが不正（JSONの解析失敗、この接続で使用できないコマンドなど）
+    #[error("リクエストが不正です")]
+    InvalidRequest,
+    /// メッセージが最大サイズを超えている
+    #[error("メッセージサイズが上限を超えています")]
+    MessageTooLarge,
+    /// プロトコルバージョンに互換性がない
+    #[error("サポートされていないプロトコルバージョンです")]
+    UnsupportedProtocolVersion,
+    /// 認証に失敗（HTTP APIのトークン不一致など）
+    #[error("認証に失敗しました")]
+    Unauthorized,
+    /// 対象が存在しない
+    #[error("対象が見つかりません")]
+    NotFound,
+    /// 設定ファイルが不正（再読み込み時の検証エラーなど）
+    #[error("設定が不正です")]
+    InvalidConfig,
+    /// チームタイマーのリーダーに追従中のため、ローカルでタイマーを操作できない
+    #[error("チームタイマーのリーダーに追従中です")]
+    FollowingLeader,
+    /// 事前フックが操作を拒否した
+    #[error("フックにより操作が拒否されました")]
+    HookRejected,
+    /// 事前フックの実行中にタイマーの状態が変わったため、操作を中止した
+    #[error("事前フックの実行中にタイマーの状態が変わりました")]
+    StateChanged,
+    /// デーモン内部のエラー
+    #[error("デーモン内部でエラーが発生しました")]
+    Internal,
+    /// このクライアントが知らないエラーコード（新しいデーモンとの通信時）
+    #[serde(other)]
+    #[error("不明なエラーです")]
+    Unknown,
+}
+
+impl ErrorCode {
+    /// エラーコード名を取得
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::AlreadyRunning => "already_running",
+            ErrorCode::NotRunning => "not_running",
+            ErrorCode::NotPaused => "not_paused",
+            ErrorCode::InvalidRequest => "invalid_request",
+            ErrorCode::MessageTooLarge => "message_too_large",
+            ErrorCode::UnsupportedProtocolVersion => "unsupported_protocol_version",
+            ErrorCode::Unauthorized => "unauthorized",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::InvalidConfig => "invalid_config",
+            ErrorCode::FollowingLeader => "following_leader",
+            ErrorCode::HookRejected => "hook_rejected",
+            ErrorCode::StateChanged => "state_changed",
+            ErrorCode::Internal => "internal",
+            ErrorCode::Unknown => "unknown",
+        }
+    }
+}
+
 /// IPCリクエスト
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "command", rename_all = "lowercase")]
@@ -302,8 +519,65 @@ pub enum IpcRequest {
     Resume,
     /// タイマー停止
     Stop,
+    /// 現在のフェーズをスキップ
+    Skip,
     /// ステータス確認
     Status,
+    /// イベント購読（接続を維持してStreamMessageを送り続ける）
+    Subscribe,
+    /// デーモンの終了（実行中のタイマーを停止してから終了する）
+    Shutdown,
+    /// フック設定・サウンド設定の再読み込み
+    Reload,
+    /// すべてのタイマーの状態を取得
+    List,
+    /// プロトコルのハンドシェイク（デーモンのバージョンと対応機能を取得）
+    Hello {
+        /// クライアントが使用するプロトコルバージョン
+        #[serde(rename = "protocolVersion", skip_serializing_if = "Option::is_none")]
+        protocol_version: Option<u32>,
+    },
+}
+
+impl IpcRequest {
+    /// コマンド名（JSONの `command` フィールドの値）を取得
+    pub fn command_name(&self) -> &'static str {
+        match self {
+            IpcRequest::Start { .. } => "start",
+            IpcRequest::Pause => "pause",
+            IpcRequest::Resume => "resume",
+            IpcRequest::Stop => "stop",
+            IpcRequest::Skip => "skip",
+            IpcRequest::Status => "status",
+            IpcRequest::Subscribe => "subscribe",
+            IpcRequest::Shutdown => "shutdown",
+            IpcRequest::Reload => "reload",
+            IpcRequest::List => "list",
+            IpcRequest::Hello { .. } => "hello",
+        }
+    }
+}
+
+/// 対象タイマーを指定したIPCリクエスト
+///
+/// `IpcRequest` と同じJSONに `timer` フィールドを追加した形式で送受信する
+/// （例: `{"command":"pause","timer":"prep"}`）。
+/// `timer` を省略した場合はデフォルトタイマー（`DEFAULT_TIMER_NAME`）が対象になる。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimerRequest {
+    /// 対象タイマーの名前
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timer: Option<String>,
+    /// リクエスト本体
+    #[serde(flatten)]
+    pub request: IpcRequest,
+}
+
+impl TimerRequest {
+    /// 対象タイマーの名前（省略時はデフォルトタイマー）
+    pub fn timer_name(&self) -> &str {
+        self.timer.as_deref().unwrap_or(DEFAULT_TIMER_NAME)
+    }
 }
 
 /// 開始パラメータ
@@ -330,13 +604,16 @@ pub struct IpcResponse {
     pub status: String,
     /// メッセージ
     pub message: String,
+    /// エラーコード（エラー時のみ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<ErrorCode>,
     /// データ
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<ResponseData>,
 }
 
 /// レスポンスデータ
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ResponseData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
@@ -348,6 +625,53 @@ pub struct ResponseData {
     pub task_name: Option<String>,
     #[serde(rename = "duration", skip_serializing_if = "Option::is_none")]
     pub duration: Option<u32>,
+    /// デーモンのバージョン（Helloのみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// デーモンのプロトコルバージョン（Helloのみ）
+    #[serde(rename = "protocolVersion", skip_serializing_if = "Option::is_none")]
+    pub protocol_version: Option<u32>,
+    /// デーモンが対応する機能（Helloのみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Vec<String>>,
+    /// タイマー名（Listの各要素）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timer: Option<String>,
+    /// タイマーごとの状態（Listのみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timers: Option<Vec<ResponseData>>,
+    /// 再実行しても失敗したままのフック（Status・スナップショットのみ、失敗がない場合は省略）
+    #[serde(rename = "hookFailures", skip_serializing_if = "Option::is_none")]
+    pub hook_failures: Option<Vec<HookFailure>>,
+}
+
+/// 最後の実行まで失敗したフック
+///
+/// 同じフックが次に成功するまで `pomodoro status` に表示する。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookFailure {
+    /// フック名
+    pub hook: String,
+    /// フックを発火したイベント
+    pub event: HookEvent,
+    /// 実行回数（最初の実行と再実行の合計）
+    pub attempts: u32,
+    /// 最後の実行の失敗の内容
+    pub error: String,
+    /// 最後の実行の開始時刻
+    pub timestamp: DateTime<Utc>,
+}
+
+/// イベント購読ストリームのメッセージ
+///
+/// `IpcRequest::Subscribe` の接続上で、1メッセージ1行のJSON（NDJSON）として送信される。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum StreamMessage {
+    /// タイマーイベント
+    Event { event: TimerEvent },
+    /// 状態スナップショット
+    Snapshot { data: ResponseData },
 }
 
 impl IpcResponse {
@@ -356,18 +680,25 @@ impl IpcResponse {
         Self {
             status: "success".to_string(),
             message: message.into(),
+            code: None,
             data,
         }
     }
 
     /// エラーレスポンスを作成
-    pub fn error(message: impl Into<String>) -> Self {
+    pub fn error(code: ErrorCode, message: impl Into<String>) -> Self {
         Self {
             status: "error".to_string(),
             message: message.into(),
+            code: Some(code),
             data: None,
         }
     }
+
+    /// 成功レスポンスかどうか
+    pub fn is_success(&self) -> bool {
+        self.status == "success"
+    }
 }
 
 // ============================================================================
@@ -391,6 +722,20 @@ mod tests {
         assert_eq!(TimerPhase::Paused.as_str(), "paused");
     }
 
+    #[test]
+    fn test_timer_phase_parse_roundtrip() {
+        for phase in [
+            TimerPhase::Stopped,
+            TimerPhase::Working,
+            TimerPhase::Breaking,
+            TimerPhase::LongBreaking,
+            TimerPhase::Paused,
+        ] {
+            assert_eq!(TimerPhase::parse(phase.as_str()), Some(phase));
+        }
+        assert_eq!(TimerPhase::parse("longbreaking"), None);
+    }
+
     #[test]
     fn test_timer_phase_is_active() {
         assert!(!TimerPhase::Stopped.is_active());
@@ -412,6 +757,20 @@ mod tests {
         assert_eq!(phase, TimerPhase::Breaking);
     }
 
+    // ------------------------------------------------------------------------
+    // HookEvent Tests
+    // ------------------------------------------------------------------------
+
+    #[test]
+    fn test_hook_event_parse_roundtrip() {
+        for event in HookEvent::ALL {
+            assert_eq!(HookEvent::parse(event.as_str()), Some(event));
+            let json = serde_json::to_string(&event).unwrap();
+            assert_eq!(json, format!("\"{}\"", event.as_str()));
+        }
+        assert_eq!(HookEvent::parse("workstart"), None);
+    }
+
     // ------------------------------------------------------------------------
     // PomodoroConfig Tests
     // ------------------------------------------------------------------------
@@ -688,6 +1047,113 @@ mod tests {
         assert_eq!(json, r#"{"command":"status"}"#);
     }
 
+    #[test]
+    fn test_ipc_request_command_name_matches_json() {
+        let requests = [
+            IpcRequest::Start {
+                params: StartParams::default(),
+            },
+            IpcRequest::Pause,
+            IpcRequest::Resume,
+            IpcRequest::Stop,
+            IpcRequest::Skip,
+            IpcRequest::Status,
+            IpcRequest::Subscribe,
+            IpcRequest::Shutdown,
+            IpcRequest::Reload,
+            IpcRequest::List,
+            IpcRequest::Hello {
+                protocol_version: None,
+            },
+        ];
+        for request in requests {
+            let json = serde_json::to_value(&request).unwrap();
+            assert_eq!(json["command"], request.command_name());
+        }
+    }
+
+    #[test]
+    fn test_timer_request_without_timer() {
+        let request: TimerRequest = serde_json::from_str(r#"{"command":"pause"}"#).unwrap();
+        assert!(request.timer.is_none());
+        assert_eq!(request.timer_name(), DEFAULT_TIMER_NAME);
+        assert!(matches!(request.request, IpcRequest::Pause));
+
+        // タイマー名を省略した場合は従来のIpcRequestと同じJSONになる
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(json, r#"{"command":"pause"}"#);
+    }
+
+    #[test]
+    fn test_timer_request_with_timer() {
+        let json = r#"{"command":"start","timer":"prep","workMinutes":10}"#;
+        let request: TimerRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.timer_name(), "prep");
+        match &request.request {
+            IpcRequest::Start { params } => assert_eq!(params.work_minutes, Some(10)),
+            other => panic!("expected Start, got {:?}", other),
+        }
+
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["timer"], "prep");
+        assert_eq!(value["command"], "start");
+        assert_eq!(value["workMinutes"], 10);
+    }
+
+    #[test]
+    fn test_ipc_request_ignores_timer_field() {
+        // 旧形式のIpcRequestとして読んでもtimerは無視される
+        let json = r#"{"command":"status","timer":"prep"}"#;
+        let request: IpcRequest = serde_json::from_str(json).unwrap();
+        assert!(matches!(request, IpcRequest::Status));
+    }
+
+    #[test]
+    fn test_ipc_request_subscribe_serialize() {
+        let json = serde_json::to_string(&IpcRequest::Subscribe).unwrap();
+        assert_eq!(json, r#"{"command":"subscribe"}"#);
+    }
+
+    #[test]
+    fn test_timer_event_serialize() {
+        let event = TimerEvent::WorkCompleted {
+            pomodoro_count: 2,
+            task_name: Some("開発".to_string()),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"event":"work_completed","pomodoroCount":2,"taskName":"開発"}"#
+        );
+
+        let json = serde_json::to_string(&TimerEvent::Paused).unwrap();
+        assert_eq!(json, r#"{"event":"paused"}"#);
+    }
+
+    #[test]
+    fn test_stream_message_roundtrip() {
+        let message = StreamMessage::Event {
+            event: TimerEvent::Tick {
+                remaining_seconds: 42,
+            },
+        };
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"event","event":{"event":"tick","remainingSeconds":42}}"#
+        );
+
+        let parsed: StreamMessage = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            StreamMessage::Event {
+                event: TimerEvent::Tick {
+                    remaining_seconds: 42
+                }
+            }
+        ));
+    }
+
     #[test]
     fn test_ipc_response_success() {
         let data = ResponseData {
@@ -696,6 +1162,7 @@ mod tests {
             pomodoro_count: Some(3),
             task_name: Some("開発".to_string()),
             duration: Some(1500),
+            ..Default::default()
         };
         let response = IpcResponse::success("タイマーを開始しました", Some(data));
 
@@ -706,11 +1173,87 @@ mod tests {
 
     #[test]
     fn test_ipc_response_error() {
-        let response = IpcResponse::error("タイマーは既に実行中です");
+        let response = IpcResponse::error(ErrorCode::AlreadyRunning, "タイマーは既に実行中です");
 
         assert_eq!(response.status, "error");
         assert_eq!(response.message, "タイマーは既に実行中です");
+        assert_eq!(response.code, Some(ErrorCode::AlreadyRunning));
         assert!(response.data.is_none());
+        assert!(!response.is_success());
+    }
+
+    #[test]
+    fn test_ipc_response_error_serialize_code() {
+        let response = IpcResponse::error(ErrorCode::NotPaused, "msg");
+        let json = serde_json::to_string(&response).unwrap();
+
+        assert!(json.contains("\"code\":\"not_paused\""));
+    }
+
+    #[test]
+    fn test_ipc_response_deserialize_without_code() {
+        // コードを持たない旧形式のレスポンス
+        let json = r#"{"status":"error","message":"失敗"}"#;
+        let response: IpcResponse = serde_json::from_str(json).unwrap();
+
+        assert!(response.code.is_none());
+    }
+
+    #[test]
+    fn test_error_code_unknown_variant() {
+        let json = r#"{"status":"error","message":"x","code":"some_future_code"}"#;
+        let response: IpcResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.code, Some(ErrorCode::Unknown));
+    }
+
+    #[test]
+    fn test_error_code_as_str_matches_serde() {
+        let codes = [
+            ErrorCode::AlreadyRunning,
+            ErrorCode::NotRunning,
+            ErrorCode::NotPaused,
+            ErrorCode::InvalidRequest,
+            ErrorCode::MessageTooLarge,
+            ErrorCode::UnsupportedProtocolVersion,
+            ErrorCode::Unauthorized,
+            ErrorCode::NotFound,
+            ErrorCode::InvalidConfig,
+            ErrorCode::FollowingLeader,
+            ErrorCode::HookRejected,
+            ErrorCode::StateChanged,
+            ErrorCode::Internal,
+            ErrorCode::Unknown,
+        ];
+        for code in codes {
+            let json = serde_json::to_string(&code).unwrap();
+            assert_eq!(json, format!("\"{}\"", code.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_error_code_display() {
+        assert_eq!(
+            ErrorCode::AlreadyRunning.to_string(),
+            "タイマーは既に実行中です"
+        );
+    }
+
+    #[test]
+    fn test_ipc_request_hello_serialize() {
+        let request = IpcRequest::Hello {
+            protocol_version: Some(PROTOCOL_VERSION),
+        };
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(json, r#"{"command":"hello","protocolVersion":1}"#);
+
+        let request: IpcRequest = serde_json::from_str(r#"{"command":"hello"}"#).unwrap();
+        assert!(matches!(
+            request,
+            IpcRequest::Hello {
+                protocol_version: None
+            }
+        ));
     }
 
     #[test]
@@ -27,10 +27,10 @@ async fn test_performance_ipc_latency() {
 
     let server_engine = engine.clone();
     let server_handle = tokio::spawn(async move {
-        if let Ok(mut stream) = server.accept().await {
-            if let Ok(request) = IpcServer::receive_request(&mut stream).await {
+        if let Ok(mut connection) = server.accept().await {
+            if let Ok(request) = connection.receive_request().await {
                 let response = pomodoro::daemon::handle_request(request, server_engine).await;
-                let _ = IpcServer::send_response(&mut stream, &response).await;
+                let _ = connection.send_response(&response).await;
             }
         }
     });
@@ -41,7 +41,7 @@ async fn test_performance_ipc_latency() {
 
     let mut stream = UnixStream::connect(&socket_path).await.unwrap();
     stream
-        .write_all(r#"{"command":"status"}"#.as_bytes())
+        .write_all(b"{\"command\":\"status\"}\n")
         .await
         .unwrap();
 
@@ -32,6 +32,7 @@ fn create_fast_test_engine() -> (Arc<Mutex<TimerEngine>>, mpsc::UnboundedReceive
 async fn send_ipc_request(socket_path: &std::path::Path, request_json: &str) -> String {
     let mut stream = UnixStream::connect(socket_path).await.unwrap();
     stream.write_all(request_json.as_bytes()).await.unwrap();
+    stream.write_all(b"\n").await.unwrap();
 
     let mut buffer = vec![0u8; 4096];
     let n = stream.read(&mut buffer).await.unwrap();
@@ -50,10 +51,10 @@ async fn test_e2e_complete_pomodoro_cycle() {
     let server_handle = tokio::spawn(async move {
         let server = IpcServer::new(&server_path).unwrap();
         for _ in 0..2 {
-            if let Ok(mut stream) = server.accept().await {
-                if let Ok(request) = IpcServer::receive_request(&mut stream).await {
+            if let Ok(mut connection) = server.accept().await {
+                if let Ok(request) = connection.receive_request().await {
                     let response = handle_request(request, server_engine.clone()).await;
-                    let _ = IpcServer::send_response(&mut stream, &response).await;
+                    let _ = connection.send_response(&response).await;
                 }
             }
         }
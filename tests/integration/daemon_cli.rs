@@ -41,7 +41,7 @@ async fn test_ipc_start_timer() {
     let client_handle = tokio::spawn(async move {
         tokio::time::sleep(Duration::from_millis(50)).await;
         let mut stream = UnixStream::connect(&client_path).await.unwrap();
-        let request = r#"{"command":"start","taskName":"API実装"}"#;
+        let request = "{\"command\":\"start\",\"taskName\":\"API実装\"}\n";
         stream.write_all(request.as_bytes()).await.unwrap();
 
         let mut buffer = vec![0u8; 4096];
@@ -50,12 +50,10 @@ async fn test_ipc_start_timer() {
     });
 
     // サーバー処理
-    let mut stream = server.accept().await.unwrap();
-    let request = IpcServer::receive_request(&mut stream).await.unwrap();
+    let mut connection = server.accept().await.unwrap();
+    let request = connection.receive_request().await.unwrap();
     let response = handle_request(request, engine.clone()).await;
-    IpcServer::send_response(&mut stream, &response)
-        .await
-        .unwrap();
+    connection.send_response(&response).await.unwrap();
 
     // レスポンス検証
     let client_response = client_handle.await.unwrap();
@@ -95,7 +93,7 @@ async fn test_ipc_pause_timer() {
         tokio::time::sleep(Duration::from_millis(50)).await;
         let mut stream = UnixStream::connect(&client_path).await.unwrap();
         stream
-            .write_all(r#"{"command":"pause"}"#.as_bytes())
+            .write_all(b"{\"command\":\"pause\"}\n")
             .await
             .unwrap();
 
@@ -105,12 +103,10 @@ async fn test_ipc_pause_timer() {
     });
 
     // サーバー処理
-    let mut stream = server.accept().await.unwrap();
-    let request = IpcServer::receive_request(&mut stream).await.unwrap();
+    let mut connection = server.accept().await.unwrap();
+    let request = connection.receive_request().await.unwrap();
     let response = handle_request(request, engine.clone()).await;
-    IpcServer::send_response(&mut stream, &response)
-        .await
-        .unwrap();
+    connection.send_response(&response).await.unwrap();
 
     // レスポンス検証
     let client_response = client_handle.await.unwrap();
@@ -149,7 +145,7 @@ async fn test_ipc_status() {
         tokio::time::sleep(Duration::from_millis(50)).await;
         let mut stream = UnixStream::connect(&client_path).await.unwrap();
         stream
-            .write_all(r#"{"command":"status"}"#.as_bytes())
+            .write_all(b"{\"command\":\"status\"}\n")
             .await
             .unwrap();
 
@@ -159,12 +155,10 @@ async fn test_ipc_status() {
     });
 
     // サーバー処理
-    let mut stream = server.accept().await.unwrap();
-    let request = IpcServer::receive_request(&mut stream).await.unwrap();
+    let mut connection = server.accept().await.unwrap();
+    let request = connection.receive_request().await.unwrap();
     let response = handle_request(request, engine.clone()).await;
-    IpcServer::send_response(&mut stream, &response)
-        .await
-        .unwrap();
+    connection.send_response(&response).await.unwrap();
 
     // レスポンス検証
     let client_response = client_handle.await.unwrap();
@@ -0,0 +1,94 @@
+//! チームタイマーの統合テスト
+//!
+//! 1つのプロセス内で2つのデーモン相当（リーダーとフォロワーのTimerRegistry）を
+//! `127.0.0.1` 上で接続し、フェーズの同期とリーダー消失後の単独動作を確認する。
+
+use std::time::Duration;
+
+use pomodoro::daemon::{TeamFollower, TeamLeader, TimerEvent, TimerRegistry};
+use pomodoro::types::{PomodoroConfig, StartParams, TimerPhase};
+use tokio::sync::mpsc;
+
+/// 名前付きイベントのレシーバーから条件を満たすイベントを待つ
+async fn wait_for_event(
+    rx: &mut mpsc::UnboundedReceiver<(String, TimerEvent)>,
+    f: impl Fn(&TimerEvent) -> bool,
+) -> TimerEvent {
+    tokio::time::timeout(Duration::from_secs(3), async {
+        loop {
+            let (_, event) = rx.recv().await.expect("event channel closed");
+            if f(&event) {
+                return event;
+            }
+        }
+    })
+    .await
+    .expect("event not received in time")
+}
+
+#[tokio::test]
+async fn test_follower_daemon_mirrors_leader_phases() {
+    let (leader_timers, _leader_rx) = TimerRegistry::new(PomodoroConfig::default(), None);
+    let (follower_timers, mut follower_rx) = TimerRegistry::new(PomodoroConfig::default(), None);
+
+    let leader = TeamLeader::bind("127.0.0.1:0".parse().unwrap())
+        .await
+        .unwrap();
+    let addr = leader.local_addr().unwrap();
+    let leader_task = tokio::spawn(leader.run(leader_timers.default_timer()));
+
+    let follower_engine = follower_timers.default_timer().engine().clone();
+    let follower = TeamFollower::new(addr.to_string())
+        .with_intervals(Duration::from_secs(60), Duration::from_millis(300));
+    let follower_task = tokio::spawn(follower.run(follower_engine.clone()));
+
+    let leader_engine = leader_timers.default_timer().engine().clone();
+    leader_engine
+        .lock()
+        .await
+        .start(&StartParams {
+            task_name: Some("モブプロ".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+
+    // 作業開始・休憩開始がフォロワーにも届く
+    let event = wait_for_event(&mut follower_rx, |e| {
+        matches!(e, TimerEvent::WorkStarted { .. })
+    })
+    .await;
+    assert_eq!(
+        event,
+        TimerEvent::WorkStarted {
+            task_name: Some("モブプロ".to_string())
+        }
+    );
+
+    leader_engine.lock().await.skip().unwrap();
+    wait_for_event(&mut follower_rx, |e| {
+        matches!(e, TimerEvent::BreakStarted { .. })
+    })
+    .await;
+    assert_eq!(
+        follower_engine.lock().await.get_state().phase,
+        TimerPhase::Breaking
+    );
+
+    // フォロワー側の操作はリーダーに追従中のため拒否される
+    assert!(follower_engine.lock().await.pause().is_err());
+
+    // リーダーが消えたら休憩の残りを単独でカウントダウンする
+    leader_task.abort();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(3);
+    while follower_engine.lock().await.is_following() {
+        assert!(tokio::time::Instant::now() < deadline);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let mut engine = follower_engine.lock().await;
+    assert_eq!(engine.get_state().phase, TimerPhase::Breaking);
+    assert!(engine.process_tick().unwrap());
+    assert!(engine.pause().is_ok());
+    drop(engine);
+
+    follower_task.abort();
+}
@@ -1,6 +1,6 @@
 use chrono::Utc;
 use pomodoro::hooks::{HookContext, HookExecutor};
-use pomodoro::types::HookEvent;
+use pomodoro::types::{HookEvent, PomodoroConfig};
 use uuid::Uuid;
 
 #[test]
@@ -23,6 +23,7 @@ fn test_hook_context_to_env_vars() {
         total_cycles: 4,
         timestamp: Utc::now(),
         session_id: Uuid::new_v4(),
+        config: PomodoroConfig::default(),
     };
 
     let vars = context.to_env_vars();